@@ -1,19 +1,41 @@
 use crate::error::ContractError;
 use crate::msg::{
     CreateMinterMsg, CreateMinterMsgWithMigration, CreateMinterMsgs, ExecuteMsg, InstantiateMsg,
-    ParamsResponse, QueryMsg,
+    MigrateMsg, MinterFactoryParams, ParamsResponse, QueryMsg,
 };
-use crate::state::PARAMS;
+use crate::state::{PendingLaunch, PARAMS, PENDING_LAUNCH};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
-    Response, StdResult, Uint128, WasmMsg,
+    to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply,
+    Response, StdResult, SubMsg, Uint128, WasmMsg,
 };
+use cw2::set_contract_version;
+use cw_ownable::{get_ownership, initialize_owner, update_ownership, Action};
 use cw_utils::NativeBalance;
+use factory_types::access::{CreatorAccess, CreatorAccessMode, CreatorStatus};
+use factory_types::address::{minter_salt, predict_address};
+use factory_types::catalog::{CodeCatalog, CodeVersion};
 use factory_types::check_payment;
+use factory_types::discount::{CreatorDiscounts, CreatorFeeDiscount, FeeDiscount};
+use factory_types::launch::{whitelist_address_from_reply, CREATE_LAUNCH_WHITELIST_REPLY_ID};
+use factory_types::registry::{
+    ContractType, CreatedContract, PendingContract, Registry, CREATE_CONTRACT_REPLY_ID,
+};
+use factory_types::upgrades::ManagedUpgrades;
+use factory_types::validation::{Validation, ValidationResponse};
+use minter_types::config::Config;
 use minter_types::utils::check_collection_creation_fee;
+use omniflix_round_whitelist_factory::msg::{
+    ExecuteMsg as WhitelistFactoryExecuteMsg, QueryMsg as WhitelistFactoryQueryMsg,
+};
+use pauser::halt::GlobalHalt;
+use pauser::migration::migrate_version;
 use pauser::PauseState;
+use whitelist_types::{check_if_whitelist_is_active, CreateWhitelistMsg};
+
+const CONTRACT_NAME: &str = "crates.io:omniflix-minter-factory";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -22,6 +44,7 @@ pub fn instantiate(
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     let admin = deps
         .api
         .addr_validate(&msg.params.clone().admin.into_string())
@@ -35,6 +58,8 @@ pub fn instantiate(
 
     let params = msg.params;
     PARAMS.save(deps.storage, &params)?;
+    // Admin is tracked as the owner for two-step ownership transfers
+    initialize_owner(deps.storage, deps.api, Some(admin.as_str()))?;
     Ok(Response::default())
 }
 
@@ -46,72 +71,233 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::CreateMinter { msg } => create_minter(deps, env, info, msg),
+        ExecuteMsg::CreateMinter { msg } => create_minter(deps, env, info, msg, None),
+        ExecuteMsg::CreateVersionedMinter { msg, version } => {
+            create_minter(deps, env, info, msg, Some(version))
+        }
         ExecuteMsg::CreateMinterWithMigration { msg } => {
             create_minter_with_migration(deps, env, info, msg)
         }
-        ExecuteMsg::UpdateAdmin { admin } => update_params_admin(deps, env, info, admin),
+        ExecuteMsg::CreateLaunch {
+            whitelist,
+            minter,
+            version,
+        } => create_launch(deps, env, info, whitelist, minter, version),
+        ExecuteMsg::UpdateOwnership(action) => update_params_ownership(deps, env, info, action),
         ExecuteMsg::UpdateFeeCollectorAddress {
             fee_collector_address,
         } => update_params_fee_collector_address(deps, env, info, fee_collector_address),
         ExecuteMsg::UpdateMinterCodeId { minter_code_id } => {
             update_params_minter_code_id(deps, env, info, minter_code_id)
         }
+        ExecuteMsg::UpdateWhitelistFactoryAddress {
+            whitelist_factory_address,
+        } => update_params_whitelist_factory_address(deps, env, info, whitelist_factory_address),
         ExecuteMsg::UpdateMinterCreationFee {
             minter_creation_fee,
         } => update_params_minter_creation_fee(deps, env, info, minter_creation_fee),
+        ExecuteMsg::AddCodeVersion {
+            contract_type,
+            version,
+            code_id,
+            label,
+        } => add_code_version(deps, env, info, contract_type, version, code_id, label),
+        ExecuteMsg::SetCodeVersionDeprecated {
+            contract_type,
+            version,
+            deprecated,
+        } => set_code_version_deprecated(deps, env, info, contract_type, version, deprecated),
+        ExecuteMsg::MigrateContracts {
+            contract_type,
+            version,
+            contracts,
+            msg,
+        } => migrate_contracts(deps, env, info, contract_type, version, contracts, msg),
+        ExecuteMsg::SetManagedUpgrades { enabled } => {
+            set_managed_upgrades(deps, env, info, enabled)
+        }
+        ExecuteMsg::UpdateCreatorAccessMode { mode } => {
+            update_creator_access_mode(deps, env, info, mode)
+        }
+        ExecuteMsg::ApproveCreators { creators } => approve_creators(deps, env, info, creators),
+        ExecuteMsg::RevokeCreators { creators } => revoke_creators(deps, env, info, creators),
+        ExecuteMsg::SetCreatorFeeDiscount { creator, discount } => {
+            set_creator_fee_discount(deps, env, info, creator, discount)
+        }
         ExecuteMsg::Pause {} => execute_pause(deps, env, info),
         ExecuteMsg::Unpause {} => execute_unpause(deps, env, info),
         ExecuteMsg::SetPausers { pausers } => set_pausers(deps, env, info, pausers),
+        ExecuteMsg::SetGlobalHalt { halted } => set_global_halt(deps, env, info, halted),
     }
 }
 
 fn create_minter(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: CreateMinterMsg,
+    version: Option<String>,
 ) -> Result<Response, ContractError> {
     let params = PARAMS.load(deps.storage)?;
     let collection_creation_fee: Coin = check_collection_creation_fee(deps.as_ref().querier)?;
     let pause_state = PauseState::new()?;
     pause_state.error_if_paused(deps.as_ref().storage)?;
+    // Creators are checked against the access mode of the factory
+    CreatorAccess::new().check(deps.storage, &info.sender, env.block.time)?;
+    let code_id = resolve_minter_code_id(deps.as_ref(), &params, version)?;
+    let minter_creation_fee =
+        creator_minter_creation_fee(deps.as_ref(), &info.sender, &params.minter_creation_fee)?;
 
     check_payment(
         &info.funds,
         &[
             collection_creation_fee.clone(),
-            params.minter_creation_fee.clone(),
+            minter_creation_fee.clone(),
         ],
     )?;
-    // Unwrap the CreateMinterMsg
-    let create_minter_msg = CreateMinterMsgs::CreateMinter { msg: msg.clone() };
     // Validate the AuthDetails
     let auth_details = msg.auth_details.clone();
     auth_details.validate(&deps.as_ref())?;
 
     let mut msgs = Vec::<CosmosMsg>::new();
-    msgs.push(CosmosMsg::Wasm(WasmMsg::Instantiate {
-        admin: Some(auth_details.admin.to_string()),
-        code_id: params.minter_code_id,
-        msg: to_json_binary(&create_minter_msg)?,
-        funds: vec![collection_creation_fee.clone()],
-        label: params.product_label,
-    }));
-    if params.minter_creation_fee.amount > Uint128::new(0) {
+    let (instantiate_msg, minter_address) = instantiate_minter_msg(
+        deps,
+        &env,
+        &info.sender,
+        msg,
+        code_id,
+        collection_creation_fee,
+    )?;
+    if minter_creation_fee.amount > Uint128::new(0) {
         msgs.push(CosmosMsg::Bank(BankMsg::Send {
-            amount: vec![params.minter_creation_fee.clone()],
+            amount: vec![minter_creation_fee],
             to_address: params.fee_collector_address.to_string(),
         }));
     }
     let res = Response::new()
+        .add_submessage(instantiate_msg)
         .add_messages(msgs)
-        .add_attribute("action", "create_minter");
+        .add_attribute("action", "create_minter")
+        .add_attribute("minter_address", minter_address);
     Ok(res)
 }
+fn create_launch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mut whitelist: CreateWhitelistMsg,
+    minter: CreateMinterMsg,
+    version: Option<String>,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    let pause_state = PauseState::new()?;
+    pause_state.error_if_paused(deps.as_ref().storage)?;
+    // Creators are checked against the access mode of the factory
+    CreatorAccess::new().check(deps.storage, &info.sender, env.block.time)?;
+    let whitelist_factory_address = params
+        .whitelist_factory_address
+        .clone()
+        .ok_or(ContractError::WhitelistFactoryNotSet {})?;
+    let collection_creation_fee: Coin = check_collection_creation_fee(deps.as_ref().querier)?;
+    let minter_creation_fee =
+        creator_minter_creation_fee(deps.as_ref(), &info.sender, &params.minter_creation_fee)?;
+    let whitelist_creation_fee =
+        query_whitelist_creation_fee(deps.as_ref(), &info.sender, &whitelist_factory_address)?;
+
+    check_payment(
+        &info.funds,
+        &[
+            collection_creation_fee.clone(),
+            minter_creation_fee.clone(),
+            whitelist_creation_fee.clone(),
+        ],
+    )?;
+    // Validate the AuthDetails
+    minter.auth_details.validate(&deps.as_ref())?;
+    // Minter is checked before the whitelist is created for it
+    check_launch_minter(&env, &minter)?;
+
+    // Minter is added to the whitelist before it exists
+    let code_id = resolve_minter_code_id(deps.as_ref(), &params, version)?;
+    let (_, minter_address) = minter_salt_and_address(
+        deps.as_ref(),
+        &env,
+        &info.sender,
+        &minter.collection_details.id,
+        code_id,
+    )?;
+    whitelist
+        .minters
+        .get_or_insert_with(Vec::new)
+        .push(minter_address.to_string());
+
+    // Minter is instantiated once the whitelist factory returns the whitelist address
+    PENDING_LAUNCH.save(
+        deps.storage,
+        &PendingLaunch {
+            creator: info.sender.clone(),
+            minter,
+            code_id,
+            collection_creation_fee,
+        },
+    )?;
+    let mut whitelist_funds = vec![];
+    if !whitelist_creation_fee.amount.is_zero() {
+        whitelist_funds.push(whitelist_creation_fee);
+    }
+    // Whitelist is created for the creator so its discount, access and registry apply to them
+    let create_whitelist_msg = SubMsg::reply_on_success(
+        WasmMsg::Execute {
+            contract_addr: whitelist_factory_address.to_string(),
+            msg: to_json_binary(&WhitelistFactoryExecuteMsg::CreateWhitelistFor {
+                creator: info.sender.to_string(),
+                msg: whitelist,
+            })?,
+            funds: whitelist_funds,
+        },
+        CREATE_LAUNCH_WHITELIST_REPLY_ID,
+    );
+
+    let mut msgs = Vec::<CosmosMsg>::new();
+    if minter_creation_fee.amount > Uint128::new(0) {
+        msgs.push(CosmosMsg::Bank(BankMsg::Send {
+            amount: vec![minter_creation_fee],
+            to_address: params.fee_collector_address.to_string(),
+        }));
+    }
+    let res = Response::new()
+        .add_submessage(create_whitelist_msg)
+        .add_messages(msgs)
+        .add_attribute("action", "create_launch")
+        .add_attribute("minter_address", minter_address);
+    Ok(res)
+}
+
+// Runs the checks of the minter instantiate that do not depend on the whitelist
+fn check_launch_minter(env: &Env, minter: &CreateMinterMsg) -> Result<(), ContractError> {
+    let init = minter.init.clone().ok_or(ContractError::InitMissing {})?;
+    minter.collection_details.check_integrity()?;
+    minter
+        .token_details
+        .as_ref()
+        .ok_or(ContractError::InvalidTokenDetails {})?
+        .check_integrity()?;
+    // Whitelist address is set once the whitelist is created
+    let config = Config {
+        per_address_limit: init.per_address_limit,
+        start_time: init.start_time,
+        mint_price: init.mint_price,
+        whitelist_address: None,
+        end_time: init.end_time,
+        num_tokens: Some(init.num_tokens),
+    };
+    config.check_integrity(env.block.time)?;
+    Ok(())
+}
+
 fn create_minter_with_migration(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: CreateMinterMsgWithMigration,
 ) -> Result<Response, ContractError> {
@@ -124,7 +310,9 @@ fn create_minter_with_migration(
     }
 
     // Only collect minter_creation_fee
-    check_payment(&info.funds, &[params.minter_creation_fee.clone()])?;
+    let minter_creation_fee =
+        creator_minter_creation_fee(deps.as_ref(), &info.sender, &params.minter_creation_fee)?;
+    check_payment(&info.funds, &[minter_creation_fee.clone()])?;
 
     let mut msgs = Vec::<CosmosMsg>::new();
     let create_minter_msg = CreateMinterMsgs::CreateMinterWithMigration { msg: msg.clone() };
@@ -133,43 +321,180 @@ fn create_minter_with_migration(
     let auth_details = msg.auth_details.clone();
     auth_details.validate(&deps.as_ref())?;
 
-    msgs.push(CosmosMsg::Wasm(WasmMsg::Instantiate {
-        admin: Some(auth_details.admin.to_string()),
-        code_id: params.minter_code_id,
-        msg: to_json_binary(&create_minter_msg)?,
-        funds: vec![],
-        label: params.product_label,
-    }));
+    let (salt, minter_address) = minter_salt_and_address(
+        deps.as_ref(),
+        &env,
+        &info.sender,
+        &msg.collection_details.id,
+        params.minter_code_id,
+    )?;
+    // Admin of the minter migrates it unless the creator opted into managed upgrades
+    let (wasm_admin, factory_admin) = ManagedUpgrades::new().wasm_admin(
+        deps.storage,
+        &info.sender,
+        &env.contract.address,
+        msg.auth_details.admin.as_str(),
+    );
+    let instantiate_msg = Registry::new().instantiate_msg(
+        deps.storage,
+        WasmMsg::Instantiate2 {
+            admin: Some(wasm_admin),
+            code_id: params.minter_code_id,
+            label: params.product_label,
+            msg: to_json_binary(&create_minter_msg)?,
+            funds: vec![],
+            salt,
+        },
+        PendingContract {
+            creator: info.sender.clone(),
+            code_id: params.minter_code_id,
+            contract_type: ContractType::Minter,
+            collection_id: Some(msg.collection_details.id.clone()),
+            factory_admin,
+        },
+    )?;
 
-    if params.minter_creation_fee.amount > Uint128::new(0) {
+    if minter_creation_fee.amount > Uint128::new(0) {
         msgs.push(CosmosMsg::Bank(BankMsg::Send {
-            amount: vec![params.minter_creation_fee.clone()],
+            amount: vec![minter_creation_fee],
             to_address: params.fee_collector_address.to_string(),
         }));
     }
 
     let res = Response::new()
+        .add_submessage(instantiate_msg)
         .add_messages(msgs)
-        .add_attribute("action", "create_minter_with_migration");
+        .add_attribute("action", "create_minter_with_migration")
+        .add_attribute("minter_address", minter_address);
 
     Ok(res)
 }
 
-fn update_params_admin(
+// Returns the code id the minter of the version is created with
+// Minters without a version use the default code id
+fn resolve_minter_code_id(
+    deps: Deps,
+    params: &MinterFactoryParams,
+    version: Option<String>,
+) -> Result<u64, ContractError> {
+    let code_id = match version {
+        Some(version) => {
+            CodeCatalog::new().active_code_id(deps.storage, &ContractType::Minter, &version)?
+        }
+        None => params.minter_code_id,
+    };
+    Ok(code_id)
+}
+
+// Returns the instantiate2 salt and the address of the minter
+// Salt is derived from the creator and collection id so a creator can not reuse a collection id
+fn minter_salt_and_address(
+    deps: Deps,
+    env: &Env,
+    creator: &Addr,
+    collection_id: &str,
+    code_id: u64,
+) -> Result<(Binary, Addr), ContractError> {
+    let salt = minter_salt(creator, collection_id);
+    let minter_address = predict_address(deps, &env.contract.address, code_id, &salt)?;
+    let existing = Registry::new().load(deps.storage, &minter_address)?;
+    if existing.is_some() {
+        return Err(ContractError::SaltCollision {
+            address: minter_address.into_string(),
+        });
+    }
+    Ok((salt, minter_address))
+}
+
+// Returns the instantiate2 message of the minter and the address it is instantiated at
+fn instantiate_minter_msg(
     deps: DepsMut,
-    _env: Env,
+    env: &Env,
+    creator: &Addr,
+    msg: CreateMinterMsg,
+    code_id: u64,
+    collection_creation_fee: Coin,
+) -> Result<(SubMsg, Addr), ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    let collection_id = msg.collection_details.id.clone();
+    let (salt, minter_address) = minter_salt_and_address(
+        deps.as_ref(),
+        env,
+        creator,
+        &collection_id,
+        code_id,
+    )?;
+    // Admin of the minter migrates it unless the creator opted into managed upgrades
+    let (wasm_admin, factory_admin) = ManagedUpgrades::new().wasm_admin(
+        deps.storage,
+        creator,
+        &env.contract.address,
+        msg.auth_details.admin.as_str(),
+    );
+    let create_minter_msg = CreateMinterMsgs::CreateMinter { msg };
+    // Minter is registered once the instantiate reply returns its address
+    let instantiate_msg = Registry::new().instantiate_msg(
+        deps.storage,
+        WasmMsg::Instantiate2 {
+            admin: Some(wasm_admin),
+            code_id,
+            label: params.product_label,
+            msg: to_json_binary(&create_minter_msg)?,
+            funds: vec![collection_creation_fee],
+            salt,
+        },
+        PendingContract {
+            creator: creator.clone(),
+            code_id,
+            contract_type: ContractType::Minter,
+            collection_id: Some(collection_id),
+            factory_admin,
+        },
+    )?;
+    Ok((instantiate_msg, minter_address))
+}
+
+// Returns the minter creation fee after the discount of the creator
+fn creator_minter_creation_fee(
+    deps: Deps,
+    creator: &Addr,
+    minter_creation_fee: &Coin,
+) -> Result<Coin, ContractError> {
+    let fee = CreatorDiscounts::new()
+        .creator_fee(deps.storage, creator, minter_creation_fee)?;
+    Ok(fee)
+}
+
+// Returns the fee the whitelist factory charges the creator for creating a whitelist
+fn query_whitelist_creation_fee(
+    deps: Deps,
+    creator: &Addr,
+    whitelist_factory_address: &Addr,
+) -> Result<Coin, ContractError> {
+    let fee: Coin = deps.querier.query_wasm_smart(
+        whitelist_factory_address,
+        &WhitelistFactoryQueryMsg::CreatorWhitelistCreationFee {
+            creator: creator.to_string(),
+        },
+    )?;
+    Ok(fee)
+}
+
+fn update_params_ownership(
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    admin: String,
+    action: Action,
 ) -> Result<Response, ContractError> {
+    let ownership = update_ownership(deps.branch(), &env.block, &info.sender, action)?;
+    // Keep the admin of params in sync with the owner
+    // If ownership is renounced, admin is set to the contract itself so no one can act as admin
     let mut params = PARAMS.load(deps.storage)?;
-    if params.admin != info.sender {
-        return Err(ContractError::Unauthorized {});
-    }
-    params.admin = deps.api.addr_validate(&admin)?;
+    params.admin = ownership.owner.clone().unwrap_or(env.contract.address);
     PARAMS.save(deps.storage, &params)?;
     Ok(Response::default()
-        .add_attribute("action", "update_admin")
-        .add_attribute("new_admin", admin))
+        .add_attribute("action", "update_ownership")
+        .add_attributes(ownership.into_attributes()))
 }
 
 fn update_params_fee_collector_address(
@@ -209,6 +534,194 @@ fn update_params_minter_code_id(
         .add_attribute("new_minter_code_id", minter_code_id.to_string()))
 }
 
+fn update_params_whitelist_factory_address(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    whitelist_factory_address: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    params.whitelist_factory_address = whitelist_factory_address
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    PARAMS.save(deps.storage, &params)?;
+    Ok(Response::default()
+        .add_attribute("action", "update_whitelist_factory_address")
+        .add_attribute(
+            "new_whitelist_factory_address",
+            params
+                .whitelist_factory_address
+                .map(|address| address.to_string())
+                .unwrap_or_default(),
+        ))
+}
+
+fn update_creator_access_mode(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    mode: CreatorAccessMode,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    CreatorAccess::new().mode.save(deps.storage, &mode)?;
+    Ok(Response::default().add_attribute("action", "update_creator_access_mode"))
+}
+
+fn approve_creators(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    creators: Vec<String>,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let creators = creators
+        .iter()
+        .map(|creator| deps.api.addr_validate(creator))
+        .collect::<StdResult<Vec<Addr>>>()?;
+    CreatorAccess::new().approve(deps.storage, &creators)?;
+    Ok(Response::default()
+        .add_attribute("action", "approve_creators")
+        .add_attribute("creators", creators.len().to_string()))
+}
+
+fn revoke_creators(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    creators: Vec<String>,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let creators = creators
+        .iter()
+        .map(|creator| deps.api.addr_validate(creator))
+        .collect::<StdResult<Vec<Addr>>>()?;
+    CreatorAccess::new().revoke(deps.storage, &creators);
+    Ok(Response::default()
+        .add_attribute("action", "revoke_creators")
+        .add_attribute("creators", creators.len().to_string()))
+}
+
+fn set_creator_fee_discount(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    creator: String,
+    discount: Option<FeeDiscount>,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let creator = deps.api.addr_validate(&creator)?;
+    CreatorDiscounts::new()
+        .set(deps.storage, &creator, discount)?;
+    Ok(Response::default()
+        .add_attribute("action", "set_creator_fee_discount")
+        .add_attribute("creator", creator))
+}
+
+fn add_code_version(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    contract_type: ContractType,
+    version: String,
+    code_id: u64,
+    label: String,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    // Code id must be stored on chain
+    deps.querier.query_wasm_code_info(code_id)?;
+    CodeCatalog::new().add(
+        deps.storage,
+        CodeVersion {
+            contract_type,
+            version: version.clone(),
+            code_id,
+            label,
+            deprecated: false,
+        },
+        &[ContractType::Minter],
+    )?;
+    Ok(Response::default()
+        .add_attribute("action", "add_code_version")
+        .add_attribute("version", version)
+        .add_attribute("code_id", code_id.to_string()))
+}
+
+fn set_code_version_deprecated(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    contract_type: ContractType,
+    version: String,
+    deprecated: bool,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    CodeCatalog::new().set_deprecated(deps.storage, &contract_type, &version, deprecated)?;
+    Ok(Response::default()
+        .add_attribute("action", "set_code_version_deprecated")
+        .add_attribute("version", version)
+        .add_attribute("deprecated", deprecated.to_string()))
+}
+
+fn migrate_contracts(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    contract_type: ContractType,
+    version: String,
+    contracts: Vec<String>,
+    msg: Binary,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let contracts = contracts
+        .iter()
+        .map(|contract| deps.api.addr_validate(contract))
+        .collect::<StdResult<Vec<Addr>>>()?;
+    let migrate_msgs =
+        CodeCatalog::new().migrate_msgs(deps.storage, &contract_type, &version, &contracts, msg)?;
+    Ok(Response::default()
+        .add_messages(migrate_msgs)
+        .add_attribute("action", "migrate_contracts")
+        .add_attribute("version", version)
+        .add_attribute("contracts", contracts.len().to_string()))
+}
+
+fn set_managed_upgrades(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    ManagedUpgrades::new().set(deps.storage, &info.sender, enabled)?;
+    Ok(Response::default()
+        .add_attribute("action", "set_managed_upgrades")
+        .add_attribute("creator", info.sender)
+        .add_attribute("enabled", enabled.to_string()))
+}
+
 fn update_params_minter_creation_fee(
     deps: DepsMut,
     _env: Env,
@@ -260,16 +773,168 @@ fn set_pausers(
         .add_attribute("pausers", pausers.join(",")))
 }
 
+fn set_global_halt(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    halted: bool,
+) -> Result<Response, ContractError> {
+    GlobalHalt::new().set_halted(deps.storage, &info.sender, halted)?;
+    Ok(Response::default()
+        .add_attribute("action", "set_global_halt")
+        .add_attribute("halted", halted.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        CREATE_CONTRACT_REPLY_ID => {
+            let contract = Registry::new().register(deps.storage, msg, env.block.time)?;
+            Ok(Response::new()
+                .add_attribute("action", "register_contract")
+                .add_attribute("contract_address", contract.address)
+                .add_attribute("creator", contract.creator))
+        }
+        CREATE_LAUNCH_WHITELIST_REPLY_ID => {
+            let whitelist_address = whitelist_address_from_reply(msg)?;
+            let mut launch = PENDING_LAUNCH.load(deps.storage)?;
+            PENDING_LAUNCH.remove(deps.storage);
+            let init = launch
+                .minter
+                .init
+                .as_mut()
+                .ok_or(ContractError::InitMissing {})?;
+            init.whitelist_address = Some(whitelist_address.to_string());
+            // Minter is instantiated with the code id its address was predicted with
+            let (instantiate_msg, minter_address) = instantiate_minter_msg(
+                deps,
+                &env,
+                &launch.creator,
+                launch.minter,
+                launch.code_id,
+                launch.collection_creation_fee,
+            )?;
+            Ok(Response::new()
+                .add_submessage(instantiate_msg)
+                .add_attribute("action", "create_launch")
+                .add_attribute("whitelist_address", whitelist_address)
+                .add_attribute("minter_address", minter_address)
+                .add_attribute("creator", launch.creator))
+        }
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    // Factories instantiated before ownership was tracked have no owner yet
+    if get_ownership(deps.storage).is_err() {
+        let params = PARAMS.load(deps.storage)?;
+        initialize_owner(deps.storage, deps.api, Some(params.admin.as_str()))?;
+    }
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Params {} => to_json_binary(&query_params(deps)?),
+        QueryMsg::Ownership {} => to_json_binary(&get_ownership(deps.storage)?),
         QueryMsg::IsPaused {} => to_json_binary(&query_is_paused(deps, _env)?),
         QueryMsg::Pausers {} => to_json_binary(&query_pausers(deps, _env)?),
+        QueryMsg::GlobalHalt {} => to_json_binary(&GlobalHalt::new().is_halted(deps.storage)?),
         QueryMsg::MinterCreationFee {} => to_json_binary(&query_minter_creation_fee(deps, _env)?),
+        QueryMsg::CreatorMinterCreationFee { creator } => {
+            to_json_binary(&query_creator_minter_creation_fee(deps, creator)?)
+        }
+        QueryMsg::LaunchCreationFee { creator } => {
+            to_json_binary(&query_launch_creation_fee(deps, creator)?)
+        }
+        QueryMsg::CreatorStatus { creator } => {
+            to_json_binary(&query_creator_status(deps, _env, creator)?)
+        }
+        QueryMsg::CreatorFeeDiscounts { start_after, limit } => {
+            to_json_binary(&query_creator_fee_discounts(deps, start_after, limit)?)
+        }
+        QueryMsg::CodeVersions {
+            contract_type,
+            start_after,
+            limit,
+        } => to_json_binary(&query_code_versions(deps, contract_type, start_after, limit)?),
+        QueryMsg::CodeVersion {
+            contract_type,
+            version,
+        } => to_json_binary(&query_code_version(deps, contract_type, version)?),
+        QueryMsg::ContractsByCreator {
+            creator,
+            start_after,
+            limit,
+        } => to_json_binary(&query_contracts_by_creator(deps, creator, start_after, limit)?),
+        QueryMsg::ContractsByType {
+            contract_type,
+            start_after,
+            limit,
+        } => to_json_binary(&query_contracts_by_type(deps, contract_type, start_after, limit)?),
+        QueryMsg::ContractsByCollectionId {
+            collection_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_contracts_by_collection_id(
+            deps,
+            collection_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::CreatedContract { address } => {
+            to_json_binary(&query_created_contract(deps, address)?)
+        }
+        QueryMsg::ManagedUpgrades { creator } => {
+            to_json_binary(&query_managed_upgrades(deps, creator)?)
+        }
+        QueryMsg::PredictMinterAddress {
+            creator,
+            collection_id,
+            version,
+        } => to_json_binary(&query_predict_minter_address(
+            deps,
+            _env,
+            creator,
+            collection_id,
+            version,
+        )?),
+        QueryMsg::ValidateCreateMinter {
+            creator,
+            msg,
+            version,
+        } => to_json_binary(&query_validate_create_minter(
+            deps,
+            _env,
+            creator,
+            msg,
+            version,
+        )?),
     }
 }
 
+fn query_code_versions(
+    deps: Deps,
+    contract_type: ContractType,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CodeVersion>> {
+    CodeCatalog::new().list(deps.storage, &contract_type, start_after, limit)
+}
+
+fn query_code_version(
+    deps: Deps,
+    contract_type: ContractType,
+    version: String,
+) -> Result<CodeVersion, ContractError> {
+    let code_version = CodeCatalog::new().load(deps.storage, &contract_type, &version)?;
+    Ok(code_version)
+}
+
 fn query_params(deps: Deps) -> StdResult<ParamsResponse> {
     let params = PARAMS.load(deps.storage)?;
     Ok(ParamsResponse { params })
@@ -296,13 +961,204 @@ fn query_minter_creation_fee(deps: Deps, _env: Env) -> Result<Vec<Coin>, Contrac
     Ok(fees.into_vec())
 }
 
+fn query_creator_minter_creation_fee(
+    deps: Deps,
+    creator: String,
+) -> Result<Vec<Coin>, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    let creator = deps.api.addr_validate(&creator)?;
+    let minter_creation_fee =
+        creator_minter_creation_fee(deps, &creator, &params.minter_creation_fee)?;
+    let collection_creation_fee: Coin = check_collection_creation_fee(deps.querier)?;
+    let mut fees = NativeBalance::default();
+    fees += minter_creation_fee;
+    fees += collection_creation_fee;
+    fees.normalize();
+    Ok(fees.into_vec())
+}
+
+fn query_launch_creation_fee(deps: Deps, creator: String) -> Result<Vec<Coin>, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    let creator = deps.api.addr_validate(&creator)?;
+    let whitelist_factory_address = params
+        .whitelist_factory_address
+        .ok_or(ContractError::WhitelistFactoryNotSet {})?;
+    let minter_creation_fee =
+        creator_minter_creation_fee(deps, &creator, &params.minter_creation_fee)?;
+    let collection_creation_fee: Coin = check_collection_creation_fee(deps.querier)?;
+    let whitelist_creation_fee =
+        query_whitelist_creation_fee(deps, &creator, &whitelist_factory_address)?;
+    let mut fees = NativeBalance::default();
+    fees += minter_creation_fee;
+    fees += collection_creation_fee;
+    fees += whitelist_creation_fee;
+    fees.normalize();
+    Ok(fees.into_vec())
+}
+
+fn query_creator_status(deps: Deps, env: Env, creator: String) -> StdResult<CreatorStatus> {
+    let creator = deps.api.addr_validate(&creator)?;
+    CreatorAccess::new().status(deps.storage, &creator, env.block.time)
+}
+
+fn query_creator_fee_discounts(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CreatorFeeDiscount>> {
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    CreatorDiscounts::new()
+        .list(deps.storage, start_after, limit)
+}
+
+fn query_contracts_by_creator(
+    deps: Deps,
+    creator: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CreatedContract>> {
+    let creator = deps.api.addr_validate(&creator)?;
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    Registry::new().by_creator(deps.storage, creator, start_after, limit)
+}
+
+fn query_contracts_by_type(
+    deps: Deps,
+    contract_type: ContractType,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CreatedContract>> {
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    Registry::new().by_type(deps.storage, contract_type, start_after, limit)
+}
+
+fn query_contracts_by_collection_id(
+    deps: Deps,
+    collection_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CreatedContract>> {
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    Registry::new().by_collection_id(deps.storage, collection_id, start_after, limit)
+}
+
+fn query_created_contract(deps: Deps, address: String) -> StdResult<Option<CreatedContract>> {
+    let address = deps.api.addr_validate(&address)?;
+    Registry::new().load(deps.storage, &address)
+}
+
+fn query_managed_upgrades(deps: Deps, creator: String) -> StdResult<bool> {
+    let creator = deps.api.addr_validate(&creator)?;
+    Ok(ManagedUpgrades::new().is_enabled(deps.storage, &creator))
+}
+
+fn query_predict_minter_address(
+    deps: Deps,
+    env: Env,
+    creator: String,
+    collection_id: String,
+    version: Option<String>,
+) -> Result<Addr, ContractError> {
+    let creator = deps.api.addr_validate(&creator)?;
+    let params = PARAMS.load(deps.storage)?;
+    // Same code id as CreateMinter and CreateVersionedMinter
+    let code_id = resolve_minter_code_id(deps, &params, version)?;
+    let salt = minter_salt(&creator, &collection_id);
+    let minter_address = predict_address(deps, &env.contract.address, code_id, &salt)?;
+    Ok(minter_address)
+}
+
+// Runs the checks of create_minter and of the minter instantiate, collecting every problem
+fn query_validate_create_minter(
+    deps: Deps,
+    env: Env,
+    creator: String,
+    msg: CreateMinterMsg,
+    version: Option<String>,
+) -> Result<ValidationResponse, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    let creator = deps.api.addr_validate(&creator)?;
+    let mut validation = Validation::new();
+
+    // Checks of the factory
+    validation.check(PauseState::new()?.error_if_paused(deps.storage));
+    validation.check(CreatorAccess::new().check(deps.storage, &creator, env.block.time));
+    // Same code id as CreateMinter and CreateVersionedMinter
+    if let Some(code_id) = validation.check(resolve_minter_code_id(deps, &params, version)) {
+        validation.check(minter_salt_and_address(
+            deps,
+            &env,
+            &creator,
+            &msg.collection_details.id,
+            code_id,
+        ));
+    }
+    if let Some(collection_creation_fee) =
+        validation.check(check_collection_creation_fee(deps.querier))
+    {
+        validation.require_funds(collection_creation_fee);
+    }
+    if let Some(minter_creation_fee) = validation.check(creator_minter_creation_fee(
+        deps,
+        &creator,
+        &params.minter_creation_fee,
+    )) {
+        validation.require_funds(minter_creation_fee);
+    }
+    validation.check(msg.auth_details.validate(&deps));
+
+    // Checks of the minter
+    validation.check(msg.collection_details.check_integrity());
+    match &msg.token_details {
+        Some(token_details) => {
+            validation.check(token_details.check_integrity());
+        }
+        None => validation.error(ContractError::InvalidTokenDetails {}),
+    }
+    match msg.init {
+        Some(init) => {
+            let whitelist_address = init
+                .whitelist_address
+                .and_then(|address| validation.check(deps.api.addr_validate(&address)));
+            if let Some(whitelist_address) = &whitelist_address {
+                let is_active =
+                    validation.check(check_if_whitelist_is_active(whitelist_address, deps));
+                if is_active == Some(true) {
+                    validation.error(ContractError::WhitelistAlreadyActive {});
+                }
+            }
+            let config = Config {
+                per_address_limit: init.per_address_limit,
+                start_time: init.start_time,
+                mint_price: init.mint_price,
+                whitelist_address,
+                end_time: init.end_time,
+                num_tokens: Some(init.num_tokens),
+            };
+            validation.check(config.check_integrity(env.block.time));
+        }
+        None => validation.error(ContractError::InitMissing {}),
+    }
+    Ok(validation.into_response())
+}
+
 #[cfg(test)]
 mod minter_factory_tests {
     use super::*;
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        Addr,
+        Addr, Storage,
     };
+    use cw_ownable::OwnershipError;
+    use pauser::migration::MigrationError;
     use pauser::PauseError;
 
     #[test]
@@ -318,6 +1174,7 @@ mod minter_factory_tests {
                     denom: "uusd".to_string(),
                 },
                 product_label: "omniflix-nft-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
         let info = mock_info("creator", &[]);
@@ -336,6 +1193,7 @@ mod minter_factory_tests {
                     denom: "uusd".to_string(),
                 },
                 product_label: "omniflix-nft-minter".to_string(),
+                whitelist_factory_address: None,
             }
         );
     }
@@ -352,6 +1210,7 @@ mod minter_factory_tests {
                     denom: "uusd".to_string(),
                 },
                 product_label: "omniflix-nft-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
         let info = mock_info("creator", &[]);
@@ -370,20 +1229,43 @@ mod minter_factory_tests {
                     denom: "uusd".to_string(),
                 },
                 product_label: "omniflix-nft-minter".to_string(),
+                whitelist_factory_address: None,
             }
         );
-        // Non admin cannot update admin
-        let new_admin = "new_admin".to_string();
+        // Non admin can not transfer ownership
+        let transfer_msg = ExecuteMsg::UpdateOwnership(Action::TransferOwnership {
+            new_owner: "new_admin".to_string(),
+            expiry: None,
+        });
         let info = mock_info("non_admin", &[]);
-        let res = update_params_admin(deps.as_mut(), mock_env(), info, new_admin.clone());
-        assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+        let res = execute(deps.as_mut(), mock_env(), info, transfer_msg.clone());
+        assert_eq!(res.unwrap_err(), ContractError::Ownership(OwnershipError::NotOwner));
 
-        // update admin
-        let new_admin = "new_admin".to_string();
+        // Admin can propose a new admin
         let info = mock_info("admin", &[]);
-        let _res = update_params_admin(deps.as_mut(), mock_env(), info, new_admin.clone()).unwrap();
+        let _res = execute(deps.as_mut(), mock_env(), info, transfer_msg).unwrap();
+
+        // Admin is not changed until the transfer is accepted
+        let params = query_params(deps.as_ref()).unwrap();
+        assert_eq!(params.params.admin, Addr::unchecked("admin"));
+
+        // Only the pending owner can accept
+        let accept_msg = ExecuteMsg::UpdateOwnership(Action::AcceptOwnership);
+        let info = mock_info("non_admin", &[]);
+        let res = execute(deps.as_mut(), mock_env(), info, accept_msg.clone());
+        assert_eq!(res.unwrap_err(), ContractError::Ownership(OwnershipError::NotPendingOwner));
+
+        // New admin accepts the ownership
+        let info = mock_info("new_admin", &[]);
+        let _res = execute(deps.as_mut(), mock_env(), info, accept_msg).unwrap();
+
+        // query params
         let params = query_params(deps.as_ref()).unwrap();
-        assert_eq!(params.params.admin, Addr::unchecked(new_admin));
+        assert_eq!(params.params.admin, Addr::unchecked("new_admin"));
+
+        let ownership = get_ownership(deps.as_ref().storage).unwrap();
+        assert_eq!(ownership.owner, Some(Addr::unchecked("new_admin")));
+        assert_eq!(ownership.pending_owner, None);
     }
     #[test]
     fn test_update_fee_collector_address() {
@@ -398,6 +1280,7 @@ mod minter_factory_tests {
                     denom: "uusd".to_string(),
                 },
                 product_label: "omniflix-nft-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
         let info = mock_info("creator", &[]);
@@ -416,6 +1299,7 @@ mod minter_factory_tests {
                     denom: "uusd".to_string(),
                 },
                 product_label: "omniflix-nft-minter".to_string(),
+                whitelist_factory_address: None,
             }
         );
         // Non admin cannot update fee_collector_address
@@ -459,6 +1343,7 @@ mod minter_factory_tests {
                     denom: "uusd".to_string(),
                 },
                 product_label: "omniflix-nft-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
         let info = mock_info("creator", &[]);
@@ -477,6 +1362,7 @@ mod minter_factory_tests {
                     denom: "uusd".to_string(),
                 },
                 product_label: "omniflix-nft-minter".to_string(),
+                whitelist_factory_address: None,
             }
         );
         // Non admin cannot update minter_code_id
@@ -507,6 +1393,7 @@ mod minter_factory_tests {
                     denom: "uusd".to_string(),
                 },
                 product_label: "omniflix-nft-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
         let info = mock_info("creator", &[]);
@@ -525,6 +1412,7 @@ mod minter_factory_tests {
                     denom: "uusd".to_string(),
                 },
                 product_label: "omniflix-nft-minter".to_string(),
+                whitelist_factory_address: None,
             }
         );
         // Non admin cannot update minter_creation_fee
@@ -570,6 +1458,7 @@ mod minter_factory_tests {
                     denom: "uusd".to_string(),
                 },
                 product_label: "omniflix-nft-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
         let info = mock_info("creator", &[]);
@@ -588,6 +1477,7 @@ mod minter_factory_tests {
                     denom: "uusd".to_string(),
                 },
                 product_label: "omniflix-nft-minter".to_string(),
+                whitelist_factory_address: None,
             }
         );
         // Non admin cannot pause
@@ -636,6 +1526,7 @@ mod minter_factory_tests {
                     denom: "uusd".to_string(),
                 },
                 product_label: "omniflix-nft-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
         let info = mock_info("creator", &[]);
@@ -654,6 +1545,7 @@ mod minter_factory_tests {
                     denom: "uusd".to_string(),
                 },
                 product_label: "omniflix-nft-minter".to_string(),
+                whitelist_factory_address: None,
             }
         );
         // Non admin cannot set pausers
@@ -677,4 +1569,45 @@ mod minter_factory_tests {
             vec![Addr::unchecked("pauser1"), Addr::unchecked("pauser2")]
         );
     }
+
+    #[test]
+    fn test_migrate() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg {
+            params: crate::msg::MinterFactoryParams {
+                admin: Addr::unchecked("admin"),
+                fee_collector_address: Addr::unchecked("fee_collector_address"),
+                minter_code_id: 1,
+                minter_creation_fee: Coin {
+                    amount: Uint128::new(100),
+                    denom: "uusd".to_string(),
+                },
+                product_label: "omniflix-nft-minter".to_string(),
+                whitelist_factory_address: None,
+            },
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+        // Factories deployed before ownership tracking stored neither an owner nor a version
+        deps.storage.remove(b"ownership");
+        deps.storage.remove(b"contract_info");
+
+        let _res = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        let ownership = get_ownership(deps.as_ref().storage).unwrap();
+        assert_eq!(ownership.owner, Some(Addr::unchecked("admin")));
+        let version = cw2::get_contract_version(deps.as_ref().storage).unwrap();
+        assert_eq!(version.contract, CONTRACT_NAME);
+        assert_eq!(version.version, CONTRACT_VERSION);
+
+        // Migrating to an older version is rejected
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "99.0.0").unwrap();
+        let res = migrate(deps.as_mut(), mock_env(), MigrateMsg {});
+        assert_eq!(
+            res.unwrap_err(),
+            ContractError::Migration(MigrationError::Downgrade {
+                stored: "99.0.0".to_string(),
+                new: CONTRACT_VERSION.to_string(),
+            })
+        );
+    }
 }
@@ -1,5 +1,11 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Coin, Timestamp};
+use cosmwasm_std::{Addr, Binary, Coin, Timestamp};
+use cw_ownable::{cw_ownable_execute, cw_ownable_query};
+use factory_types::access::{CreatorAccessMode, CreatorStatus};
+use factory_types::catalog::CodeVersion;
+use factory_types::discount::{CreatorFeeDiscount, FeeDiscount};
+use factory_types::registry::{ContractType, CreatedContract};
+use factory_types::validation::ValidationResponse;
 use minter_types::{
     collection_details::CollectionDetails,
     config::Config,
@@ -7,6 +13,7 @@ use minter_types::{
     token_details::{Token, TokenDetails},
     types::AuthDetails,
 };
+use whitelist_types::CreateWhitelistMsg;
 #[cw_serde]
 pub struct InstantiateMsg {
     pub params: MinterFactoryParams,
@@ -30,6 +37,8 @@ pub struct CreateMinterMsgWithMigration {
     pub collection_details: CollectionDetails,
     pub auth_details: AuthDetails,
     pub token_details: TokenDetails,
+    // Timelock delay in seconds, defaults to one day and 0 disables the timelock
+    pub timelock_duration: Option<u64>,
 }
 
 #[cw_serde]
@@ -46,18 +55,68 @@ pub enum CreateMinterMsgs {
     CreateMinterWithMigration { msg: CreateMinterMsgWithMigration },
 }
 
+#[cw_ownable_execute]
 #[allow(clippy::large_enum_variant)]
 #[cw_serde]
 pub enum ExecuteMsg {
     CreateMinter { msg: CreateMinterMsg },
+    // Creates the minter with the code id of a version in the code catalog
+    CreateVersionedMinter {
+        msg: CreateMinterMsg,
+        version: String,
+    },
     CreateMinterWithMigration { msg: CreateMinterMsgWithMigration },
-    UpdateAdmin { admin: String },
+    // Creates the whitelist through the whitelist factory and the minter wired to it
+    CreateLaunch {
+        whitelist: CreateWhitelistMsg,
+        minter: CreateMinterMsg,
+        // Version of the code catalog the minter is created with, None for the default code id
+        version: Option<String>,
+    },
     UpdateFeeCollectorAddress { fee_collector_address: String },
     UpdateMinterCreationFee { minter_creation_fee: Coin },
     UpdateMinterCodeId { minter_code_id: u64 },
+    UpdateWhitelistFactoryAddress {
+        whitelist_factory_address: Option<String>,
+    },
+    // Adds a code id to the code catalog under the version
+    AddCodeVersion {
+        contract_type: ContractType,
+        version: String,
+        code_id: u64,
+        label: String,
+    },
+    // Deprecated versions can not be used to create or migrate minters
+    SetCodeVersionDeprecated {
+        contract_type: ContractType,
+        version: String,
+        deprecated: bool,
+    },
+    // Migrates minters created by the factory to the version
+    MigrateContracts {
+        contract_type: ContractType,
+        version: String,
+        contracts: Vec<String>,
+        msg: Binary,
+    },
+    // Lets the factory admin migrate the minters the sender creates from now on
+    // Without it the admin of a contract is its wasm admin and migrates it
+    SetManagedUpgrades { enabled: bool },
+    // Sets who can create minters through the factory
+    UpdateCreatorAccessMode { mode: CreatorAccessMode },
+    // Approved creators are not restricted by the access mode
+    ApproveCreators { creators: Vec<String> },
+    RevokeCreators { creators: Vec<String> },
+    // Sets the minter creation fee discount of the creator, None removes it
+    SetCreatorFeeDiscount {
+        creator: String,
+        discount: Option<FeeDiscount>,
+    },
     Pause {},
     Unpause {},
     SetPausers { pausers: Vec<String> },
+    // Stops minting on every minter created by the factory, only pausers can set it
+    SetGlobalHalt { halted: bool },
 }
 
 #[cw_serde]
@@ -71,8 +130,12 @@ pub struct MinterFactoryParams {
     pub fee_collector_address: Addr,
     pub admin: Addr,
     pub product_label: String,
+    // Round whitelist factory used to create the whitelists of launches
+    pub whitelist_factory_address: Option<Addr>,
 }
 
+#[cw_ownable_query]
+#[allow(clippy::large_enum_variant)]
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
@@ -82,6 +145,79 @@ pub enum QueryMsg {
     IsPaused {},
     #[returns(Vec<Addr>)]
     Pausers {},
+    // Returns whether minting is halted on every minter created by the factory
+    #[returns(bool)]
+    GlobalHalt {},
     #[returns(Vec<Coin>)]
     MinterCreationFee {},
+    // Returns the funds required from the creator, after its discount
+    #[returns(Vec<Coin>)]
+    CreatorMinterCreationFee { creator: String },
+    // Returns the funds required from the creator to create a launch
+    #[returns(Vec<Coin>)]
+    LaunchCreationFee { creator: String },
+    // Returns whether the creator is approved and can create now
+    #[returns(CreatorStatus)]
+    CreatorStatus { creator: String },
+    #[returns(Vec<CreatorFeeDiscount>)]
+    CreatorFeeDiscounts {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<CodeVersion>)]
+    CodeVersions {
+        contract_type: ContractType,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(CodeVersion)]
+    CodeVersion {
+        contract_type: ContractType,
+        version: String,
+    },
+    // Returns the minters created by the creator
+    #[returns(Vec<CreatedContract>)]
+    ContractsByCreator {
+        creator: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<CreatedContract>)]
+    ContractsByType {
+        contract_type: ContractType,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<CreatedContract>)]
+    ContractsByCollectionId {
+        collection_id: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // Returns the contract if it was created by this factory, None otherwise
+    #[returns(Option<CreatedContract>)]
+    CreatedContract { address: String },
+    // Returns whether the factory is the wasm admin of the contracts the creator creates
+    #[returns(bool)]
+    ManagedUpgrades { creator: String },
+    // Returns the address the minter of the creator and collection id is instantiated at
+    // With a version, the address of the minter created with CreateVersionedMinter
+    #[returns(Addr)]
+    PredictMinterAddress {
+        creator: String,
+        collection_id: String,
+        version: Option<String>,
+    },
+    // Runs the checks of CreateMinter and of the minter without creating it
+    // Access and the fee discount are those of the creator, who would send CreateMinter
+    // With a version the checks of CreateVersionedMinter run with the code id of the version
+    #[returns(ValidationResponse)]
+    ValidateCreateMinter {
+        creator: String,
+        msg: CreateMinterMsg,
+        version: Option<String>,
+    },
 }
+
+#[cw_serde]
+pub struct MigrateMsg {}
@@ -1,4 +1,18 @@
-use crate::msg::MinterFactoryParams;
+use crate::msg::{CreateMinterMsg, MinterFactoryParams};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin};
 use cw_storage_plus::Item;
 
 pub const PARAMS: Item<MinterFactoryParams> = Item::new("params");
+
+// Minter of a launch, kept until the whitelist factory returns the whitelist address
+#[cw_serde]
+pub struct PendingLaunch {
+    pub creator: Addr,
+    pub minter: CreateMinterMsg,
+    // Code id the address of the minter was predicted with
+    pub code_id: u64,
+    pub collection_creation_fee: Coin,
+}
+
+pub const PENDING_LAUNCH: Item<PendingLaunch> = Item::new("pending_launch");
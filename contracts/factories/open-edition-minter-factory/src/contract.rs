@@ -1,19 +1,42 @@
 use crate::error::ContractError;
 use crate::msg::{
-    ExecuteMsg, InstantiateMsg, MultiMinterCreateMsg, OpenEditionMinterCreateMsg, ParamsResponse,
-    QueryMsg,
+    ExecuteMsg, InstantiateMsg, MigrateMsg, MultiMinterCreateMsg, MultiMinterParams,
+    OpenEditionMinterCreateMsg, OpenEditionMinterFactoryParams, ParamsResponse,
+    PredictedMinterAddresses, QueryMsg,
 };
-use crate::state::PARAMS;
+use crate::state::{PendingLaunch, PARAMS, PENDING_LAUNCH};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
-    Response, StdResult, Uint128, WasmMsg,
+    to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply,
+    Response, StdResult, SubMsg, Uint128, WasmMsg,
 };
+use cw2::set_contract_version;
+use cw_ownable::{get_ownership, initialize_owner, update_ownership, Action};
 use cw_utils::NativeBalance;
+use factory_types::access::{CreatorAccess, CreatorAccessMode, CreatorStatus};
+use factory_types::address::{minter_salt, predict_address};
+use factory_types::catalog::{CatalogError, CodeCatalog, CodeVersion};
 use factory_types::check_payment;
+use factory_types::discount::{CreatorDiscounts, CreatorFeeDiscount, FeeDiscount};
+use factory_types::launch::{whitelist_address_from_reply, CREATE_LAUNCH_WHITELIST_REPLY_ID};
+use factory_types::registry::{
+    ContractType, CreatedContract, PendingContract, Registry, CREATE_CONTRACT_REPLY_ID,
+};
+use factory_types::upgrades::ManagedUpgrades;
+use factory_types::validation::{Validation, ValidationResponse};
+use minter_types::config::Config;
 use minter_types::utils::check_collection_creation_fee;
+use omniflix_round_whitelist_factory::msg::{
+    ExecuteMsg as WhitelistFactoryExecuteMsg, QueryMsg as WhitelistFactoryQueryMsg,
+};
+use pauser::halt::GlobalHalt;
+use pauser::migration::migrate_version;
 use pauser::PauseState;
+use whitelist_types::{check_if_whitelist_is_active, CreateWhitelistMsg};
+
+const CONTRACT_NAME: &str = "crates.io:omniflix-open-edition-minter-factory";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -22,6 +45,7 @@ pub fn instantiate(
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     let admin = deps
         .api
         .addr_validate(&msg.params.clone().admin.into_string())
@@ -34,6 +58,8 @@ pub fn instantiate(
     pause_state.set_pausers(deps.storage, info.sender.clone(), vec![admin.clone()])?;
     let params = msg.params;
     PARAMS.save(deps.storage, &params)?;
+    // Admin is tracked as the owner for two-step ownership transfers
+    initialize_owner(deps.storage, deps.api, Some(admin.as_str()))?;
     Ok(Response::default())
 }
 
@@ -45,11 +71,22 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::CreateOpenEditionMinter { msg } => create_oem(deps, env, info, msg),
+        ExecuteMsg::CreateOpenEditionMinter { msg } => create_oem(deps, env, info, msg, None),
+        ExecuteMsg::CreateVersionedOpenEditionMinter { msg, version } => {
+            create_oem(deps, env, info, msg, Some(version))
+        }
         ExecuteMsg::CreateMultiMintOpenEditionMinter { msg } => {
-            create_multi_mint_oem(deps, env, info, msg)
+            create_multi_mint_oem(deps, env, info, msg, None)
+        }
+        ExecuteMsg::CreateVersionedMultiMintOpenEditionMinter { msg, version } => {
+            create_multi_mint_oem(deps, env, info, msg, Some(version))
         }
-        ExecuteMsg::UpdateAdmin { admin } => update_params_admin(deps, env, info, admin),
+        ExecuteMsg::CreateLaunch {
+            whitelist,
+            minter,
+            version,
+        } => create_launch(deps, env, info, whitelist, minter, version),
+        ExecuteMsg::UpdateOwnership(action) => update_params_ownership(deps, env, info, action),
         ExecuteMsg::UpdateFeeCollectorAddress {
             fee_collector_address,
         } => update_params_fee_collector_address(deps, env, info, fee_collector_address),
@@ -72,27 +109,68 @@ pub fn execute(
         ExecuteMsg::UpdateMultiMinterCodeId {
             multi_minter_code_id,
         } => update_params_multi_minter_code_id(deps, env, info, multi_minter_code_id),
+        ExecuteMsg::UpdateWhitelistFactoryAddress {
+            whitelist_factory_address,
+        } => update_params_whitelist_factory_address(deps, env, info, whitelist_factory_address),
+        ExecuteMsg::AddCodeVersion {
+            contract_type,
+            version,
+            code_id,
+            label,
+        } => add_code_version(deps, env, info, contract_type, version, code_id, label),
+        ExecuteMsg::SetCodeVersionDeprecated {
+            contract_type,
+            version,
+            deprecated,
+        } => set_code_version_deprecated(deps, env, info, contract_type, version, deprecated),
+        ExecuteMsg::MigrateContracts {
+            contract_type,
+            version,
+            contracts,
+            msg,
+        } => migrate_contracts(deps, env, info, contract_type, version, contracts, msg),
+        ExecuteMsg::SetManagedUpgrades { enabled } => {
+            set_managed_upgrades(deps, env, info, enabled)
+        }
+        ExecuteMsg::UpdateCreatorAccessMode { mode } => {
+            update_creator_access_mode(deps, env, info, mode)
+        }
+        ExecuteMsg::ApproveCreators { creators } => approve_creators(deps, env, info, creators),
+        ExecuteMsg::RevokeCreators { creators } => revoke_creators(deps, env, info, creators),
+        ExecuteMsg::SetCreatorFeeDiscount { creator, discount } => {
+            set_creator_fee_discount(deps, env, info, creator, discount)
+        }
         ExecuteMsg::Pause {} => execute_pause(deps, env, info),
         ExecuteMsg::Unpause {} => execute_unpause(deps, env, info),
         ExecuteMsg::SetPausers { pausers } => set_pausers(deps, env, info, pausers),
+        ExecuteMsg::SetGlobalHalt { halted } => set_global_halt(deps, env, info, halted),
     }
 }
 
 fn create_oem(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: OpenEditionMinterCreateMsg,
+    version: Option<String>,
 ) -> Result<Response, ContractError> {
     let pause_state = PauseState::new()?;
     pause_state.error_if_paused(deps.as_ref().storage)?;
+    // Creators are checked against the access mode of the factory
+    CreatorAccess::new().check(deps.storage, &info.sender, env.block.time)?;
     let params = PARAMS.load(deps.storage)?;
+    let code_id = resolve_oem_code_id(deps.as_ref(), &params, version)?;
     let collection_creation_fee: Coin = check_collection_creation_fee(deps.as_ref().querier)?;
+    let minter_creation_fee = creator_creation_fee(
+        deps.as_ref(),
+        &info.sender,
+        &params.open_edition_minter_creation_fee,
+    )?;
     check_payment(
         &info.funds,
         &[
             collection_creation_fee.clone(),
-            params.open_edition_minter_creation_fee.clone(),
+            minter_creation_fee.clone(),
         ],
     )?;
 
@@ -101,45 +179,178 @@ fn create_oem(
     auth_details.validate(&deps.as_ref())?;
 
     let mut msgs = Vec::<CosmosMsg>::new();
-    msgs.push(CosmosMsg::Wasm(WasmMsg::Instantiate {
-        admin: Some(msg.auth_details.admin.to_string()),
-        code_id: params.open_edition_minter_code_id,
-        msg: to_json_binary(&msg)?,
-        funds: vec![collection_creation_fee.clone()],
-        label: params.oem_product_label,
-    }));
-    if params.open_edition_minter_creation_fee.amount > Uint128::new(0) {
+    let (instantiate_msg, minter_address) = instantiate_oem_msg(
+        deps,
+        &env,
+        &info.sender,
+        msg,
+        code_id,
+        collection_creation_fee,
+    )?;
+    if minter_creation_fee.amount > Uint128::new(0) {
+        msgs.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: params.fee_collector_address.to_string(),
+            amount: vec![minter_creation_fee],
+        }));
+    }
+    let res = Response::new()
+        .add_submessage(instantiate_msg)
+        .add_messages(msgs)
+        .add_attribute("action", "create_minter")
+        .add_attribute("minter_address", minter_address);
+    Ok(res)
+}
+
+fn create_launch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mut whitelist: CreateWhitelistMsg,
+    minter: OpenEditionMinterCreateMsg,
+    version: Option<String>,
+) -> Result<Response, ContractError> {
+    let pause_state = PauseState::new()?;
+    pause_state.error_if_paused(deps.as_ref().storage)?;
+    // Creators are checked against the access mode of the factory
+    CreatorAccess::new().check(deps.storage, &info.sender, env.block.time)?;
+    let params = PARAMS.load(deps.storage)?;
+    let whitelist_factory_address = params
+        .whitelist_factory_address
+        .clone()
+        .ok_or(ContractError::WhitelistFactoryNotSet {})?;
+    let collection_creation_fee: Coin = check_collection_creation_fee(deps.as_ref().querier)?;
+    let minter_creation_fee = creator_creation_fee(
+        deps.as_ref(),
+        &info.sender,
+        &params.open_edition_minter_creation_fee,
+    )?;
+    let whitelist_creation_fee =
+        query_whitelist_creation_fee(deps.as_ref(), &info.sender, &whitelist_factory_address)?;
+    check_payment(
+        &info.funds,
+        &[
+            collection_creation_fee.clone(),
+            minter_creation_fee.clone(),
+            whitelist_creation_fee.clone(),
+        ],
+    )?;
+
+    // Validate auth details
+    minter.auth_details.validate(&deps.as_ref())?;
+    // Minter is checked before the whitelist is created for it
+    check_launch_minter(&env, &minter)?;
+
+    // Minter is added to the whitelist before it exists
+    let code_id = resolve_oem_code_id(deps.as_ref(), &params, version)?;
+    let (_, minter_address) = minter_salt_and_address(
+        deps.as_ref(),
+        &env,
+        &info.sender,
+        &minter.collection_details.id,
+        code_id,
+    )?;
+    whitelist
+        .minters
+        .get_or_insert_with(Vec::new)
+        .push(minter_address.to_string());
+
+    // Minter is instantiated once the whitelist factory returns the whitelist address
+    PENDING_LAUNCH.save(
+        deps.storage,
+        &PendingLaunch {
+            creator: info.sender.clone(),
+            minter,
+            code_id,
+            collection_creation_fee,
+        },
+    )?;
+    let mut whitelist_funds = vec![];
+    if !whitelist_creation_fee.amount.is_zero() {
+        whitelist_funds.push(whitelist_creation_fee);
+    }
+    // Whitelist is created for the creator so its discount, access and registry apply to them
+    let create_whitelist_msg = SubMsg::reply_on_success(
+        WasmMsg::Execute {
+            contract_addr: whitelist_factory_address.to_string(),
+            msg: to_json_binary(&WhitelistFactoryExecuteMsg::CreateWhitelistFor {
+                creator: info.sender.to_string(),
+                msg: whitelist,
+            })?,
+            funds: whitelist_funds,
+        },
+        CREATE_LAUNCH_WHITELIST_REPLY_ID,
+    );
+
+    let mut msgs = Vec::<CosmosMsg>::new();
+    if minter_creation_fee.amount > Uint128::new(0) {
         msgs.push(CosmosMsg::Bank(BankMsg::Send {
             to_address: params.fee_collector_address.to_string(),
-            amount: vec![params.open_edition_minter_creation_fee.clone()],
+            amount: vec![minter_creation_fee],
         }));
     }
     let res = Response::new()
+        .add_submessage(create_whitelist_msg)
         .add_messages(msgs)
-        .add_attribute("action", "create_minter");
+        .add_attribute("action", "create_launch")
+        .add_attribute("minter_address", minter_address);
     Ok(res)
 }
 
+// Runs the checks of the open edition minter instantiate that do not depend on the whitelist
+fn check_launch_minter(
+    env: &Env,
+    minter: &OpenEditionMinterCreateMsg,
+) -> Result<(), ContractError> {
+    let init = minter.init.clone().ok_or(ContractError::InitMissing {})?;
+    minter.collection_details.check_integrity()?;
+    minter
+        .token_details
+        .as_ref()
+        .ok_or(ContractError::InvalidTokenDetails {})?
+        .check_integrity()?;
+    // Whitelist address is set once the whitelist is created
+    let config = Config {
+        per_address_limit: init.per_address_limit,
+        start_time: init.start_time,
+        mint_price: init.mint_price,
+        whitelist_address: None,
+        end_time: init.end_time,
+        num_tokens: init.num_tokens,
+    };
+    config.check_integrity(env.block.time)?;
+    Ok(())
+}
+
 fn create_multi_mint_oem(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: MultiMinterCreateMsg,
+    version: Option<String>,
 ) -> Result<Response, ContractError> {
     let pause_state = PauseState::new()?;
     pause_state.error_if_paused(deps.as_ref().storage)?;
+    // Creators are checked against the access mode of the factory
+    CreatorAccess::new().check(deps.storage, &info.sender, env.block.time)?;
     let params = PARAMS.load(deps.storage)?;
     if params.multi_minter_params.is_none() {
         return Err(ContractError::MultiMinterNotEnabled {});
     }
     let multi_minter_params = params.multi_minter_params.unwrap();
+    let code_id = resolve_multi_mint_oem_code_id(deps.as_ref(), &multi_minter_params, version)?;
     let collection_creation_fee: Coin = check_collection_creation_fee(deps.as_ref().querier)?;
 
+    let minter_creation_fee = creator_creation_fee(
+        deps.as_ref(),
+        &info.sender,
+        &multi_minter_params.multi_minter_creation_fee,
+    )?;
+
     check_payment(
         &info.funds,
         &[
             collection_creation_fee.clone(),
-            multi_minter_params.multi_minter_creation_fee.clone(),
+            minter_creation_fee.clone(),
         ],
     )?;
     let mut msgs = Vec::<CosmosMsg>::new();
@@ -148,40 +359,195 @@ fn create_multi_mint_oem(
     let auth_details = msg.auth_details.clone();
     auth_details.validate(&deps.as_ref())?;
 
-    msgs.push(CosmosMsg::Wasm(WasmMsg::Instantiate {
-        admin: Some(auth_details.admin.to_string()),
-        code_id: multi_minter_params.multi_minter_code_id,
-        msg: to_json_binary(&msg)?,
-        funds: vec![collection_creation_fee.clone()],
-        label: multi_minter_params.multi_minter_product_label,
-    }));
-    if multi_minter_params.multi_minter_creation_fee.amount > Uint128::new(0) {
+    let (salt, minter_address) = minter_salt_and_address(
+        deps.as_ref(),
+        &env,
+        &info.sender,
+        &msg.collection_details.id,
+        code_id,
+    )?;
+    // Admin of the minter migrates it unless the creator opted into managed upgrades
+    let (wasm_admin, factory_admin) = ManagedUpgrades::new().wasm_admin(
+        deps.storage,
+        &info.sender,
+        &env.contract.address,
+        msg.auth_details.admin.as_str(),
+    );
+    let instantiate_msg = Registry::new().instantiate_msg(
+        deps.storage,
+        WasmMsg::Instantiate2 {
+            admin: Some(wasm_admin),
+            code_id,
+            label: multi_minter_params.multi_minter_product_label,
+            msg: to_json_binary(&msg)?,
+            funds: vec![collection_creation_fee.clone()],
+            salt,
+        },
+        PendingContract {
+            creator: info.sender.clone(),
+            code_id,
+            contract_type: ContractType::MultiMintOpenEditionMinter,
+            collection_id: Some(msg.collection_details.id.clone()),
+            factory_admin,
+        },
+    )?;
+    if minter_creation_fee.amount > Uint128::new(0) {
         msgs.push(CosmosMsg::Bank(BankMsg::Send {
             to_address: params.fee_collector_address.to_string(),
-            amount: vec![multi_minter_params.multi_minter_creation_fee.clone()],
+            amount: vec![minter_creation_fee],
         }));
     }
     let res = Response::new()
+        .add_submessage(instantiate_msg)
         .add_messages(msgs)
-        .add_attribute("action", "create_minter");
+        .add_attribute("action", "create_minter")
+        .add_attribute("minter_address", minter_address);
     Ok(res)
 }
 
-fn update_params_admin(
+// Returns the code id the open edition minter of the version is created with
+// Minters without a version use the default code id
+fn resolve_oem_code_id(
+    deps: Deps,
+    params: &OpenEditionMinterFactoryParams,
+    version: Option<String>,
+) -> Result<u64, ContractError> {
+    let code_id = match version {
+        Some(version) => CodeCatalog::new().active_code_id(
+            deps.storage,
+            &ContractType::OpenEditionMinter,
+            &version,
+        )?,
+        None => params.open_edition_minter_code_id,
+    };
+    Ok(code_id)
+}
+
+// Returns the code id the multi mint open edition minter of the version is created with
+// Minters without a version use the default code id
+fn resolve_multi_mint_oem_code_id(
+    deps: Deps,
+    multi_minter_params: &MultiMinterParams,
+    version: Option<String>,
+) -> Result<u64, ContractError> {
+    let code_id = match version {
+        Some(version) => CodeCatalog::new().active_code_id(
+            deps.storage,
+            &ContractType::MultiMintOpenEditionMinter,
+            &version,
+        )?,
+        None => multi_minter_params.multi_minter_code_id,
+    };
+    Ok(code_id)
+}
+
+// Returns the instantiate2 salt and the address of the minter
+// Salt is derived from the creator and collection id so a creator can not reuse a collection id
+fn minter_salt_and_address(
+    deps: Deps,
+    env: &Env,
+    creator: &Addr,
+    collection_id: &str,
+    code_id: u64,
+) -> Result<(Binary, Addr), ContractError> {
+    let salt = minter_salt(creator, collection_id);
+    let minter_address = predict_address(deps, &env.contract.address, code_id, &salt)?;
+    let existing = Registry::new().load(deps.storage, &minter_address)?;
+    if existing.is_some() {
+        return Err(ContractError::SaltCollision {
+            address: minter_address.into_string(),
+        });
+    }
+    Ok((salt, minter_address))
+}
+
+// Returns the instantiate2 message of the open edition minter and the address it is instantiated at
+fn instantiate_oem_msg(
     deps: DepsMut,
-    _env: Env,
+    env: &Env,
+    creator: &Addr,
+    msg: OpenEditionMinterCreateMsg,
+    code_id: u64,
+    collection_creation_fee: Coin,
+) -> Result<(SubMsg, Addr), ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    let (salt, minter_address) = minter_salt_and_address(
+        deps.as_ref(),
+        env,
+        creator,
+        &msg.collection_details.id,
+        code_id,
+    )?;
+    // Admin of the minter migrates it unless the creator opted into managed upgrades
+    let (wasm_admin, factory_admin) = ManagedUpgrades::new().wasm_admin(
+        deps.storage,
+        creator,
+        &env.contract.address,
+        msg.auth_details.admin.as_str(),
+    );
+    // Minter is registered once the instantiate reply returns its address
+    let instantiate_msg = Registry::new().instantiate_msg(
+        deps.storage,
+        WasmMsg::Instantiate2 {
+            admin: Some(wasm_admin),
+            code_id,
+            label: params.oem_product_label,
+            msg: to_json_binary(&msg)?,
+            funds: vec![collection_creation_fee],
+            salt,
+        },
+        PendingContract {
+            creator: creator.clone(),
+            code_id,
+            contract_type: ContractType::OpenEditionMinter,
+            collection_id: Some(msg.collection_details.id.clone()),
+            factory_admin,
+        },
+    )?;
+    Ok((instantiate_msg, minter_address))
+}
+
+// Returns the minter creation fee after the discount of the creator
+fn creator_creation_fee(
+    deps: Deps,
+    creator: &Addr,
+    minter_creation_fee: &Coin,
+) -> Result<Coin, ContractError> {
+    let fee = CreatorDiscounts::new()
+        .creator_fee(deps.storage, creator, minter_creation_fee)?;
+    Ok(fee)
+}
+
+// Returns the fee the whitelist factory charges the creator for creating a whitelist
+fn query_whitelist_creation_fee(
+    deps: Deps,
+    creator: &Addr,
+    whitelist_factory_address: &Addr,
+) -> Result<Coin, ContractError> {
+    let fee: Coin = deps.querier.query_wasm_smart(
+        whitelist_factory_address,
+        &WhitelistFactoryQueryMsg::CreatorWhitelistCreationFee {
+            creator: creator.to_string(),
+        },
+    )?;
+    Ok(fee)
+}
+
+fn update_params_ownership(
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    admin: String,
+    action: Action,
 ) -> Result<Response, ContractError> {
+    let ownership = update_ownership(deps.branch(), &env.block, &info.sender, action)?;
+    // Keep the admin of params in sync with the owner
+    // If ownership is renounced, admin is set to the contract itself so no one can act as admin
     let mut params = PARAMS.load(deps.storage)?;
-    if params.admin != info.sender {
-        return Err(ContractError::Unauthorized {});
-    }
-    params.admin = deps.api.addr_validate(&admin)?;
+    params.admin = ownership.owner.clone().unwrap_or(env.contract.address);
     PARAMS.save(deps.storage, &params)?;
     Ok(Response::default()
-        .add_attribute("action", "update_admin")
-        .add_attribute("new_admin", admin))
+        .add_attribute("action", "update_ownership")
+        .add_attributes(ownership.into_attributes()))
 }
 
 fn update_params_fee_collector_address(
@@ -224,6 +590,172 @@ fn update_params_open_edition_minter_code_id(
         ))
 }
 
+fn update_creator_access_mode(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    mode: CreatorAccessMode,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    CreatorAccess::new().mode.save(deps.storage, &mode)?;
+    Ok(Response::default().add_attribute("action", "update_creator_access_mode"))
+}
+
+fn approve_creators(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    creators: Vec<String>,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let creators = creators
+        .iter()
+        .map(|creator| deps.api.addr_validate(creator))
+        .collect::<StdResult<Vec<Addr>>>()?;
+    CreatorAccess::new().approve(deps.storage, &creators)?;
+    Ok(Response::default()
+        .add_attribute("action", "approve_creators")
+        .add_attribute("creators", creators.len().to_string()))
+}
+
+fn revoke_creators(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    creators: Vec<String>,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let creators = creators
+        .iter()
+        .map(|creator| deps.api.addr_validate(creator))
+        .collect::<StdResult<Vec<Addr>>>()?;
+    CreatorAccess::new().revoke(deps.storage, &creators);
+    Ok(Response::default()
+        .add_attribute("action", "revoke_creators")
+        .add_attribute("creators", creators.len().to_string()))
+}
+
+fn set_creator_fee_discount(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    creator: String,
+    discount: Option<FeeDiscount>,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let creator = deps.api.addr_validate(&creator)?;
+    CreatorDiscounts::new()
+        .set(deps.storage, &creator, discount)?;
+    Ok(Response::default()
+        .add_attribute("action", "set_creator_fee_discount")
+        .add_attribute("creator", creator))
+}
+
+fn add_code_version(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    contract_type: ContractType,
+    version: String,
+    code_id: u64,
+    label: String,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    // Code id must be stored on chain
+    deps.querier.query_wasm_code_info(code_id)?;
+    CodeCatalog::new().add(
+        deps.storage,
+        CodeVersion {
+            contract_type,
+            version: version.clone(),
+            code_id,
+            label,
+            deprecated: false,
+        },
+        &[
+            ContractType::OpenEditionMinter,
+            ContractType::MultiMintOpenEditionMinter,
+        ],
+    )?;
+    Ok(Response::default()
+        .add_attribute("action", "add_code_version")
+        .add_attribute("version", version)
+        .add_attribute("code_id", code_id.to_string()))
+}
+
+fn set_code_version_deprecated(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    contract_type: ContractType,
+    version: String,
+    deprecated: bool,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    CodeCatalog::new().set_deprecated(deps.storage, &contract_type, &version, deprecated)?;
+    Ok(Response::default()
+        .add_attribute("action", "set_code_version_deprecated")
+        .add_attribute("version", version)
+        .add_attribute("deprecated", deprecated.to_string()))
+}
+
+fn migrate_contracts(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    contract_type: ContractType,
+    version: String,
+    contracts: Vec<String>,
+    msg: Binary,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    let contracts = contracts
+        .iter()
+        .map(|contract| deps.api.addr_validate(contract))
+        .collect::<StdResult<Vec<Addr>>>()?;
+    let migrate_msgs =
+        CodeCatalog::new().migrate_msgs(deps.storage, &contract_type, &version, &contracts, msg)?;
+    Ok(Response::default()
+        .add_messages(migrate_msgs)
+        .add_attribute("action", "migrate_contracts")
+        .add_attribute("version", version)
+        .add_attribute("contracts", contracts.len().to_string()))
+}
+
+fn set_managed_upgrades(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    ManagedUpgrades::new().set(deps.storage, &info.sender, enabled)?;
+    Ok(Response::default()
+        .add_attribute("action", "set_managed_upgrades")
+        .add_attribute("creator", info.sender)
+        .add_attribute("enabled", enabled.to_string()))
+}
+
 fn update_params_open_edition_minter_creation_fee(
     deps: DepsMut,
     _env: Env,
@@ -297,6 +829,31 @@ fn update_params_multi_minter_code_id(
         .add_attribute("action", "update_multi_minter_code_id")
         .add_attribute("new_multi_minter_code_id", multi_minter_code_id.to_string()))
 }
+fn update_params_whitelist_factory_address(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    whitelist_factory_address: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut params = PARAMS.load(deps.storage)?;
+    if params.admin != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    params.whitelist_factory_address = whitelist_factory_address
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    PARAMS.save(deps.storage, &params)?;
+    Ok(Response::default()
+        .add_attribute("action", "update_whitelist_factory_address")
+        .add_attribute(
+            "new_whitelist_factory_address",
+            params
+                .whitelist_factory_address
+                .map(|address| address.to_string())
+                .unwrap_or_default(),
+        ))
+}
+
 fn execute_pause(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
     let pause_state = PauseState::new()?;
     pause_state.pause(deps.storage, &info.sender)?;
@@ -331,21 +888,176 @@ fn set_pausers(
         .add_attribute("pausers", pausers.join(",")))
 }
 
+fn set_global_halt(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    halted: bool,
+) -> Result<Response, ContractError> {
+    GlobalHalt::new().set_halted(deps.storage, &info.sender, halted)?;
+    Ok(Response::default()
+        .add_attribute("action", "set_global_halt")
+        .add_attribute("halted", halted.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        CREATE_CONTRACT_REPLY_ID => {
+            let contract = Registry::new().register(deps.storage, msg, env.block.time)?;
+            Ok(Response::new()
+                .add_attribute("action", "register_contract")
+                .add_attribute("contract_address", contract.address)
+                .add_attribute("creator", contract.creator))
+        }
+        CREATE_LAUNCH_WHITELIST_REPLY_ID => {
+            let whitelist_address = whitelist_address_from_reply(msg)?;
+            let mut launch = PENDING_LAUNCH.load(deps.storage)?;
+            PENDING_LAUNCH.remove(deps.storage);
+            let init = launch
+                .minter
+                .init
+                .as_mut()
+                .ok_or(ContractError::InitMissing {})?;
+            init.whitelist_address = Some(whitelist_address.to_string());
+            // Minter is instantiated with the code id its address was predicted with
+            let (instantiate_msg, minter_address) = instantiate_oem_msg(
+                deps,
+                &env,
+                &launch.creator,
+                launch.minter,
+                launch.code_id,
+                launch.collection_creation_fee,
+            )?;
+            Ok(Response::new()
+                .add_submessage(instantiate_msg)
+                .add_attribute("action", "create_launch")
+                .add_attribute("whitelist_address", whitelist_address)
+                .add_attribute("minter_address", minter_address)
+                .add_attribute("creator", launch.creator))
+        }
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    // Factories instantiated before ownership was tracked have no owner yet
+    if get_ownership(deps.storage).is_err() {
+        let params = PARAMS.load(deps.storage)?;
+        initialize_owner(deps.storage, deps.api, Some(params.admin.as_str()))?;
+    }
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Params {} => to_json_binary(&query_params(deps)?),
+        QueryMsg::Ownership {} => to_json_binary(&get_ownership(deps.storage)?),
         QueryMsg::IsPaused {} => to_json_binary(&query_is_paused(deps, _env)?),
         QueryMsg::Pausers {} => to_json_binary(&query_pausers(deps, _env)?),
+        QueryMsg::GlobalHalt {} => to_json_binary(&GlobalHalt::new().is_halted(deps.storage)?),
         QueryMsg::OpenEditionMinterCreationFee {} => {
             to_json_binary(&query_open_edition_minter_creation_fee(deps)?)
         }
         QueryMsg::MultiMinterCreationFee {} => {
             to_json_binary(&query_multi_minter_creation_fee(deps)?)
         }
+        QueryMsg::CreatorOpenEditionMinterCreationFee { creator } => {
+            to_json_binary(&query_creator_open_edition_minter_creation_fee(deps, creator)?)
+        }
+        QueryMsg::CreatorMultiMinterCreationFee { creator } => {
+            to_json_binary(&query_creator_multi_minter_creation_fee(deps, creator)?)
+        }
+        QueryMsg::LaunchCreationFee { creator } => {
+            to_json_binary(&query_launch_creation_fee(deps, creator)?)
+        }
+        QueryMsg::CreatorStatus { creator } => {
+            to_json_binary(&query_creator_status(deps, _env, creator)?)
+        }
+        QueryMsg::CreatorFeeDiscounts { start_after, limit } => {
+            to_json_binary(&query_creator_fee_discounts(deps, start_after, limit)?)
+        }
+        QueryMsg::CodeVersions {
+            contract_type,
+            start_after,
+            limit,
+        } => to_json_binary(&query_code_versions(deps, contract_type, start_after, limit)?),
+        QueryMsg::CodeVersion {
+            contract_type,
+            version,
+        } => to_json_binary(&query_code_version(deps, contract_type, version)?),
+        QueryMsg::ContractsByCreator {
+            creator,
+            start_after,
+            limit,
+        } => to_json_binary(&query_contracts_by_creator(deps, creator, start_after, limit)?),
+        QueryMsg::ContractsByType {
+            contract_type,
+            start_after,
+            limit,
+        } => to_json_binary(&query_contracts_by_type(deps, contract_type, start_after, limit)?),
+        QueryMsg::ContractsByCollectionId {
+            collection_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_contracts_by_collection_id(
+            deps,
+            collection_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::CreatedContract { address } => {
+            to_json_binary(&query_created_contract(deps, address)?)
+        }
+        QueryMsg::ManagedUpgrades { creator } => {
+            to_json_binary(&query_managed_upgrades(deps, creator)?)
+        }
+        QueryMsg::PredictMinterAddress {
+            creator,
+            collection_id,
+            version,
+        } => to_json_binary(&query_predict_minter_address(
+            deps,
+            _env,
+            creator,
+            collection_id,
+            version,
+        )?),
+        QueryMsg::ValidateCreateOpenEditionMinter {
+            creator,
+            msg,
+            version,
+        } => to_json_binary(&query_validate_create_oem(
+            deps,
+            _env,
+            creator,
+            msg,
+            version,
+        )?),
     }
 }
 
+fn query_code_versions(
+    deps: Deps,
+    contract_type: ContractType,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CodeVersion>> {
+    CodeCatalog::new().list(deps.storage, &contract_type, start_after, limit)
+}
+
+fn query_code_version(
+    deps: Deps,
+    contract_type: ContractType,
+    version: String,
+) -> Result<CodeVersion, ContractError> {
+    let code_version = CodeCatalog::new().load(deps.storage, &contract_type, &version)?;
+    Ok(code_version)
+}
+
 fn query_params(deps: Deps) -> StdResult<ParamsResponse> {
     let params = PARAMS.load(deps.storage)?;
     Ok(ParamsResponse { params })
@@ -386,6 +1098,236 @@ fn query_multi_minter_creation_fee(deps: Deps) -> Result<Vec<Coin>, ContractErro
     Ok(fees.into_vec())
 }
 
+fn query_creator_open_edition_minter_creation_fee(
+    deps: Deps,
+    creator: String,
+) -> Result<Vec<Coin>, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    let creator = deps.api.addr_validate(&creator)?;
+    let minter_creation_fee =
+        creator_creation_fee(deps, &creator, &params.open_edition_minter_creation_fee)?;
+    let collection_creation_fee: Coin = check_collection_creation_fee(deps.querier)?;
+    let mut fees = NativeBalance::default();
+    fees += collection_creation_fee;
+    fees += minter_creation_fee;
+    fees.normalize();
+    Ok(fees.into_vec())
+}
+
+fn query_creator_multi_minter_creation_fee(
+    deps: Deps,
+    creator: String,
+) -> Result<Vec<Coin>, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    let creator = deps.api.addr_validate(&creator)?;
+    let multi_minter_params = params
+        .multi_minter_params
+        .ok_or(ContractError::MultiMinterNotEnabled {})?;
+    let minter_creation_fee = creator_creation_fee(
+        deps,
+        &creator,
+        &multi_minter_params.multi_minter_creation_fee,
+    )?;
+    let collection_creation_fee: Coin = check_collection_creation_fee(deps.querier)?;
+    let mut fees = NativeBalance::default();
+    fees += collection_creation_fee;
+    fees += minter_creation_fee;
+    fees.normalize();
+    Ok(fees.into_vec())
+}
+
+fn query_launch_creation_fee(deps: Deps, creator: String) -> Result<Vec<Coin>, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    let creator = deps.api.addr_validate(&creator)?;
+    let whitelist_factory_address = params
+        .whitelist_factory_address
+        .ok_or(ContractError::WhitelistFactoryNotSet {})?;
+    let minter_creation_fee =
+        creator_creation_fee(deps, &creator, &params.open_edition_minter_creation_fee)?;
+    let collection_creation_fee: Coin = check_collection_creation_fee(deps.querier)?;
+    let whitelist_creation_fee =
+        query_whitelist_creation_fee(deps, &creator, &whitelist_factory_address)?;
+    let mut fees = NativeBalance::default();
+    fees += minter_creation_fee;
+    fees += collection_creation_fee;
+    fees += whitelist_creation_fee;
+    fees.normalize();
+    Ok(fees.into_vec())
+}
+
+fn query_creator_status(deps: Deps, env: Env, creator: String) -> StdResult<CreatorStatus> {
+    let creator = deps.api.addr_validate(&creator)?;
+    CreatorAccess::new().status(deps.storage, &creator, env.block.time)
+}
+
+fn query_creator_fee_discounts(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CreatorFeeDiscount>> {
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    CreatorDiscounts::new()
+        .list(deps.storage, start_after, limit)
+}
+
+fn query_contracts_by_creator(
+    deps: Deps,
+    creator: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CreatedContract>> {
+    let creator = deps.api.addr_validate(&creator)?;
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    Registry::new().by_creator(deps.storage, creator, start_after, limit)
+}
+
+fn query_contracts_by_type(
+    deps: Deps,
+    contract_type: ContractType,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CreatedContract>> {
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    Registry::new().by_type(deps.storage, contract_type, start_after, limit)
+}
+
+fn query_contracts_by_collection_id(
+    deps: Deps,
+    collection_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CreatedContract>> {
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    Registry::new().by_collection_id(deps.storage, collection_id, start_after, limit)
+}
+
+fn query_created_contract(deps: Deps, address: String) -> StdResult<Option<CreatedContract>> {
+    let address = deps.api.addr_validate(&address)?;
+    Registry::new().load(deps.storage, &address)
+}
+
+fn query_managed_upgrades(deps: Deps, creator: String) -> StdResult<bool> {
+    let creator = deps.api.addr_validate(&creator)?;
+    Ok(ManagedUpgrades::new().is_enabled(deps.storage, &creator))
+}
+
+fn query_predict_minter_address(
+    deps: Deps,
+    env: Env,
+    creator: String,
+    collection_id: String,
+    version: Option<String>,
+) -> Result<PredictedMinterAddresses, ContractError> {
+    let creator = deps.api.addr_validate(&creator)?;
+    let params = PARAMS.load(deps.storage)?;
+    let salt = minter_salt(&creator, &collection_id);
+    // Same code ids as the create messages of each minter type
+    let oem_code_id = catalogued_code_id(resolve_oem_code_id(deps, &params, version.clone()))?;
+    let multi_mint_oem_code_id = match &params.multi_minter_params {
+        Some(multi_minter_params) => {
+            let code_id = resolve_multi_mint_oem_code_id(deps, multi_minter_params, version);
+            catalogued_code_id(code_id)?
+        }
+        None => None,
+    };
+    let predict = |code_id| predict_address(deps, &env.contract.address, code_id, &salt);
+    Ok(PredictedMinterAddresses {
+        open_edition_minter: oem_code_id.map(&predict).transpose()?,
+        multi_mint_open_edition_minter: multi_mint_oem_code_id.map(&predict).transpose()?,
+    })
+}
+
+// Versions are catalogued per minter type, None if the minter type has no such version
+fn catalogued_code_id(code_id: Result<u64, ContractError>) -> Result<Option<u64>, ContractError> {
+    match code_id {
+        Ok(code_id) => Ok(Some(code_id)),
+        Err(ContractError::Catalog(CatalogError::VersionNotFound { .. })) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+// Runs the checks of create_oem and of the minter instantiate, collecting every problem
+fn query_validate_create_oem(
+    deps: Deps,
+    env: Env,
+    creator: String,
+    msg: OpenEditionMinterCreateMsg,
+    version: Option<String>,
+) -> Result<ValidationResponse, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    let creator = deps.api.addr_validate(&creator)?;
+    let mut validation = Validation::new();
+
+    // Checks of the factory
+    validation.check(PauseState::new()?.error_if_paused(deps.storage));
+    validation.check(CreatorAccess::new().check(deps.storage, &creator, env.block.time));
+    // Same code id as CreateOpenEditionMinter and CreateVersionedOpenEditionMinter
+    if let Some(code_id) = validation.check(resolve_oem_code_id(deps, &params, version)) {
+        validation.check(minter_salt_and_address(
+            deps,
+            &env,
+            &creator,
+            &msg.collection_details.id,
+            code_id,
+        ));
+    }
+    if let Some(collection_creation_fee) =
+        validation.check(check_collection_creation_fee(deps.querier))
+    {
+        validation.require_funds(collection_creation_fee);
+    }
+    if let Some(minter_creation_fee) = validation.check(creator_creation_fee(
+        deps,
+        &creator,
+        &params.open_edition_minter_creation_fee,
+    )) {
+        validation.require_funds(minter_creation_fee);
+    }
+    validation.check(msg.auth_details.validate(&deps));
+
+    // Checks of the open edition minter
+    validation.check(msg.collection_details.check_integrity());
+    match &msg.token_details {
+        Some(token_details) => {
+            validation.check(token_details.check_integrity());
+        }
+        None => validation.error(ContractError::InvalidTokenDetails {}),
+    }
+    match msg.init {
+        Some(init) => {
+            let whitelist_address = init
+                .whitelist_address
+                .and_then(|address| validation.check(deps.api.addr_validate(&address)));
+            if let Some(whitelist_address) = &whitelist_address {
+                let is_active =
+                    validation.check(check_if_whitelist_is_active(whitelist_address, deps));
+                if is_active == Some(true) {
+                    validation.error(ContractError::WhitelistAlreadyActive {});
+                }
+            }
+            let config = Config {
+                per_address_limit: init.per_address_limit,
+                start_time: init.start_time,
+                mint_price: init.mint_price,
+                whitelist_address,
+                end_time: init.end_time,
+                num_tokens: init.num_tokens,
+            };
+            validation.check(config.check_integrity(env.block.time));
+        }
+        None => validation.error(ContractError::InitMissing {}),
+    }
+    Ok(validation.into_response())
+}
+
 #[cfg(test)]
 mod open_edition_minter_factory_test {
     use crate::msg::{MultiMinterParams, OpenEditionMinterFactoryParams};
@@ -395,6 +1337,7 @@ mod open_edition_minter_factory_test {
         testing::{mock_dependencies, mock_env, mock_info},
         Addr,
     };
+    use cw_ownable::OwnershipError;
     use pauser::PauseError;
 
     #[test]
@@ -418,6 +1361,7 @@ mod open_edition_minter_factory_test {
                     denom: "uusd".to_string(),
                 },
                 oem_product_label: "omniflix-open-edition-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
 
@@ -445,6 +1389,7 @@ mod open_edition_minter_factory_test {
                     denom: "uusd".to_string(),
                 },
                 oem_product_label: "omniflix-open-edition-minter".to_string(),
+                whitelist_factory_address: None,
             }
         );
     }
@@ -469,30 +1414,47 @@ mod open_edition_minter_factory_test {
                     denom: "uusd".to_string(),
                 },
                 oem_product_label: "omniflix-open-edition-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
 
         let info = mock_info("creator", &[]);
         let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
-        // Non admin can not update admin
+        // Non admin can not transfer ownership
+        let transfer_msg = ExecuteMsg::UpdateOwnership(Action::TransferOwnership {
+            new_owner: "new_admin".to_string(),
+            expiry: None,
+        });
         let info = mock_info("non_admin", &[]);
-        let msg = ExecuteMsg::UpdateAdmin {
-            admin: "new_admin".to_string(),
-        };
-        let res = execute(deps.as_mut(), mock_env(), info, msg);
-        assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+        let res = execute(deps.as_mut(), mock_env(), info, transfer_msg.clone());
+        assert_eq!(res.unwrap_err(), ContractError::Ownership(OwnershipError::NotOwner));
 
-        // admin can update admin
+        // Admin can propose a new admin
         let info = mock_info("admin", &[]);
-        let msg = ExecuteMsg::UpdateAdmin {
-            admin: "new_admin".to_string(),
-        };
-        let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        let _res = execute(deps.as_mut(), mock_env(), info, transfer_msg).unwrap();
+
+        // Admin is not changed until the transfer is accepted
+        let params = query_params(deps.as_ref()).unwrap();
+        assert_eq!(params.params.admin, Addr::unchecked("admin"));
+
+        // Only the pending owner can accept
+        let accept_msg = ExecuteMsg::UpdateOwnership(Action::AcceptOwnership);
+        let info = mock_info("non_admin", &[]);
+        let res = execute(deps.as_mut(), mock_env(), info, accept_msg.clone());
+        assert_eq!(res.unwrap_err(), ContractError::Ownership(OwnershipError::NotPendingOwner));
+
+        // New admin accepts the ownership
+        let info = mock_info("new_admin", &[]);
+        let _res = execute(deps.as_mut(), mock_env(), info, accept_msg).unwrap();
 
         // query params
         let params = query_params(deps.as_ref()).unwrap();
         assert_eq!(params.params.admin, Addr::unchecked("new_admin"));
+
+        let ownership = get_ownership(deps.as_ref().storage).unwrap();
+        assert_eq!(ownership.owner, Some(Addr::unchecked("new_admin")));
+        assert_eq!(ownership.pending_owner, None);
     }
 
     #[test]
@@ -516,6 +1478,7 @@ mod open_edition_minter_factory_test {
                     denom: "uusd".to_string(),
                 },
                 oem_product_label: "omniflix-open-edition-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
 
@@ -566,6 +1529,7 @@ mod open_edition_minter_factory_test {
                     denom: "uusd".to_string(),
                 },
                 oem_product_label: "omniflix-open-edition-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
 
@@ -613,6 +1577,7 @@ mod open_edition_minter_factory_test {
                     denom: "uusd".to_string(),
                 },
                 oem_product_label: "omniflix-open-edition-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
 
@@ -672,6 +1637,7 @@ mod open_edition_minter_factory_test {
                     denom: "uusd".to_string(),
                 },
                 oem_product_label: "omniflix-open-edition-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
 
@@ -735,6 +1701,7 @@ mod open_edition_minter_factory_test {
                     denom: "uusd".to_string(),
                 },
                 oem_product_label: "omniflix-open-edition-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
 
@@ -789,6 +1756,7 @@ mod open_edition_minter_factory_test {
                     denom: "uusd".to_string(),
                 },
                 oem_product_label: "omniflix-open-edition-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
 
@@ -857,6 +1825,7 @@ mod open_edition_minter_factory_test {
                     denom: "uusd".to_string(),
                 },
                 oem_product_label: "omniflix-open-edition-minter".to_string(),
+                whitelist_factory_address: None,
             },
         };
 
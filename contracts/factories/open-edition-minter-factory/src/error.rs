@@ -1,5 +1,13 @@
 use cosmwasm_std::StdError;
+use cw_ownable::OwnershipError;
+use factory_types::access::AccessError;
+use factory_types::catalog::CatalogError;
 use factory_types::CustomPaymentError;
+use minter_types::collection_details::CollectionDetailsError;
+use minter_types::config::ConfigurationError;
+use minter_types::token_details::TokenDetailsError;
+use pauser::halt::HaltError;
+use pauser::migration::MigrationError;
 use pauser::PauseError;
 use thiserror::Error;
 
@@ -15,6 +23,30 @@ pub enum ContractError {
     #[error(transparent)]
     Pause(#[from] PauseError),
 
+    #[error(transparent)]
+    Halt(#[from] HaltError),
+
+    #[error(transparent)]
+    Ownership(#[from] OwnershipError),
+
+    #[error(transparent)]
+    Access(#[from] AccessError),
+
+    #[error(transparent)]
+    Catalog(#[from] CatalogError),
+
+    #[error(transparent)]
+    Migration(#[from] MigrationError),
+
+    #[error(transparent)]
+    CollectionDetails(#[from] CollectionDetailsError),
+
+    #[error(transparent)]
+    TokenDetails(#[from] TokenDetailsError),
+
+    #[error(transparent)]
+    Configuration(#[from] ConfigurationError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -35,6 +67,24 @@ pub enum ContractError {
 
     #[error("MultiMinter not enabled")]
     MultiMinterNotEnabled {},
+
+    #[error("Whitelist factory is not set")]
+    WhitelistFactoryNotSet {},
+
+    #[error("Collection id is already used by the creator, minter exists at {address}")]
+    SaltCollision { address: String },
+
+    #[error("Init is missing")]
+    InitMissing {},
+
+    #[error("Invalid token details")]
+    InvalidTokenDetails {},
+
+    #[error("Whitelist is already active")]
+    WhitelistAlreadyActive {},
+
+    #[error("Unknown reply id {id}")]
+    UnknownReplyId { id: u64 },
 }
 impl From<ContractError> for StdError {
     fn from(err: ContractError) -> StdError {
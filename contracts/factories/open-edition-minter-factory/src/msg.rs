@@ -1,6 +1,13 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Coin, Empty, Timestamp};
+use cosmwasm_std::{Addr, Binary, Coin, Empty, Timestamp};
+use cw_ownable::{cw_ownable_execute, cw_ownable_query};
+use factory_types::access::{CreatorAccessMode, CreatorStatus};
+use factory_types::catalog::CodeVersion;
+use factory_types::discount::{CreatorFeeDiscount, FeeDiscount};
+use factory_types::registry::{ContractType, CreatedContract};
+use factory_types::validation::ValidationResponse;
 use minter_types::msg::MinterInstantiateMsg;
+use whitelist_types::CreateWhitelistMsg;
 #[cw_serde]
 pub struct InstantiateMsg {
     pub params: OpenEditionMinterFactoryParams,
@@ -19,17 +26,31 @@ pub struct OpenEditionMinterInitExtention {
 pub type OpenEditionMinterCreateMsg = MinterInstantiateMsg<OpenEditionMinterInitExtention>;
 pub type MultiMinterCreateMsg = MinterInstantiateMsg<Empty>;
 
+#[cw_ownable_execute]
 #[allow(clippy::large_enum_variant)]
 #[cw_serde]
 pub enum ExecuteMsg {
     CreateOpenEditionMinter {
         msg: OpenEditionMinterCreateMsg,
     },
+    // Creates the open edition minter with the code id of a version in the code catalog
+    CreateVersionedOpenEditionMinter {
+        msg: OpenEditionMinterCreateMsg,
+        version: String,
+    },
     CreateMultiMintOpenEditionMinter {
         msg: MultiMinterCreateMsg,
     },
-    UpdateAdmin {
-        admin: String,
+    CreateVersionedMultiMintOpenEditionMinter {
+        msg: MultiMinterCreateMsg,
+        version: String,
+    },
+    // Creates the whitelist through the whitelist factory and the open edition minter wired to it
+    CreateLaunch {
+        whitelist: CreateWhitelistMsg,
+        minter: OpenEditionMinterCreateMsg,
+        // Version of the code catalog the minter is created with, None for the default code id
+        version: Option<String>,
     },
     UpdateFeeCollectorAddress {
         fee_collector_address: String,
@@ -46,11 +67,49 @@ pub enum ExecuteMsg {
     UpdateMultiMinterCodeId {
         multi_minter_code_id: u64,
     },
+    UpdateWhitelistFactoryAddress {
+        whitelist_factory_address: Option<String>,
+    },
+    // Adds a code id to the code catalog under the version
+    AddCodeVersion {
+        contract_type: ContractType,
+        version: String,
+        code_id: u64,
+        label: String,
+    },
+    // Deprecated versions can not be used to create or migrate minters
+    SetCodeVersionDeprecated {
+        contract_type: ContractType,
+        version: String,
+        deprecated: bool,
+    },
+    // Migrates minters created by the factory to the version
+    MigrateContracts {
+        contract_type: ContractType,
+        version: String,
+        contracts: Vec<String>,
+        msg: Binary,
+    },
+    // Lets the factory admin migrate the minters the sender creates from now on
+    // Without it the admin of a contract is its wasm admin and migrates it
+    SetManagedUpgrades { enabled: bool },
+    // Sets who can create minters through the factory
+    UpdateCreatorAccessMode { mode: CreatorAccessMode },
+    // Approved creators are not restricted by the access mode
+    ApproveCreators { creators: Vec<String> },
+    RevokeCreators { creators: Vec<String> },
+    // Sets the minter creation fee discount of the creator, None removes it
+    SetCreatorFeeDiscount {
+        creator: String,
+        discount: Option<FeeDiscount>,
+    },
     Pause {},
     Unpause {},
     SetPausers {
         pausers: Vec<String>,
     },
+    // Stops minting on every minter created by the factory, only pausers can set it
+    SetGlobalHalt { halted: bool },
 }
 
 #[cw_serde]
@@ -71,8 +130,20 @@ pub struct OpenEditionMinterFactoryParams {
     pub admin: Addr,
     pub oem_product_label: String,
     pub multi_minter_params: Option<MultiMinterParams>,
+    // Round whitelist factory used to create the whitelists of launches
+    pub whitelist_factory_address: Option<Addr>,
 }
 
+#[cw_serde]
+pub struct PredictedMinterAddresses {
+    // None if the requested version is not catalogued for open edition minters
+    pub open_edition_minter: Option<Addr>,
+    // None if multi minters are not enabled or the requested version is not catalogued for them
+    pub multi_mint_open_edition_minter: Option<Addr>,
+}
+
+#[cw_ownable_query]
+#[allow(clippy::large_enum_variant)]
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
@@ -82,8 +153,83 @@ pub enum QueryMsg {
     IsPaused {},
     #[returns(Vec<Addr>)]
     Pausers {},
+    // Returns whether minting is halted on every minter created by the factory
+    #[returns(bool)]
+    GlobalHalt {},
     #[returns(Vec<Coin>)]
     OpenEditionMinterCreationFee {},
     #[returns(Vec<Coin>)]
     MultiMinterCreationFee {},
+    // Returns the funds required from the creator, after its discount
+    #[returns(Vec<Coin>)]
+    CreatorOpenEditionMinterCreationFee { creator: String },
+    #[returns(Vec<Coin>)]
+    CreatorMultiMinterCreationFee { creator: String },
+    // Returns the funds required from the creator to create a launch
+    #[returns(Vec<Coin>)]
+    LaunchCreationFee { creator: String },
+    // Returns whether the creator is approved and can create now
+    #[returns(CreatorStatus)]
+    CreatorStatus { creator: String },
+    #[returns(Vec<CreatorFeeDiscount>)]
+    CreatorFeeDiscounts {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<CodeVersion>)]
+    CodeVersions {
+        contract_type: ContractType,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(CodeVersion)]
+    CodeVersion {
+        contract_type: ContractType,
+        version: String,
+    },
+    // Returns the minters created by the creator
+    #[returns(Vec<CreatedContract>)]
+    ContractsByCreator {
+        creator: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<CreatedContract>)]
+    ContractsByType {
+        contract_type: ContractType,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<CreatedContract>)]
+    ContractsByCollectionId {
+        collection_id: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // Returns the contract if it was created by this factory, None otherwise
+    #[returns(Option<CreatedContract>)]
+    CreatedContract { address: String },
+    // Returns whether the factory is the wasm admin of the contracts the creator creates
+    #[returns(bool)]
+    ManagedUpgrades { creator: String },
+    // Returns the addresses the minters of the creator and collection id are instantiated at
+    // With a version, the addresses of the minters created with the versioned create messages
+    #[returns(PredictedMinterAddresses)]
+    PredictMinterAddress {
+        creator: String,
+        collection_id: String,
+        version: Option<String>,
+    },
+    // Runs the checks of CreateOpenEditionMinter and of the minter without creating it
+    // Access and the fee discount are those of the creator, who would send the create msg
+    // With a version the checks of CreateVersionedOpenEditionMinter run with its code id
+    #[returns(ValidationResponse)]
+    ValidateCreateOpenEditionMinter {
+        creator: String,
+        msg: OpenEditionMinterCreateMsg,
+        version: Option<String>,
+    },
 }
+
+#[cw_serde]
+pub struct MigrateMsg {}
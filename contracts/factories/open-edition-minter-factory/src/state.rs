@@ -1,5 +1,19 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin};
 use cw_storage_plus::Item;
 
-use crate::msg::OpenEditionMinterFactoryParams;
+use crate::msg::{OpenEditionMinterCreateMsg, OpenEditionMinterFactoryParams};
 
 pub const PARAMS: Item<OpenEditionMinterFactoryParams> = Item::new("params");
+
+// Minter of a launch, kept until the whitelist factory returns the whitelist address
+#[cw_serde]
+pub struct PendingLaunch {
+    pub creator: Addr,
+    pub minter: OpenEditionMinterCreateMsg,
+    // Code id the address of the minter was predicted with
+    pub code_id: u64,
+    pub collection_creation_fee: Coin,
+}
+
+pub const PENDING_LAUNCH: Item<PendingLaunch> = Item::new("pending_launch");
@@ -1,16 +1,33 @@
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, ParamsResponse, QueryMsg};
-use crate::state::PARAMS;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, ParamsResponse, QueryMsg};
+use crate::state::{ALLOWED_FACTORIES, PARAMS};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
-    Response, StdResult, WasmMsg,
+    to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order,
+    Reply, Response, StdResult, WasmMsg,
 };
+use cw2::set_contract_version;
+use cw_ownable::{get_ownership, initialize_owner, update_ownership, Action};
 use cw_utils::may_pay;
+use factory_types::access::{CreatorAccess, CreatorAccessMode, CreatorStatus};
+use factory_types::catalog::{CodeCatalog, CodeVersion};
+use factory_types::discount::{CreatorDiscounts, CreatorFeeDiscount, FeeDiscount};
+use factory_types::registry::{
+    ContractType, CreatedContract, PendingContract, Registry, CREATE_CONTRACT_REPLY_ID,
+};
+use factory_types::upgrades::ManagedUpgrades;
+use factory_types::validation::{Validation, ValidationResponse};
+use pauser::migration::migrate_version;
 use pauser::PauseState;
+use whitelist_types::round::{
+    check_round_integrity, check_round_members, check_rounds_overlap, stores_members,
+};
 use whitelist_types::CreateWhitelistMsg;
 
+const CONTRACT_NAME: &str = "crates.io:omniflix-round-whitelist-factory";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -18,6 +35,7 @@ pub fn instantiate(
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     let admin = deps
         .api
         .addr_validate(&msg.params.clone().admin.into_string())?;
@@ -29,6 +47,8 @@ pub fn instantiate(
     pause_state.set_pausers(deps.storage, info.sender.clone(), vec![admin.clone()])?;
     let params = msg.params;
     PARAMS.save(deps.storage, &params)?;
+    // Admin is tracked as the owner for two-step ownership transfers
+    initialize_owner(deps.storage, deps.api, Some(admin.as_str()))?;
     Ok(Response::default())
 }
 
@@ -40,8 +60,18 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::CreateWhitelist { msg } => create_whitelist(deps, env, info, msg),
-        ExecuteMsg::UpdateAdmin { admin } => update_admin(deps, env, info, admin),
+        ExecuteMsg::CreateWhitelist { msg } => {
+            let creator = info.sender.clone();
+            create_whitelist(deps, env, info, creator, msg, None)
+        }
+        ExecuteMsg::CreateWhitelistFor { creator, msg } => {
+            create_whitelist_for(deps, env, info, creator, msg)
+        }
+        ExecuteMsg::CreateVersionedWhitelist { msg, version } => {
+            let creator = info.sender.clone();
+            create_whitelist(deps, env, info, creator, msg, Some(version))
+        }
+        ExecuteMsg::UpdateOwnership(action) => update_params_ownership(deps, env, info, action),
         ExecuteMsg::UpdateFeeCollectorAddress {
             fee_collector_address,
         } => update_fee_collector_address(deps, env, info, fee_collector_address),
@@ -51,24 +81,73 @@ pub fn execute(
         ExecuteMsg::UpdateWhitelistCodeId { whitelist_code_id } => {
             update_whitelist_code_id(deps, env, info, whitelist_code_id)
         }
+        ExecuteMsg::AddCodeVersion {
+            contract_type,
+            version,
+            code_id,
+            label,
+        } => add_code_version(deps, env, info, contract_type, version, code_id, label),
+        ExecuteMsg::SetCodeVersionDeprecated {
+            contract_type,
+            version,
+            deprecated,
+        } => set_code_version_deprecated(deps, env, info, contract_type, version, deprecated),
+        ExecuteMsg::MigrateContracts {
+            contract_type,
+            version,
+            contracts,
+            msg,
+        } => migrate_contracts(deps, env, info, contract_type, version, contracts, msg),
+        ExecuteMsg::SetManagedUpgrades { enabled } => {
+            set_managed_upgrades(deps, env, info, enabled)
+        }
+        ExecuteMsg::UpdateCreatorAccessMode { mode } => {
+            update_creator_access_mode(deps, env, info, mode)
+        }
+        ExecuteMsg::ApproveCreators { creators } => approve_creators(deps, env, info, creators),
+        ExecuteMsg::RevokeCreators { creators } => revoke_creators(deps, env, info, creators),
+        ExecuteMsg::SetCreatorFeeDiscount { creator, discount } => {
+            set_creator_fee_discount(deps, env, info, creator, discount)
+        }
+        ExecuteMsg::UpdateAllowedFactories { add, remove } => {
+            update_allowed_factories(deps, env, info, add, remove)
+        }
         ExecuteMsg::Pause {} => execute_pause(deps, env, info),
         ExecuteMsg::Unpause {} => execute_unpause(deps, env, info),
         ExecuteMsg::SetPausers { pausers } => set_pausers(deps, env, info, pausers),
     }
 }
 
+// Creates the whitelist for the creator, the fee is paid by the sender
 pub fn create_whitelist(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
+    creator: Addr,
     msg: CreateWhitelistMsg,
+    version: Option<String>,
 ) -> Result<Response, ContractError> {
     let pause_state = PauseState::new()?;
     pause_state.error_if_paused(deps.as_ref().storage)?;
+    // Creators are checked against the access mode of the factory
+    CreatorAccess::new().check(deps.storage, &creator, env.block.time)?;
     let params = PARAMS.load(deps.storage)?;
-    let creation_fee = params.whitelist_creation_fee;
+    // Creators with a discount pay less than the whitelist creation fee
+    let creation_fee = CreatorDiscounts::new().creator_fee(
+        deps.storage,
+        &creator,
+        &params.whitelist_creation_fee,
+    )?;
     let fee_collector_address = params.fee_collector_address;
-    let whitelist_code_id = params.whitelist_code_id;
+    // Whitelists without a version use the default code id
+    let whitelist_code_id = match version {
+        Some(version) => CodeCatalog::new().active_code_id(
+            deps.storage,
+            &ContractType::RoundWhitelist,
+            &version,
+        )?,
+        None => params.whitelist_code_id,
+    };
     let mut messages: Vec<CosmosMsg> = vec![];
 
     let amount = may_pay(&info, &creation_fee.clone().denom)?;
@@ -82,34 +161,71 @@ pub fn create_whitelist(
             amount: vec![creation_fee],
         }));
     }
-    messages.push(CosmosMsg::Wasm(WasmMsg::Instantiate {
-        admin: Some(msg.admin.clone()),
-        code_id: whitelist_code_id,
-        msg: to_json_binary(&msg)?,
-        funds: vec![],
-        label: params.product_label,
-    }));
+    // Admin of the whitelist migrates it unless the creator opted into managed upgrades
+    let (wasm_admin, factory_admin) = ManagedUpgrades::new().wasm_admin(
+        deps.storage,
+        &creator,
+        &env.contract.address,
+        &msg.admin,
+    );
+    // Whitelist is registered once the instantiate reply returns its address
+    let instantiate_msg = Registry::new().instantiate_msg(
+        deps.storage,
+        WasmMsg::Instantiate {
+            admin: Some(wasm_admin),
+            code_id: whitelist_code_id,
+            msg: to_json_binary(&msg)?,
+            funds: vec![],
+            label: params.product_label,
+        },
+        PendingContract {
+            creator: creator.clone(),
+            code_id: whitelist_code_id,
+            contract_type: ContractType::RoundWhitelist,
+            collection_id: None,
+            factory_admin,
+        },
+    )?;
     Ok(Response::new()
         .add_messages(messages)
+        .add_submessage(instantiate_msg)
         .add_attribute("action", "create_whitelist")
-        .add_attribute("creator", info.sender))
+        .add_attribute("creator", creator))
 }
 
-pub fn update_admin(
+// Factories creating launches create the whitelist for the creator of the launch
+pub fn create_whitelist_for(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
-    admin: String,
+    creator: String,
+    msg: CreateWhitelistMsg,
 ) -> Result<Response, ContractError> {
-    let mut params = PARAMS.load(deps.storage)?;
-    if info.sender != params.admin {
-        return Err(ContractError::Unauthorized {});
+    let is_allowed = ALLOWED_FACTORIES
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or(false);
+    if !is_allowed {
+        return Err(ContractError::FactoryNotAllowed {});
     }
-    params.admin = deps.api.addr_validate(&admin)?;
+    let creator = deps.api.addr_validate(&creator)?;
+    create_whitelist(deps, env, info, creator, msg, None)
+}
+
+pub fn update_params_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: Action,
+) -> Result<Response, ContractError> {
+    let ownership = update_ownership(deps.branch(), &env.block, &info.sender, action)?;
+    // Keep the admin of params in sync with the owner
+    // If ownership is renounced, admin is set to the contract itself so no one can act as admin
+    let mut params = PARAMS.load(deps.storage)?;
+    params.admin = ownership.owner.clone().unwrap_or(env.contract.address);
     PARAMS.save(deps.storage, &params)?;
     Ok(Response::new()
-        .add_attribute("action", "update_admin")
-        .add_attribute("admin", admin))
+        .add_attribute("action", "update_ownership")
+        .add_attributes(ownership.into_attributes()))
 }
 
 pub fn update_fee_collector_address(
@@ -156,6 +272,194 @@ pub fn update_whitelist_code_id(
     PARAMS.save(deps.storage, &params)?;
     Ok(Response::new().add_attribute("action", "update_whitelist_code_id"))
 }
+pub fn update_creator_access_mode(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    mode: CreatorAccessMode,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if info.sender != params.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    CreatorAccess::new().mode.save(deps.storage, &mode)?;
+    Ok(Response::new().add_attribute("action", "update_creator_access_mode"))
+}
+
+pub fn approve_creators(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    creators: Vec<String>,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if info.sender != params.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let creators = creators
+        .iter()
+        .map(|creator| deps.api.addr_validate(creator))
+        .collect::<StdResult<Vec<Addr>>>()?;
+    CreatorAccess::new().approve(deps.storage, &creators)?;
+    Ok(Response::new()
+        .add_attribute("action", "approve_creators")
+        .add_attribute("creators", creators.len().to_string()))
+}
+
+pub fn revoke_creators(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    creators: Vec<String>,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if info.sender != params.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let creators = creators
+        .iter()
+        .map(|creator| deps.api.addr_validate(creator))
+        .collect::<StdResult<Vec<Addr>>>()?;
+    CreatorAccess::new().revoke(deps.storage, &creators);
+    Ok(Response::new()
+        .add_attribute("action", "revoke_creators")
+        .add_attribute("creators", creators.len().to_string()))
+}
+
+pub fn set_creator_fee_discount(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    creator: String,
+    discount: Option<FeeDiscount>,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if info.sender != params.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let creator = deps.api.addr_validate(&creator)?;
+    CreatorDiscounts::new()
+        .set(deps.storage, &creator, discount)?;
+    Ok(Response::new()
+        .add_attribute("action", "set_creator_fee_discount")
+        .add_attribute("creator", creator))
+}
+
+pub fn add_code_version(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    contract_type: ContractType,
+    version: String,
+    code_id: u64,
+    label: String,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if info.sender != params.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    // Code id must be stored on chain
+    deps.querier.query_wasm_code_info(code_id)?;
+    CodeCatalog::new().add(
+        deps.storage,
+        CodeVersion {
+            contract_type,
+            version: version.clone(),
+            code_id,
+            label,
+            deprecated: false,
+        },
+        &[ContractType::RoundWhitelist],
+    )?;
+    Ok(Response::new()
+        .add_attribute("action", "add_code_version")
+        .add_attribute("version", version)
+        .add_attribute("code_id", code_id.to_string()))
+}
+
+pub fn set_code_version_deprecated(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    contract_type: ContractType,
+    version: String,
+    deprecated: bool,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if info.sender != params.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    CodeCatalog::new().set_deprecated(deps.storage, &contract_type, &version, deprecated)?;
+    Ok(Response::new()
+        .add_attribute("action", "set_code_version_deprecated")
+        .add_attribute("version", version)
+        .add_attribute("deprecated", deprecated.to_string()))
+}
+
+pub fn migrate_contracts(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    contract_type: ContractType,
+    version: String,
+    contracts: Vec<String>,
+    msg: Binary,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if info.sender != params.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let contracts = contracts
+        .iter()
+        .map(|contract| deps.api.addr_validate(contract))
+        .collect::<StdResult<Vec<Addr>>>()?;
+    let migrate_msgs =
+        CodeCatalog::new().migrate_msgs(deps.storage, &contract_type, &version, &contracts, msg)?;
+    Ok(Response::new()
+        .add_messages(migrate_msgs)
+        .add_attribute("action", "migrate_contracts")
+        .add_attribute("version", version)
+        .add_attribute("contracts", contracts.len().to_string()))
+}
+
+fn set_managed_upgrades(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    ManagedUpgrades::new().set(deps.storage, &info.sender, enabled)?;
+    Ok(Response::default()
+        .add_attribute("action", "set_managed_upgrades")
+        .add_attribute("creator", info.sender)
+        .add_attribute("enabled", enabled.to_string()))
+}
+
+pub fn update_allowed_factories(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    add: Vec<String>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    if info.sender != params.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    for factory in &add {
+        let factory = deps.api.addr_validate(factory)?;
+        ALLOWED_FACTORIES.save(deps.storage, &factory, &true)?;
+    }
+    for factory in &remove {
+        let factory = deps.api.addr_validate(factory)?;
+        ALLOWED_FACTORIES.remove(deps.storage, &factory);
+    }
+    Ok(Response::new()
+        .add_attribute("action", "update_allowed_factories")
+        .add_attribute("added", add.join(","))
+        .add_attribute("removed", remove.join(",")))
+}
+
 fn execute_pause(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
     let pause_state = PauseState::new()?;
     pause_state.pause(deps.storage, &info.sender)?;
@@ -190,15 +494,94 @@ fn set_pausers(
         .add_attribute("pausers", pausers.join(",")))
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        CREATE_CONTRACT_REPLY_ID => {
+            let contract = Registry::new().register(deps.storage, msg, env.block.time)?;
+            // Factories creating whitelists for others read the address from the data
+            Ok(Response::new()
+                .set_data(to_json_binary(&contract.address)?)
+                .add_attribute("action", "register_contract")
+                .add_attribute("contract_address", contract.address)
+                .add_attribute("creator", contract.creator))
+        }
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    // Factories instantiated before ownership was tracked have no owner yet
+    if get_ownership(deps.storage).is_err() {
+        let params = PARAMS.load(deps.storage)?;
+        initialize_owner(deps.storage, deps.api, Some(params.admin.as_str()))?;
+    }
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Params {} => to_json_binary(&query_params(deps)?),
+        QueryMsg::Ownership {} => to_json_binary(&get_ownership(deps.storage)?),
         QueryMsg::IsPaused {} => to_json_binary(&query_is_paused(deps, _env)?),
         QueryMsg::Pausers {} => to_json_binary(&query_pausers(deps, _env)?),
+        QueryMsg::AllowedFactories {} => to_json_binary(&query_allowed_factories(deps)?),
+        QueryMsg::CreatorWhitelistCreationFee { creator } => {
+            to_json_binary(&query_creator_whitelist_creation_fee(deps, creator)?)
+        }
+        QueryMsg::CreatorStatus { creator } => {
+            to_json_binary(&query_creator_status(deps, _env, creator)?)
+        }
+        QueryMsg::CreatorFeeDiscounts { start_after, limit } => {
+            to_json_binary(&query_creator_fee_discounts(deps, start_after, limit)?)
+        }
+        QueryMsg::CodeVersions {
+            contract_type,
+            start_after,
+            limit,
+        } => to_json_binary(&query_code_versions(deps, contract_type, start_after, limit)?),
+        QueryMsg::CodeVersion {
+            contract_type,
+            version,
+        } => to_json_binary(&query_code_version(deps, contract_type, version)?),
+        QueryMsg::ContractsByCreator {
+            creator,
+            start_after,
+            limit,
+        } => to_json_binary(&query_contracts_by_creator(deps, creator, start_after, limit)?),
+        QueryMsg::CreatedContract { address } => {
+            to_json_binary(&query_created_contract(deps, address)?)
+        }
+        QueryMsg::ManagedUpgrades { creator } => {
+            to_json_binary(&query_managed_upgrades(deps, creator)?)
+        }
+        QueryMsg::ValidateCreateWhitelist { creator, msg } => {
+            to_json_binary(&query_validate_create_whitelist(deps, _env, creator, msg)?)
+        }
     }
 }
 
+fn query_code_versions(
+    deps: Deps,
+    contract_type: ContractType,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CodeVersion>> {
+    CodeCatalog::new().list(deps.storage, &contract_type, start_after, limit)
+}
+
+fn query_code_version(
+    deps: Deps,
+    contract_type: ContractType,
+    version: String,
+) -> Result<CodeVersion, ContractError> {
+    let code_version = CodeCatalog::new().load(deps.storage, &contract_type, &version)?;
+    Ok(code_version)
+}
+
 fn query_params(deps: Deps) -> StdResult<ParamsResponse> {
     let params = PARAMS.load(deps.storage)?;
     Ok(ParamsResponse { params })
@@ -216,6 +599,108 @@ fn query_pausers(deps: Deps, _env: Env) -> Result<Vec<Addr>, ContractError> {
     Ok(pausers)
 }
 
+fn query_allowed_factories(deps: Deps) -> StdResult<Vec<Addr>> {
+    ALLOWED_FACTORIES
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect()
+}
+
+fn query_creator_whitelist_creation_fee(deps: Deps, creator: String) -> StdResult<Coin> {
+    let params = PARAMS.load(deps.storage)?;
+    let creator = deps.api.addr_validate(&creator)?;
+    CreatorDiscounts::new().creator_fee(
+        deps.storage,
+        &creator,
+        &params.whitelist_creation_fee,
+    )
+}
+
+fn query_creator_status(deps: Deps, env: Env, creator: String) -> StdResult<CreatorStatus> {
+    let creator = deps.api.addr_validate(&creator)?;
+    CreatorAccess::new().status(deps.storage, &creator, env.block.time)
+}
+
+fn query_creator_fee_discounts(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CreatorFeeDiscount>> {
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    CreatorDiscounts::new()
+        .list(deps.storage, start_after, limit)
+}
+
+fn query_contracts_by_creator(
+    deps: Deps,
+    creator: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CreatedContract>> {
+    let creator = deps.api.addr_validate(&creator)?;
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    Registry::new().by_creator(deps.storage, creator, start_after, limit)
+}
+
+fn query_created_contract(deps: Deps, address: String) -> StdResult<Option<CreatedContract>> {
+    let address = deps.api.addr_validate(&address)?;
+    Registry::new().load(deps.storage, &address)
+}
+
+fn query_managed_upgrades(deps: Deps, creator: String) -> StdResult<bool> {
+    let creator = deps.api.addr_validate(&creator)?;
+    Ok(ManagedUpgrades::new().is_enabled(deps.storage, &creator))
+}
+
+// Runs the checks of create_whitelist and of the whitelist instantiate, collecting every problem
+fn query_validate_create_whitelist(
+    deps: Deps,
+    env: Env,
+    creator: String,
+    msg: CreateWhitelistMsg,
+) -> Result<ValidationResponse, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    let creator = deps.api.addr_validate(&creator)?;
+    let mut validation = Validation::new();
+
+    // Checks of the factory
+    validation.check(PauseState::new()?.error_if_paused(deps.storage));
+    validation.check(CreatorAccess::new().check(deps.storage, &creator, env.block.time));
+    if let Some(creation_fee) = validation.check(CreatorDiscounts::new().creator_fee(
+        deps.storage,
+        &creator,
+        &params.whitelist_creation_fee,
+    )) {
+        validation.require_funds(creation_fee);
+    }
+
+    // Checks of the whitelist
+    validation.check(deps.api.addr_validate(&msg.admin));
+    for round_config in &msg.rounds {
+        let round = &round_config.round;
+        validation.check(check_round_integrity(round, env.block.time));
+        validation.check(check_round_members(round, &round_config.members));
+        if stores_members(round) {
+            for member in &round_config.members {
+                validation.check(deps.api.addr_validate(member));
+            }
+        }
+    }
+    let rounds = msg
+        .rounds
+        .iter()
+        .map(|round_config| &round_config.round)
+        .collect();
+    validation.check(check_rounds_overlap(rounds));
+    for minter in msg.minters.unwrap_or_default() {
+        validation.check(deps.api.addr_validate(&minter));
+    }
+    Ok(validation.into_response())
+}
+
 #[cfg(test)]
 mod round_whitelist_factory_tests {
     use crate::msg::RoundWhitelistFactoryParams;
@@ -224,9 +709,12 @@ mod round_whitelist_factory_tests {
     use cosmwasm_std::{
         from_json,
         testing::{mock_dependencies, mock_env, mock_info},
-        Addr,
+        Addr, Decimal,
     };
+    use cw_ownable::OwnershipError;
+    use factory_types::access::AccessError;
     use pauser::PauseError;
+    use whitelist_types::{Round, RoundConfig};
 
     #[test]
     fn test_instantiate() {
@@ -372,20 +860,40 @@ mod round_whitelist_factory_tests {
         };
         let _res = instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
 
-        // Non admin can not update admin
-        let info = mock_info("anyone", &[]);
-        let res = update_admin(deps.as_mut(), env.clone(), info, "anyone".to_string());
-
-        assert_eq!(res.err().unwrap(), ContractError::Unauthorized {});
+        // Non admin can not transfer ownership
+        let transfer_msg = ExecuteMsg::UpdateOwnership(Action::TransferOwnership {
+            new_owner: "new_admin".to_string(),
+            expiry: None,
+        });
+        let info = mock_info("non_admin", &[]);
+        let res = execute(deps.as_mut(), mock_env(), info, transfer_msg.clone());
+        assert_eq!(res.unwrap_err(), ContractError::Ownership(OwnershipError::NotOwner));
 
-        // Admin can update admin
+        // Admin can propose a new admin
         let info = mock_info("admin", &[]);
-        let _res = update_admin(deps.as_mut(), env.clone(), info, "anyone".to_string()).unwrap();
+        let _res = execute(deps.as_mut(), mock_env(), info, transfer_msg).unwrap();
 
-        // Query params
-        let res = query(deps.as_ref(), mock_env(), QueryMsg::Params {}).unwrap();
-        let params: ParamsResponse = from_json(res).unwrap();
-        assert_eq!(params.params.admin, Addr::unchecked("anyone"));
+        // Admin is not changed until the transfer is accepted
+        let params = query_params(deps.as_ref()).unwrap();
+        assert_eq!(params.params.admin, Addr::unchecked("admin"));
+
+        // Only the pending owner can accept
+        let accept_msg = ExecuteMsg::UpdateOwnership(Action::AcceptOwnership);
+        let info = mock_info("non_admin", &[]);
+        let res = execute(deps.as_mut(), mock_env(), info, accept_msg.clone());
+        assert_eq!(res.unwrap_err(), ContractError::Ownership(OwnershipError::NotPendingOwner));
+
+        // New admin accepts the ownership
+        let info = mock_info("new_admin", &[]);
+        let _res = execute(deps.as_mut(), mock_env(), info, accept_msg).unwrap();
+
+        // query params
+        let params = query_params(deps.as_ref()).unwrap();
+        assert_eq!(params.params.admin, Addr::unchecked("new_admin"));
+
+        let ownership = get_ownership(deps.as_ref().storage).unwrap();
+        assert_eq!(ownership.owner, Some(Addr::unchecked("new_admin")));
+        assert_eq!(ownership.pending_owner, None);
     }
 
     #[test]
@@ -503,4 +1011,389 @@ mod round_whitelist_factory_tests {
         let params: ParamsResponse = from_json(res).unwrap();
         assert_eq!(params.params.whitelist_code_id, 2);
     }
+
+    #[test]
+    fn test_set_creator_fee_discount() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("creator", &[]);
+        let msg = InstantiateMsg {
+            params: RoundWhitelistFactoryParams {
+                admin: Addr::unchecked("admin"),
+                fee_collector_address: Addr::unchecked("fee_collector_address"),
+                whitelist_creation_fee: Coin::new(100, "uflix"),
+                whitelist_code_id: 1,
+                product_label: "product_label".to_string(),
+            },
+        };
+        let _res = instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+        let discount = FeeDiscount::Percentage(Decimal::percent(40));
+
+        // Non admin can not set discounts
+        let info = mock_info("anyone", &[]);
+        let res = set_creator_fee_discount(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            "partner".to_string(),
+            Some(discount.clone()),
+        );
+        assert_eq!(res.err().unwrap(), ContractError::Unauthorized {});
+
+        let info = mock_info("admin", &[]);
+        let _res = set_creator_fee_discount(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            "partner".to_string(),
+            Some(discount.clone()),
+        )
+        .unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::CreatorWhitelistCreationFee {
+                creator: "partner".to_string(),
+            },
+        )
+        .unwrap();
+        let fee: Coin = from_json(res).unwrap();
+        assert_eq!(fee, Coin::new(60, "uflix"));
+
+        // Other creators pay the whitelist creation fee
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::CreatorWhitelistCreationFee {
+                creator: "anyone".to_string(),
+            },
+        )
+        .unwrap();
+        let fee: Coin = from_json(res).unwrap();
+        assert_eq!(fee, Coin::new(100, "uflix"));
+
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::CreatorFeeDiscounts {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let discounts: Vec<CreatorFeeDiscount> = from_json(res).unwrap();
+        assert_eq!(
+            discounts,
+            vec![CreatorFeeDiscount {
+                creator: Addr::unchecked("partner"),
+                discount,
+            }]
+        );
+
+        // Discount is removed
+        let info = mock_info("admin", &[]);
+        let _res = set_creator_fee_discount(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            "partner".to_string(),
+            None,
+        )
+        .unwrap();
+        let res = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::CreatorWhitelistCreationFee {
+                creator: "partner".to_string(),
+            },
+        )
+        .unwrap();
+        let fee: Coin = from_json(res).unwrap();
+        assert_eq!(fee, Coin::new(100, "uflix"));
+    }
+
+    #[test]
+    fn test_creator_access() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("creator", &[]);
+        let msg = InstantiateMsg {
+            params: RoundWhitelistFactoryParams {
+                admin: Addr::unchecked("admin"),
+                fee_collector_address: Addr::unchecked("fee_collector_address"),
+                whitelist_creation_fee: Coin::new(100, "uflix"),
+                whitelist_code_id: 1,
+                product_label: "product_label".to_string(),
+            },
+        };
+        let _res = instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        // Non admin can not change the access mode
+        let info = mock_info("anyone", &[]);
+        let res = update_creator_access_mode(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            CreatorAccessMode::ApprovedOnly,
+        );
+        assert_eq!(res.err().unwrap(), ContractError::Unauthorized {});
+
+        let info = mock_info("admin", &[]);
+        let _res = update_creator_access_mode(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            CreatorAccessMode::ApprovedOnly,
+        )
+        .unwrap();
+
+        // Unapproved creator can not create a whitelist
+        let info = mock_info("anyone", &[Coin::new(100, "uflix")]);
+        let msg = CreateWhitelistMsg {
+            admin: "anyone".to_string(),
+            rounds: vec![],
+            minters: None,
+        };
+        let res = create_whitelist(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            Addr::unchecked("anyone"),
+            msg,
+            None,
+        );
+        assert_eq!(
+            res.err().unwrap(),
+            ContractError::Access(AccessError::CreatorNotApproved {})
+        );
+
+        let info = mock_info("admin", &[]);
+        let _res = approve_creators(deps.as_mut(), env.clone(), info, vec!["anyone".to_string()])
+            .unwrap();
+        let res = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::CreatorStatus {
+                creator: "anyone".to_string(),
+            },
+        )
+        .unwrap();
+        let status: CreatorStatus = from_json(res).unwrap();
+        assert!(status.approved);
+        assert!(status.can_create);
+    }
+
+    #[test]
+    fn test_create_whitelist_for() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("creator", &[]);
+        let msg = InstantiateMsg {
+            params: RoundWhitelistFactoryParams {
+                admin: Addr::unchecked("admin"),
+                fee_collector_address: Addr::unchecked("fee_collector_address"),
+                whitelist_creation_fee: Coin::new(100, "uflix"),
+                whitelist_code_id: 1,
+                product_label: "product_label".to_string(),
+            },
+        };
+        let _res = instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+        let msg = CreateWhitelistMsg {
+            admin: "creator".to_string(),
+            rounds: vec![],
+            minters: None,
+        };
+
+        // Factories that are not allowed can not create for others
+        let info = mock_info("factory", &[Coin::new(100, "uflix")]);
+        let res = create_whitelist_for(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            "creator".to_string(),
+            msg.clone(),
+        );
+        assert_eq!(res.err().unwrap(), ContractError::FactoryNotAllowed {});
+
+        // Non admin can not allow factories
+        let info = mock_info("anyone", &[]);
+        let res = update_allowed_factories(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            vec!["factory".to_string()],
+            vec![],
+        );
+        assert_eq!(res.err().unwrap(), ContractError::Unauthorized {});
+
+        let info = mock_info("admin", &[]);
+        let _res = update_allowed_factories(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            vec!["factory".to_string()],
+            vec![],
+        )
+        .unwrap();
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::AllowedFactories {}).unwrap();
+        let factories: Vec<Addr> = from_json(res).unwrap();
+        assert_eq!(factories, vec![Addr::unchecked("factory")]);
+
+        // Creator is checked against the access mode, not the factory
+        let info = mock_info("admin", &[]);
+        let _res = update_creator_access_mode(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            CreatorAccessMode::ApprovedOnly,
+        )
+        .unwrap();
+        let info = mock_info("admin", &[]);
+        let _res = approve_creators(deps.as_mut(), env.clone(), info, vec!["factory".to_string()])
+            .unwrap();
+        let info = mock_info("factory", &[Coin::new(100, "uflix")]);
+        let res = create_whitelist_for(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            "creator".to_string(),
+            msg.clone(),
+        );
+        assert_eq!(
+            res.err().unwrap(),
+            ContractError::Access(AccessError::CreatorNotApproved {})
+        );
+
+        let info = mock_info("admin", &[]);
+        let _res = approve_creators(deps.as_mut(), env.clone(), info, vec!["creator".to_string()])
+            .unwrap();
+        let info = mock_info("factory", &[Coin::new(100, "uflix")]);
+        let res = create_whitelist_for(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            "creator".to_string(),
+            msg.clone(),
+        )
+        .unwrap();
+        assert_eq!(res.attributes[1].value, "creator");
+
+        // Removed factories can not create for others
+        let info = mock_info("admin", &[]);
+        let _res = update_allowed_factories(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            vec![],
+            vec!["factory".to_string()],
+        )
+        .unwrap();
+        let info = mock_info("factory", &[Coin::new(100, "uflix")]);
+        let res = create_whitelist_for(deps.as_mut(), env, info, "creator".to_string(), msg);
+        assert_eq!(res.err().unwrap(), ContractError::FactoryNotAllowed {});
+    }
+
+    #[test]
+    fn test_validate_create_whitelist() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("creator", &[]);
+        let msg = InstantiateMsg {
+            params: RoundWhitelistFactoryParams {
+                admin: Addr::unchecked("admin"),
+                fee_collector_address: Addr::unchecked("fee_collector_address"),
+                whitelist_creation_fee: Coin::new(100, "uflix"),
+                whitelist_code_id: 1,
+                product_label: "product_label".to_string(),
+            },
+        };
+        let _res = instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+        let round = Round {
+            start_time: env.block.time.plus_seconds(100),
+            end_time: env.block.time.plus_seconds(200),
+            mint_price: Coin::new(1000, "uflix"),
+            round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
+        };
+
+        let msg = CreateWhitelistMsg {
+            admin: "admin".to_string(),
+            rounds: vec![RoundConfig {
+                round: round.clone(),
+                members: vec!["member".to_string()],
+            }],
+            minters: None,
+        };
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::ValidateCreateWhitelist {
+                creator: "anyone".to_string(),
+                msg,
+            },
+        )
+        .unwrap();
+        let res: ValidationResponse = from_json(res).unwrap();
+        assert_eq!(
+            res,
+            ValidationResponse {
+                valid: true,
+                errors: vec![],
+                required_funds: vec![Coin::new(100, "uflix")],
+            }
+        );
+
+        // Every problem of every round is returned
+        let merkle_round = Round {
+            start_time: env.block.time.plus_seconds(150),
+            end_time: env.block.time.plus_seconds(250),
+            merkle_root: Some("root".to_string()),
+            ..round.clone()
+        };
+        let msg = CreateWhitelistMsg {
+            admin: "admin".to_string(),
+            rounds: vec![
+                RoundConfig {
+                    round: Round {
+                        round_per_address_limit: 0,
+                        ..round
+                    },
+                    members: vec!["member".to_string()],
+                },
+                RoundConfig {
+                    round: merkle_round,
+                    members: vec!["member".to_string()],
+                },
+            ],
+            minters: None,
+        };
+        let res = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::ValidateCreateWhitelist {
+                creator: "anyone".to_string(),
+                msg,
+            },
+        )
+        .unwrap();
+        let res: ValidationResponse = from_json(res).unwrap();
+        assert!(!res.valid);
+        assert_eq!(
+            res.errors,
+            vec![
+                "Invalid per address limit".to_string(),
+                "Invalid merkle root".to_string(),
+                "Members can not be added to a round with a merkle root or a voucher signer"
+                    .to_string(),
+                "Rounds overlapped".to_string(),
+            ]
+        );
+        assert_eq!(res.required_funds, vec![Coin::new(100, "uflix")]);
+    }
 }
@@ -1,5 +1,9 @@
 use cosmwasm_std::{Coin, StdError};
+use cw_ownable::OwnershipError;
 use cw_utils::PaymentError;
+use factory_types::access::AccessError;
+use factory_types::catalog::CatalogError;
+use pauser::migration::MigrationError;
 use pauser::PauseError;
 use thiserror::Error;
 
@@ -15,6 +19,18 @@ pub enum ContractError {
     #[error(transparent)]
     Pause(#[from] PauseError),
 
+    #[error(transparent)]
+    Ownership(#[from] OwnershipError),
+
+    #[error(transparent)]
+    Access(#[from] AccessError),
+
+    #[error(transparent)]
+    Catalog(#[from] CatalogError),
+
+    #[error(transparent)]
+    Migration(#[from] MigrationError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -37,6 +53,12 @@ pub enum ContractError {
 
     #[error("Missing minter creation fee")]
     MissingMinterCreationFee {},
+
+    #[error("Factory not allowed")]
+    FactoryNotAllowed {},
+
+    #[error("Unknown reply id {id}")]
+    UnknownReplyId { id: u64 },
 }
 
 impl From<ContractError> for StdError {
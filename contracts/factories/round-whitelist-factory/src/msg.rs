@@ -1,5 +1,11 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Coin};
+use cosmwasm_std::{Addr, Binary, Coin};
+use cw_ownable::{cw_ownable_execute, cw_ownable_query};
+use factory_types::access::{CreatorAccessMode, CreatorStatus};
+use factory_types::catalog::CodeVersion;
+use factory_types::discount::{CreatorFeeDiscount, FeeDiscount};
+use factory_types::registry::{ContractType, CreatedContract};
+use factory_types::validation::ValidationResponse;
 use whitelist_types::CreateWhitelistMsg;
 
 #[cw_serde]
@@ -15,14 +21,63 @@ pub struct RoundWhitelistFactoryParams {
     pub admin: Addr,
     pub product_label: String,
 }
+#[cw_ownable_execute]
 #[allow(clippy::large_enum_variant)]
 #[cw_serde]
 pub enum ExecuteMsg {
     CreateWhitelist { msg: CreateWhitelistMsg },
-    UpdateAdmin { admin: String },
+    // Creates the whitelist for the creator, only allowed factories can create for others
+    // Response data is the address of the whitelist
+    CreateWhitelistFor {
+        creator: String,
+        msg: CreateWhitelistMsg,
+    },
+    // Creates the whitelist with the code id of a version in the code catalog
+    CreateVersionedWhitelist {
+        msg: CreateWhitelistMsg,
+        version: String,
+    },
     UpdateFeeCollectorAddress { fee_collector_address: String },
     UpdateWhitelistCreationFee { whitelist_creation_fee: Coin },
     UpdateWhitelistCodeId { whitelist_code_id: u64 },
+    // Adds a code id to the code catalog under the version
+    AddCodeVersion {
+        contract_type: ContractType,
+        version: String,
+        code_id: u64,
+        label: String,
+    },
+    // Deprecated versions can not be used to create or migrate whitelists
+    SetCodeVersionDeprecated {
+        contract_type: ContractType,
+        version: String,
+        deprecated: bool,
+    },
+    // Migrates whitelists created by the factory to the version
+    MigrateContracts {
+        contract_type: ContractType,
+        version: String,
+        contracts: Vec<String>,
+        msg: Binary,
+    },
+    // Lets the factory admin migrate the whitelists the sender creates from now on
+    // Without it the admin of a contract is its wasm admin and migrates it
+    SetManagedUpgrades { enabled: bool },
+    // Sets who can create whitelists through the factory
+    UpdateCreatorAccessMode { mode: CreatorAccessMode },
+    // Approved creators are not restricted by the access mode
+    ApproveCreators { creators: Vec<String> },
+    RevokeCreators { creators: Vec<String> },
+    // Sets the whitelist creation fee discount of the creator, None removes it
+    SetCreatorFeeDiscount {
+        creator: String,
+        discount: Option<FeeDiscount>,
+    },
+    // Sets which factories can create whitelists on behalf of their creators
+    UpdateAllowedFactories {
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
     Pause {},
     Unpause {},
     SetPausers { pausers: Vec<String> },
@@ -33,6 +88,7 @@ pub struct ParamsResponse {
     pub params: RoundWhitelistFactoryParams,
 }
 
+#[cw_ownable_query]
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
@@ -42,4 +98,50 @@ pub enum QueryMsg {
     IsPaused {},
     #[returns(Vec<Addr>)]
     Pausers {},
+    #[returns(Vec<Addr>)]
+    AllowedFactories {},
+    // Returns the whitelist creation fee of the creator, after its discount
+    #[returns(Coin)]
+    CreatorWhitelistCreationFee { creator: String },
+    // Returns whether the creator is approved and can create now
+    #[returns(CreatorStatus)]
+    CreatorStatus { creator: String },
+    #[returns(Vec<CreatorFeeDiscount>)]
+    CreatorFeeDiscounts {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<CodeVersion>)]
+    CodeVersions {
+        contract_type: ContractType,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(CodeVersion)]
+    CodeVersion {
+        contract_type: ContractType,
+        version: String,
+    },
+    // Returns the whitelists created by the creator
+    #[returns(Vec<CreatedContract>)]
+    ContractsByCreator {
+        creator: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // Returns the contract if it was created by this factory, None otherwise
+    #[returns(Option<CreatedContract>)]
+    CreatedContract { address: String },
+    // Returns whether the factory is the wasm admin of the contracts the creator creates
+    #[returns(bool)]
+    ManagedUpgrades { creator: String },
+    // Runs the checks of CreateWhitelist and of the whitelist for the creator without creating it
+    #[returns(ValidationResponse)]
+    ValidateCreateWhitelist {
+        creator: String,
+        msg: CreateWhitelistMsg,
+    },
 }
+
+#[cw_serde]
+pub struct MigrateMsg {}
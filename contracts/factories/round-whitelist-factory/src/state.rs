@@ -1,4 +1,7 @@
 use crate::msg::RoundWhitelistFactoryParams;
-use cw_storage_plus::Item;
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, Map};
 
 pub const PARAMS: Item<RoundWhitelistFactoryParams> = Item::new("params");
+// Factories allowed to create whitelists on behalf of their creators
+pub const ALLOWED_FACTORIES: Map<&Addr, bool> = Map::new("allowed_factories");
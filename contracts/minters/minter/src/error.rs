@@ -3,11 +3,14 @@ use std::convert::Infallible;
 use cosmwasm_std::{
     CheckedFromRatioError, Coin, ConversionOverflowError, StdError, Timestamp, Uint128,
 };
+use cw_ownable::OwnershipError;
 use cw_utils::PaymentError;
 use minter_types::{
     collection_details::CollectionDetailsError, config::ConfigurationError,
-    token_details::TokenDetailsError,
+    timelock::TimelockError, token_details::TokenDetailsError,
 };
+use pauser::halt::HaltError;
+use pauser::migration::MigrationError;
 use pauser::PauseError;
 use serde_json::Error as SerdeError;
 use thiserror::Error;
@@ -20,6 +23,18 @@ pub enum ContractError {
     #[error(transparent)]
     Pause(#[from] PauseError),
 
+    #[error(transparent)]
+    Halt(#[from] HaltError),
+
+    #[error(transparent)]
+    Ownership(#[from] OwnershipError),
+
+    #[error(transparent)]
+    Timelock(#[from] TimelockError),
+
+    #[error(transparent)]
+    Migration(#[from] MigrationError),
+
     #[error(transparent)]
     TokenDetailsError(#[from] TokenDetailsError),
 
@@ -32,6 +47,9 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("Address does not have the role")]
+    RoleNotFound {},
+
     #[error("Payment error")]
     PaymentError(#[from] PaymentError),
 
@@ -1,6 +1,7 @@
 use cosmwasm_std::{Coin, CosmosMsg, DepsMut, Env, MessageInfo, Response};
 use cw_utils::{maybe_addr, must_pay};
 use minter_types::config::Config;
+use minter_types::timelock::{Timelock, DEFAULT_TIMELOCK_DURATION};
 use minter_types::utils::{check_collection_creation_fee, generate_create_denom_msg};
 use omniflix_minter_factory::msg::QueryMsg::Params as QueryFactoryParams;
 use omniflix_minter_factory::msg::{CreateMinterMsg, ParamsResponse};
@@ -92,6 +93,14 @@ pub fn default_instantiate(
     // Save configuration and authorization details
     CONFIG.save(deps.storage, &config)?;
     AUTH_DETAILS.save(deps.storage, &auth_details)?;
+    // Admin is tracked as the owner for two-step ownership transfers
+    cw_ownable::initialize_owner(deps.storage, deps.api, Some(auth_details.admin.as_str()))?;
+    // Changes made after minting starts are delayed unless the creator disables the timelock
+    Timelock::new().set_duration(
+        deps.storage,
+        msg.timelock_duration.unwrap_or(DEFAULT_TIMELOCK_DURATION),
+        false,
+    )?;
     COLLECTION.save(deps.storage, &collection_details)?;
     TOKEN_DETAILS.save(deps.storage, &token_details)?;
 
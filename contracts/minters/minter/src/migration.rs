@@ -1,5 +1,6 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use minter_types::timelock::{Timelock, DEFAULT_TIMELOCK_DURATION};
 use minter_types::token_details::Token;
 use omniflix_minter_factory::msg::QueryMsg::Params as QueryFactoryParams;
 use omniflix_minter_factory::msg::{CreateMinterMsgWithMigration, ParamsResponse};
@@ -29,6 +30,7 @@ pub fn instantiate_with_migration(
         collection_details,
         auth_details,
         token_details,
+        timelock_duration,
     } = msg;
 
     let mintable_tokens = migration_data.mintable_tokens;
@@ -73,6 +75,14 @@ pub fn instantiate_with_migration(
             payment_collector,
         },
     )?;
+    // Admin is tracked as the owner for two-step ownership transfers
+    cw_ownable::initialize_owner(deps.storage, deps.api, Some(admin.as_str()))?;
+    // Changes made after minting starts are delayed unless the creator disables the timelock
+    Timelock::new().set_duration(
+        deps.storage,
+        timelock_duration.unwrap_or(DEFAULT_TIMELOCK_DURATION),
+        false,
+    )?;
     // Initialize pause state and set admin as pauser
     let pause_state = PauseState::new()?;
     pause_state.set_pausers(deps.storage, info.sender.clone(), vec![admin])?;
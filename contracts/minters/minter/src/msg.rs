@@ -1,11 +1,19 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Coin;
+use cosmwasm_std::{Coin, Timestamp};
+use cw_ownable::cw_ownable_execute;
+use minter_types::config::deserialize_some;
+use minter_types::roles::Role;
 use minter_types::token_details::Token;
 use omniflix_std::types::omniflix::onft::v1beta1::WeightedAddress;
+use whitelist_types::MembershipProof;
 
+#[cw_ownable_execute]
 #[cw_serde]
 pub enum ExecuteMsg {
-    Mint {},
+    Mint {
+        // Membership proof for whitelist rounds that do not store their members
+        proof: Option<MembershipProof>,
+    },
     MintAdmin {
         recipient: String,
         token_id: Option<String>,
@@ -28,6 +36,14 @@ pub enum ExecuteMsg {
     SetPausers {
         pausers: Vec<String>,
     },
+    GrantRole {
+        role: Role,
+        address: String,
+    },
+    RevokeRole {
+        role: Role,
+        address: String,
+    },
     UpdateRoyaltyReceivers {
         receivers: Vec<WeightedAddress>,
     },
@@ -37,12 +53,34 @@ pub enum ExecuteMsg {
         preview_uri: Option<String>,
     },
     PurgeDenom {},
-    UpdateAdmin {
-        admin: String,
-    },
     UpdatePaymentCollector {
         payment_collector: String,
     },
+    ExecutePendingChange {
+        change_id: u32,
+    },
+    CancelPendingChange {
+        change_id: u32,
+    },
+    UpdateTimelockDuration {
+        duration: u64,
+    },
+    /// A `null` end time or per address limit clears it, a missing one keeps the current value
+    UpdateSaleConfig {
+        start_time: Option<Timestamp>,
+        #[serde(
+            default,
+            deserialize_with = "deserialize_some",
+            skip_serializing_if = "Option::is_none"
+        )]
+        end_time: Option<Option<Timestamp>>,
+        #[serde(
+            default,
+            deserialize_with = "deserialize_some",
+            skip_serializing_if = "Option::is_none"
+        )]
+        per_address_limit: Option<Option<u32>>,
+    },
 }
 #[cw_serde]
 #[derive(QueryResponses)]
@@ -55,3 +93,9 @@ pub enum MinterExtensionQueryMsg {
     #[returns(u32)]
     TotalTokensRemaining {},
 }
+
+#[cw_serde]
+pub struct MigrateMsg {
+    // Factory that created the minter, recorded if the minter predates the global halt
+    pub factory: Option<String>,
+}
@@ -2,18 +2,23 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     to_json_binary, Addr, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
-    Response, StdResult, WasmMsg,
+    Response, StdResult, Storage, Timestamp, WasmMsg,
 };
+use cw_ownable::{get_ownership, update_ownership, Action};
 use cw_utils::{may_pay, must_pay, nonpayable};
+use factory_types::registry::CreatedContract;
 use minter_types::collection_details::{update_collection_details, CollectionDetails};
 use minter_types::config::Config;
 use minter_types::msg::{MintHistoryResponse, QueryMsg as BaseMinterQueryMsg};
+use minter_types::roles::{Role, RoleRegistry};
+use minter_types::timelock::{ConfigChange, PendingChange, Timelock, DEFAULT_TIMELOCK_DURATION};
 use minter_types::token_details::{Token, TokenDetails};
 use minter_types::types::{AuthDetails, UserDetails};
 use minter_types::utils::{
     check_collection_creation_fee, generate_create_denom_msg, generate_multi_minter_mint_message,
     generate_update_denom_msg,
 };
+use pauser::halt::{halt_factory_attribute, GlobalHalt, HaltError, HaltStatus};
 use pauser::PauseState;
 use std::str::FromStr;
 
@@ -23,7 +28,7 @@ use crate::mint_instance::{
     MintInstance, MintInstanceParams, ACTIVE_MINT_INSTANCE_ID, MINT_INSTANCES,
     MINT_INSTANCE_IDS_IN_USE, MINT_INSTANCE_IDS_REMOVED,
 };
-use crate::msg::{ExecuteMsg, QueryMsgExtension};
+use crate::msg::{ExecuteMsg, MigrateMsg, QueryMsgExtension};
 use crate::state::{
     UserMintingDetails, AUTH_DETAILS, COLLECTION, LAST_MINTED_TOKEN_ID, USER_MINTING_DETAILS_KEY,
 };
@@ -34,8 +39,10 @@ use omniflix_open_edition_minter_factory::msg::{
 };
 use omniflix_round_whitelist::msg::ExecuteMsg as RoundWhitelistExecuteMsg;
 use omniflix_std::types::omniflix::onft::v1beta1::{MsgPurgeDenom, WeightedAddress};
+use pauser::migration::migrate_version;
 use whitelist_types::{
-    check_if_address_is_member, check_if_whitelist_is_active, check_whitelist_price,
+    check_if_address_is_member, check_if_whitelist_is_active, check_public_rollover,
+    check_whitelist_price, MembershipProof,
 };
 
 // version info for migration info
@@ -51,6 +58,8 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     // Set the contract version
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    // Record the factory so minting stops when it halts its minters
+    let factory = GlobalHalt::new().set_factory(deps.storage, deps.querier, &info.sender)?;
 
     // Query factory parameters of instantiator
     // If the instantiator is not our factory, we won't be able to parse the response
@@ -88,6 +97,14 @@ pub fn instantiate(
     ACTIVE_MINT_INSTANCE_ID.save(deps.storage, &0)?;
     LAST_MINTED_TOKEN_ID.save(deps.storage, &0)?;
     AUTH_DETAILS.save(deps.storage, &auth_details)?;
+    // Admin is tracked as the owner for two-step ownership transfers
+    cw_ownable::initialize_owner(deps.storage, deps.api, Some(auth_details.admin.as_str()))?;
+    // Changes made after minting starts are delayed unless the creator disables the timelock
+    Timelock::new().set_duration(
+        deps.storage,
+        msg.timelock_duration.unwrap_or(DEFAULT_TIMELOCK_DURATION),
+        false,
+    )?;
 
     // Prepare and send the create denom message
     let nft_creation_fee = Coin {
@@ -107,7 +124,8 @@ pub fn instantiate(
         .add_attribute("action", "instantiate")
         .add_attribute("contract_version", CONTRACT_VERSION)
         .add_attribute("contract_name", CONTRACT_NAME)
-        .add_attribute("mint_instance_id", "1");
+        .add_attribute("mint_instance_id", "1")
+        .add_attributes(vec![halt_factory_attribute(factory)]);
 
     Ok(res)
 }
@@ -120,7 +138,10 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Mint { mint_instance_id } => execute_mint(deps, env, info, mint_instance_id),
+        ExecuteMsg::Mint {
+            mint_instance_id,
+            proof,
+        } => execute_mint(deps, env, info, mint_instance_id, proof),
         ExecuteMsg::MintAdmin {
             recipient,
             mint_instance_id,
@@ -137,13 +158,42 @@ pub fn execute(
             address,
             mint_instance_id,
         } => execute_update_whitelist_address(deps, env, info, address, mint_instance_id),
-        ExecuteMsg::UpdateAdmin { admin } => execute_update_admin(deps, env, info, admin),
+        ExecuteMsg::UpdateOwnership(action) => execute_update_ownership(deps, env, info, action),
         ExecuteMsg::UpdatePaymentCollector { payment_collector } => {
             execute_update_payment_collector(deps, env, info, payment_collector)
         }
         ExecuteMsg::Pause {} => execute_pause(deps, env, info),
         ExecuteMsg::Unpause {} => execute_unpause(deps, env, info),
         ExecuteMsg::SetPausers { pausers } => execute_set_pausers(deps, env, info, pausers),
+        ExecuteMsg::ExecutePendingChange { change_id } => {
+            execute_pending_change(deps, env, info, change_id)
+        }
+        ExecuteMsg::CancelPendingChange { change_id } => {
+            execute_cancel_pending_change(deps, env, info, change_id)
+        }
+        ExecuteMsg::UpdateTimelockDuration { duration } => {
+            execute_update_timelock_duration(deps, env, info, duration)
+        }
+        ExecuteMsg::UpdateSaleConfig {
+            start_time,
+            end_time,
+            per_address_limit,
+            mint_instance_id,
+        } => execute_update_sale_config(
+            deps,
+            env,
+            info,
+            start_time,
+            end_time,
+            per_address_limit,
+            mint_instance_id,
+        ),
+        ExecuteMsg::GrantRole { role, address } => {
+            execute_grant_role(deps, env, info, role, address)
+        }
+        ExecuteMsg::RevokeRole { role, address } => {
+            execute_revoke_role(deps, env, info, role, address)
+        }
         ExecuteMsg::CreateMintInstance {
             config,
             token_details,
@@ -169,10 +219,13 @@ pub fn execute_mint(
     env: Env,
     info: MessageInfo,
     mint_instance_id: Option<u32>,
+    proof: Option<MembershipProof>,
 ) -> Result<Response, ContractError> {
     // Ensure contract is not paused
     let pause_state = PauseState::new()?;
     pause_state.error_if_paused(deps.storage)?;
+    // Error if the factory halted its minters
+    GlobalHalt::new().error_if_halted(deps.storage, deps.querier)?;
 
     // Retrieve the mint_instance
     let (mint_instance_id, mut mint_instance) =
@@ -219,21 +272,37 @@ pub fn execute_mint(
     if !is_public {
         // Check if any whitelist is present
         if let Some(whitelist_address) = config.whitelist_address {
-            // Check whitelist price
-            let whitelist_price = check_whitelist_price(&whitelist_address, deps.as_ref())
-                .map_err(|_| ContractError::WhitelistNotActive {})?;
-            mint_price = whitelist_price;
+            // Check if whitelist is active
+            let is_active = check_if_whitelist_is_active(&whitelist_address, deps.as_ref())?;
+            if !is_active {
+                return Err(ContractError::WhitelistNotActive {});
+            }
 
             // Check if member is whitelisted
-            let is_member =
-                check_if_address_is_member(&info.sender, &whitelist_address, deps.as_ref())?;
+            let is_member = check_if_address_is_member(
+                &info.sender,
+                &whitelist_address,
+                proof.clone(),
+                deps.as_ref(),
+            )?;
             if !is_member {
                 return Err(ContractError::AddressNotWhitelisted {});
             }
+
+            // Price of the round the collector mints from
+            let whitelist_price = check_whitelist_price(
+                &info.sender,
+                &whitelist_address,
+                &env.contract.address,
+                proof.clone(),
+                deps.as_ref(),
+            )?;
+            mint_price = whitelist_price;
             messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: whitelist_address.into_string(),
                 msg: to_json_binary(&RoundWhitelistExecuteMsg::PrivateMint {
                     collector: info.sender.clone().into_string(),
+                    proof,
                 })?,
                 funds: vec![],
             }));
@@ -248,7 +317,20 @@ pub fn execute_mint(
         // Check if per address limit is set and if it is reached
         if let Some(per_address_limit) = config.per_address_limit {
             if user_details.public_mint_count > per_address_limit {
-                return Err(ContractError::AddressReachedMintLimit {});
+                // Unused whitelist allocation can roll over to the public limit
+                let public_rollover = match &config.whitelist_address {
+                    Some(whitelist_address) => check_public_rollover(
+                        &info.sender,
+                        whitelist_address,
+                        &env.contract.address,
+                        deps.as_ref(),
+                    )?,
+                    None => 0,
+                };
+                let public_limit = per_address_limit.saturating_add(public_rollover);
+                if user_details.public_mint_count > public_limit {
+                    return Err(ContractError::AddressReachedMintLimit {});
+                }
             }
         }
     }
@@ -297,6 +379,7 @@ pub fn execute_mint(
         info.sender,
         mint_instance_id.to_string(),
         mint_instance_token_id.to_string(),
+        config.num_tokens,
     )?
     .into();
 
@@ -343,8 +426,9 @@ pub fn execute_mint_admin(
     let token_details = mint_instance.clone().mint_instance_params.token_details;
     let config = mint_instance.clone().mint_instance_params.config;
     let auth_details = AUTH_DETAILS.load(deps.storage)?;
-    // Check if admin
-    if info.sender != auth_details.admin {
+    // Check if sender is admin or has the airdrop role
+    let roles = RoleRegistry::new();
+    if !roles.is_authorized(deps.storage, Role::Airdrop, &auth_details.admin, &info.sender) {
         return Err(ContractError::Unauthorized {});
     }
     // Check if token limit is set and if it is reached
@@ -363,6 +447,8 @@ pub fn execute_mint_admin(
 
     let pause_state = PauseState::new()?;
     pause_state.error_if_paused(deps.storage)?;
+    // Error if the factory halted its minters
+    GlobalHalt::new().error_if_halted(deps.storage, deps.querier)?;
 
     let recipient = deps.api.addr_validate(&recipient)?;
 
@@ -402,6 +488,7 @@ pub fn execute_mint_admin(
         recipient.clone(),
         mint_instance_id.to_string(),
         mint_instance_token_id.to_string(),
+        config.num_tokens,
     )?
     .into();
 
@@ -417,7 +504,7 @@ pub fn execute_mint_admin(
 
 pub fn execute_update_royalty_ratio(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     ratio: String,
     mint_instance_id: Option<u32>,
@@ -428,97 +515,175 @@ pub fn execute_update_royalty_ratio(
         return Err(ContractError::Unauthorized {});
     }
     // Find the mint_instance
-    let (mint_instance_id, mut mint_instance) =
+    let (mint_instance_id, mint_instance) =
         get_mint_instance_by_id(mint_instance_id, deps.storage)?;
+    let ratio = Decimal::from_str(&ratio)?;
     // Extract the token details
-    let mut mint_instance_token_details = mint_instance.clone().mint_instance_params.token_details;
-    // Set the new ratio without checking the ratio
-    mint_instance_token_details.royalty_ratio = Decimal::from_str(&ratio)?;
+    let mut mint_instance_token_details = mint_instance.mint_instance_params.token_details;
+    mint_instance_token_details.royalty_ratio = ratio;
     // Check integrity of token details
     mint_instance_token_details.check_integrity()?;
 
-    // Save the new token details
-    mint_instance
-        .mint_instance_params
-        .token_details
-        .royalty_ratio = Decimal::from_str(&ratio)?;
-    MINT_INSTANCES.save(deps.storage, mint_instance_id, &mint_instance)?;
-
-    let res = Response::new()
-        .add_attribute("action", "update_royalty_ratio")
-        .add_attribute("ratio", ratio.to_string())
-        .add_attribute("mint_instance_id", mint_instance_id.to_string());
-    Ok(res)
+    queue_or_apply_config_change(
+        deps,
+        env,
+        ConfigChange::RoyaltyRatio { ratio },
+        Some(mint_instance.mint_instance_params.config.start_time),
+        Some(mint_instance_id),
+    )
 }
 
 pub fn execute_update_mint_price(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     mint_price: Coin,
     mint_instance_id: Option<u32>,
 ) -> Result<Response, ContractError> {
     let auth_details = AUTH_DETAILS.load(deps.storage)?;
 
-    // Check if sender is admin
-    if info.sender != auth_details.admin {
+    // Check if sender is admin or has the finance role
+    let roles = RoleRegistry::new();
+    if !roles.is_authorized(deps.storage, Role::Finance, &auth_details.admin, &info.sender) {
         return Err(ContractError::Unauthorized {});
     }
     // Find the mint_instance
-    let (mint_instance_id, mut mint_instance) =
+    let (mint_instance_id, mint_instance) =
         get_mint_instance_by_id(mint_instance_id, deps.storage)?;
-    mint_instance.mint_instance_params.config.mint_price = mint_price.clone();
-
-    MINT_INSTANCES.save(deps.storage, mint_instance_id, &mint_instance)?;
 
-    let res = Response::new()
-        .add_attribute("action", "update_mint_price")
-        .add_attribute("mint_price_denom", mint_price.denom.to_string())
-        .add_attribute("mint_price_amount", mint_price.amount.to_string())
-        .add_attribute("mint_instance_id", mint_instance_id.to_string());
-    Ok(res)
+    queue_or_apply_config_change(
+        deps,
+        env,
+        ConfigChange::MintPrice { mint_price },
+        Some(mint_instance.mint_instance_params.config.start_time),
+        Some(mint_instance_id),
+    )
 }
 
-pub fn execute_update_admin(
-    deps: DepsMut,
-    _env: Env,
+pub fn execute_update_ownership(
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    admin: String,
+    action: Action,
 ) -> Result<Response, ContractError> {
-    let mut auth_details = AUTH_DETAILS.load(deps.storage)?;
-    // Check if sender is admin
-    if info.sender != auth_details.admin {
-        return Err(ContractError::Unauthorized {});
+    // Roles are delegated by the owner, so they are revoked when the owner changes
+    let owner_changes = matches!(action, Action::AcceptOwnership | Action::RenounceOwnership);
+    let ownership = update_ownership(deps.branch(), &env.block, &info.sender, action)?;
+    if owner_changes {
+        RoleRegistry::new().clear(deps.storage)?;
     }
-
-    let validated_new_admin = deps.api.addr_validate(&admin)?;
-    auth_details.admin = validated_new_admin.clone();
+    // Keep the admin of auth details in sync with the owner
+    // If ownership is renounced, admin is set to the contract itself so no one can act as admin
+    let mut auth_details = AUTH_DETAILS.load(deps.storage)?;
+    auth_details.admin = ownership.owner.clone().unwrap_or(env.contract.address);
     AUTH_DETAILS.save(deps.storage, &auth_details)?;
 
     let res = Response::new()
-        .add_attribute("action", "update_admin")
-        .add_attribute("admin", admin.to_string());
+        .add_attribute("action", "update_ownership")
+        .add_attributes(ownership.into_attributes());
     Ok(res)
 }
 
 pub fn execute_update_payment_collector(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     payment_collector: String,
 ) -> Result<Response, ContractError> {
-    let mut auth_details = AUTH_DETAILS.load(deps.storage)?;
-    // Check if sender is admin
-    if info.sender != auth_details.admin {
+    let auth_details = AUTH_DETAILS.load(deps.storage)?;
+    // Check if sender is admin or has the finance role
+    let roles = RoleRegistry::new();
+    if !roles.is_authorized(deps.storage, Role::Finance, &auth_details.admin, &info.sender) {
         return Err(ContractError::Unauthorized {});
     }
-    let validated_new_payment_collector = deps.api.addr_validate(&payment_collector)?;
-    auth_details.payment_collector = validated_new_payment_collector.clone();
-    AUTH_DETAILS.save(deps.storage, &auth_details)?;
+    let payment_collector = deps.api.addr_validate(&payment_collector)?;
+    // Payment collector is shared, so the active mint instance decides if minting has started
+    let start_time = active_mint_instance_start_time(deps.storage);
+
+    queue_or_apply_config_change(
+        deps,
+        env,
+        ConfigChange::PaymentCollector { payment_collector },
+        start_time,
+        None,
+    )
+}
 
-    let res = Response::new()
-        .add_attribute("action", "update_payment_collector")
-        .add_attribute("payment_collector", payment_collector.to_string());
+fn active_mint_instance_start_time(storage: &dyn Storage) -> Option<Timestamp> {
+    get_mint_instance_by_id(None, storage)
+        .ok()
+        .map(|(_, mint_instance)| mint_instance.mint_instance_params.config.start_time)
+}
+
+fn queue_or_apply_config_change(
+    deps: DepsMut,
+    env: Env,
+    change: ConfigChange,
+    start_time: Option<Timestamp>,
+    mint_instance_id: Option<u32>,
+) -> Result<Response, ContractError> {
+    let timelock = Timelock::new();
+    // Once minting has started, changes are queued until the timelock passes
+    if let Some(start_time) = start_time {
+        if timelock.is_active(deps.storage, env.block.time, start_time)? {
+            let pending_change =
+                timelock.queue(deps.storage, env.block.time, change, mint_instance_id)?;
+            let res = Response::new()
+                .add_attribute("action", "queue_config_change")
+                .add_attributes(pending_change.attributes());
+            return Ok(res);
+        }
+    }
+    apply_config_change(deps, change, mint_instance_id)
+}
+
+fn apply_config_change(
+    deps: DepsMut,
+    change: ConfigChange,
+    mint_instance_id: Option<u32>,
+) -> Result<Response, ContractError> {
+    let res = match change {
+        ConfigChange::MintPrice { mint_price } => {
+            let (mint_instance_id, mut mint_instance) =
+                get_mint_instance_by_id(mint_instance_id, deps.storage)?;
+            mint_instance.mint_instance_params.config.mint_price = mint_price.clone();
+            MINT_INSTANCES.save(deps.storage, mint_instance_id, &mint_instance)?;
+
+            Response::new()
+                .add_attribute("action", "update_mint_price")
+                .add_attribute("mint_price_denom", mint_price.denom.to_string())
+                .add_attribute("mint_price_amount", mint_price.amount.to_string())
+                .add_attribute("mint_instance_id", mint_instance_id.to_string())
+        }
+        ConfigChange::PaymentCollector { payment_collector } => {
+            let mut auth_details = AUTH_DETAILS.load(deps.storage)?;
+            auth_details.payment_collector = payment_collector.clone();
+            AUTH_DETAILS.save(deps.storage, &auth_details)?;
+
+            Response::new()
+                .add_attribute("action", "update_payment_collector")
+                .add_attribute("payment_collector", payment_collector.to_string())
+        }
+        ConfigChange::RoyaltyRatio { ratio } => {
+            let (mint_instance_id, mut mint_instance) =
+                get_mint_instance_by_id(mint_instance_id, deps.storage)?;
+            mint_instance
+                .mint_instance_params
+                .token_details
+                .royalty_ratio = ratio;
+            // Check integrity of token details
+            mint_instance
+                .mint_instance_params
+                .token_details
+                .check_integrity()?;
+            MINT_INSTANCES.save(deps.storage, mint_instance_id, &mint_instance)?;
+
+            Response::new()
+                .add_attribute("action", "update_royalty_ratio")
+                .add_attribute("ratio", ratio.to_string())
+                .add_attribute("mint_instance_id", mint_instance_id.to_string())
+        }
+    };
     Ok(res)
 }
 
@@ -577,7 +742,9 @@ pub fn execute_pause(
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
     let pause_state = PauseState::new()?;
-    pause_state.pause(deps.storage, &info.sender)?;
+    // Ops role can pause without being one of the pausers
+    let is_ops = RoleRegistry::new().has_role(deps.storage, Role::Ops, &info.sender);
+    pause_state.pause_as(deps.storage, &info.sender, is_ops)?;
     let res = Response::new().add_attribute("action", "pause");
     Ok(res)
 }
@@ -587,9 +754,10 @@ pub fn execute_unpause(
     _env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    // Check if sender is admin
     let pause_state = PauseState::new()?;
-    pause_state.unpause(deps.storage, &info.sender)?;
+    // Ops role can unpause without being one of the pausers
+    let is_ops = RoleRegistry::new().has_role(deps.storage, Role::Ops, &info.sender);
+    pause_state.unpause_as(deps.storage, &info.sender, is_ops)?;
     let res = Response::new().add_attribute("action", "unpause");
     Ok(res)
 }
@@ -615,6 +783,154 @@ pub fn execute_set_pausers(
     Ok(res)
 }
 
+pub fn execute_grant_role(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    role: Role,
+    address: String,
+) -> Result<Response, ContractError> {
+    let auth_details = AUTH_DETAILS.load(deps.storage)?;
+    // Only admin can grant roles
+    if info.sender != auth_details.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let address = deps.api.addr_validate(&address)?;
+    let roles = RoleRegistry::new();
+    roles.grant(deps.storage, role, &address)?;
+
+    let res = Response::new()
+        .add_attribute("action", "grant_role")
+        .add_attribute("role", role.as_str())
+        .add_attribute("address", address.to_string());
+    Ok(res)
+}
+
+pub fn execute_revoke_role(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    role: Role,
+    address: String,
+) -> Result<Response, ContractError> {
+    let auth_details = AUTH_DETAILS.load(deps.storage)?;
+    // Only admin can revoke roles
+    if info.sender != auth_details.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let address = deps.api.addr_validate(&address)?;
+    let roles = RoleRegistry::new();
+    if !roles.has_role(deps.storage, role, &address) {
+        return Err(ContractError::RoleNotFound {});
+    }
+    roles.revoke(deps.storage, role, &address);
+
+    let res = Response::new()
+        .add_attribute("action", "revoke_role")
+        .add_attribute("role", role.as_str())
+        .add_attribute("address", address.to_string());
+    Ok(res)
+}
+
+pub fn execute_pending_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    change_id: u32,
+) -> Result<Response, ContractError> {
+    let auth_details = AUTH_DETAILS.load(deps.storage)?;
+    let timelock = Timelock::new();
+    let pending_change = timelock.load(deps.storage, change_id)?;
+    // Check if sender can manage this change
+    if !pending_change
+        .change
+        .is_authorized(deps.storage, &auth_details.admin, &info.sender)
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+    let pending_change = timelock.take_ready(deps.storage, change_id, env.block.time)?;
+    let res = apply_config_change(deps, pending_change.change, pending_change.mint_instance_id)?;
+    Ok(res.add_attribute("change_id", change_id.to_string()))
+}
+
+pub fn execute_cancel_pending_change(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    change_id: u32,
+) -> Result<Response, ContractError> {
+    let auth_details = AUTH_DETAILS.load(deps.storage)?;
+    let timelock = Timelock::new();
+    let pending_change = timelock.load(deps.storage, change_id)?;
+    // Check if sender can manage this change
+    if !pending_change
+        .change
+        .is_authorized(deps.storage, &auth_details.admin, &info.sender)
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+    timelock.cancel(deps.storage, change_id)?;
+
+    let res = Response::new()
+        .add_attribute("action", "cancel_pending_change")
+        .add_attribute("change_id", change_id.to_string());
+    Ok(res)
+}
+
+pub fn execute_update_timelock_duration(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    duration: u64,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin
+    let auth_details = AUTH_DETAILS.load(deps.storage)?;
+    if info.sender != auth_details.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let minting_started = active_mint_instance_start_time(deps.storage)
+        .is_some_and(|start_time| env.block.time >= start_time);
+    let timelock = Timelock::new();
+    timelock.set_duration(deps.storage, duration, minting_started)?;
+
+    let res = Response::new()
+        .add_attribute("action", "update_timelock_duration")
+        .add_attribute("duration", duration.to_string());
+    Ok(res)
+}
+
+pub fn execute_update_sale_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_time: Option<Timestamp>,
+    end_time: Option<Option<Timestamp>>,
+    per_address_limit: Option<Option<u32>>,
+    mint_instance_id: Option<u32>,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin
+    let auth_details = AUTH_DETAILS.load(deps.storage)?;
+    if info.sender != auth_details.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let (mint_instance_id, mut mint_instance) =
+        get_mint_instance_by_id(mint_instance_id, deps.storage)?;
+    let config = mint_instance.mint_instance_params.config.update_sale_config(
+        env.block.time,
+        start_time,
+        end_time,
+        per_address_limit,
+    )?;
+    let res = Response::new()
+        .add_attribute("action", "update_sale_config")
+        .add_attributes(config.sale_attributes())
+        .add_attribute("mint_instance_id", mint_instance_id.to_string());
+    mint_instance.mint_instance_params.config = config;
+    MINT_INSTANCES.save(deps.storage, mint_instance_id, &mint_instance)?;
+
+    Ok(res)
+}
+
 pub fn execute_create_mint_instance(
     deps: DepsMut,
     env: Env,
@@ -759,8 +1075,9 @@ pub fn execute_update_denom(
 ) -> Result<Response, ContractError> {
     let auth_details = AUTH_DETAILS.load(deps.storage)?;
     let admin = auth_details.admin;
-    // Current mint_instances admin can update the denom
-    if info.sender != admin {
+    // Check if sender is admin or has the ops role
+    let roles = RoleRegistry::new();
+    if !roles.is_authorized(deps.storage, Role::Ops, &admin, &info.sender) {
         return Err(ContractError::Unauthorized {});
     }
     let collection_details = COLLECTION.load(deps.storage)?;
@@ -804,6 +1121,44 @@ fn execute_purge_denom(
         .add_message(purge_msg))
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    // Minters instantiated before ownership was tracked have no owner yet
+    if get_ownership(deps.storage).is_err() {
+        let auth_details = AUTH_DETAILS.load(deps.storage)?;
+        cw_ownable::initialize_owner(deps.storage, deps.api, Some(auth_details.admin.as_str()))?;
+    }
+    // Roles start empty, minters instantiated before the timelock get the default delay
+    let timelock = Timelock::new();
+    if timelock.duration.may_load(deps.storage)?.is_none() {
+        timelock
+            .duration
+            .save(deps.storage, &DEFAULT_TIMELOCK_DURATION)?;
+    }
+    let mut response = Response::new().add_attribute("action", "migrate");
+    // Minters created before the global halt record the factory given in the migrate msg
+    let halt = GlobalHalt::new();
+    if let Some(factory) = msg.factory {
+        if halt.factory.may_load(deps.storage)?.is_none() {
+            let factory = deps.api.addr_validate(&factory)?;
+            // Error if the factory has no record of creating this minter
+            let created: Option<CreatedContract> = deps.querier.query_wasm_smart(
+                &factory,
+                &OpenEditionMinterFactoryQueryMsg::CreatedContract {
+                    address: env.contract.address.to_string(),
+                },
+            )?;
+            if created.is_none() {
+                return Err(HaltError::NotCreatedByFactory { factory }.into());
+            }
+            let factory = halt.set_factory(deps.storage, deps.querier, &factory)?;
+            response = response.add_attributes(vec![halt_factory_attribute(factory)]);
+        }
+    }
+    Ok(response)
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(
     deps: Deps,
@@ -826,8 +1181,24 @@ pub fn query(
             to_json_binary(&query_total_tokens_minted(deps, env)?)
         }
         BaseMinterQueryMsg::AuthDetails {} => to_json_binary(&query_auth_details(deps, env)?),
+        BaseMinterQueryMsg::Ownership {} => to_json_binary(&get_ownership(deps.storage)?),
         BaseMinterQueryMsg::IsPaused {} => to_json_binary(&query_is_paused(deps, env)?),
         BaseMinterQueryMsg::Pausers {} => to_json_binary(&query_pausers(deps, env)?),
+        BaseMinterQueryMsg::HaltStatus {} => to_json_binary(&query_halt_status(deps, env)?),
+        BaseMinterQueryMsg::TimelockDuration {} => {
+            to_json_binary(&query_timelock_duration(deps, env)?)
+        }
+        BaseMinterQueryMsg::PendingChanges { start_after, limit } => {
+            to_json_binary(&query_pending_changes(deps, env, start_after, limit)?)
+        }
+        BaseMinterQueryMsg::RoleMembers {
+            role,
+            start_after,
+            limit,
+        } => to_json_binary(&query_role_members(deps, env, role, start_after, limit)?),
+        BaseMinterQueryMsg::AddressRoles { address } => {
+            to_json_binary(&query_address_roles(deps, env, address)?)
+        }
         BaseMinterQueryMsg::Extension(ext) => match ext {
             QueryMsgExtension::ActiveMintInstanceId {} => {
                 to_json_binary(&query_active_mint_instance_id(deps, env)?)
@@ -943,6 +1314,49 @@ fn query_pausers(deps: Deps, _env: Env) -> Result<Vec<Addr>, ContractError> {
     Ok(pausers)
 }
 
+fn query_halt_status(deps: Deps, _env: Env) -> Result<HaltStatus, ContractError> {
+    let halt_status = GlobalHalt::new().status(deps.storage, deps.querier)?;
+    Ok(halt_status)
+}
+
+fn query_role_members(
+    deps: Deps,
+    _env: Env,
+    role: Role,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<Addr>, ContractError> {
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    let roles = RoleRegistry::new();
+    let members = roles.role_members(deps.storage, role, start_after, limit)?;
+    Ok(members)
+}
+
+fn query_address_roles(deps: Deps, _env: Env, address: String) -> Result<Vec<Role>, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    let roles = RoleRegistry::new();
+    Ok(roles.address_roles(deps.storage, &address))
+}
+
+fn query_timelock_duration(deps: Deps, _env: Env) -> Result<u64, ContractError> {
+    let timelock = Timelock::new();
+    let duration = timelock.duration(deps.storage)?;
+    Ok(duration)
+}
+
+fn query_pending_changes(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<u32>,
+    limit: Option<u32>,
+) -> Result<Vec<PendingChange>, ContractError> {
+    let timelock = Timelock::new();
+    let pending_changes = timelock.pending_changes(deps.storage, start_after, limit)?;
+    Ok(pending_changes)
+}
+
 fn query_active_mint_instance_id(deps: Deps, _env: Env) -> Result<u32, ContractError> {
     let active_mint_instance_id = ACTIVE_MINT_INSTANCE_ID.load(deps.storage).unwrap_or(0);
     Ok(active_mint_instance_id)
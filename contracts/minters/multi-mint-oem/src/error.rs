@@ -1,6 +1,11 @@
 use cosmwasm_std::{Coin, StdError, Timestamp, Uint128};
+use cw_ownable::OwnershipError;
 use cw_utils::PaymentError;
-use minter_types::{config::ConfigurationError, token_details::TokenDetailsError};
+use minter_types::{
+    config::ConfigurationError, timelock::TimelockError, token_details::TokenDetailsError,
+};
+use pauser::halt::HaltError;
+use pauser::migration::MigrationError;
 use pauser::PauseError;
 use serde_json::Error as SerdeError;
 use thiserror::Error;
@@ -13,9 +18,24 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("Address does not have the role")]
+    RoleNotFound {},
+
     #[error(transparent)]
     Pause(#[from] PauseError),
 
+    #[error(transparent)]
+    Halt(#[from] HaltError),
+
+    #[error(transparent)]
+    Ownership(#[from] OwnershipError),
+
+    #[error(transparent)]
+    Timelock(#[from] TimelockError),
+
+    #[error(transparent)]
+    Migration(#[from] MigrationError),
+
     #[error(transparent)]
     TokenDetailsError(#[from] TokenDetailsError),
 
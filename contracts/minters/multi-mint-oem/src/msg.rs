@@ -1,15 +1,24 @@
 use crate::mint_instance::MintInstance;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Coin;
+use cosmwasm_std::{Coin, Timestamp};
+use cw_ownable::cw_ownable_execute;
 use minter_types::{
-    config::Config, msg::MintHistoryResponse, token_details::TokenDetails, types::UserDetails,
+    config::{deserialize_some, Config},
+    msg::MintHistoryResponse,
+    roles::Role,
+    token_details::TokenDetails,
+    types::UserDetails,
 };
 use omniflix_std::types::omniflix::onft::v1beta1::WeightedAddress;
+use whitelist_types::MembershipProof;
 
+#[cw_ownable_execute]
 #[cw_serde]
 pub enum ExecuteMsg {
     Mint {
         mint_instance_id: Option<u32>,
+        // Membership proof for whitelist rounds that do not store their members
+        proof: Option<MembershipProof>,
     },
     MintAdmin {
         recipient: String,
@@ -32,6 +41,14 @@ pub enum ExecuteMsg {
     SetPausers {
         pausers: Vec<String>,
     },
+    GrantRole {
+        role: Role,
+        address: String,
+    },
+    RevokeRole {
+        role: Role,
+        address: String,
+    },
     CreateMintInstance {
         token_details: TokenDetails,
         config: Config,
@@ -48,12 +65,35 @@ pub enum ExecuteMsg {
         preview_uri: Option<String>,
     },
     PurgeDenom {},
-    UpdateAdmin {
-        admin: String,
-    },
     UpdatePaymentCollector {
         payment_collector: String,
     },
+    ExecutePendingChange {
+        change_id: u32,
+    },
+    CancelPendingChange {
+        change_id: u32,
+    },
+    UpdateTimelockDuration {
+        duration: u64,
+    },
+    /// A `null` end time or per address limit clears it, a missing one keeps the current value
+    UpdateSaleConfig {
+        start_time: Option<Timestamp>,
+        #[serde(
+            default,
+            deserialize_with = "deserialize_some",
+            skip_serializing_if = "Option::is_none"
+        )]
+        end_time: Option<Option<Timestamp>>,
+        #[serde(
+            default,
+            deserialize_with = "deserialize_some",
+            skip_serializing_if = "Option::is_none"
+        )]
+        per_address_limit: Option<Option<u32>>,
+        mint_instance_id: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -82,3 +122,9 @@ pub enum QueryMsgExtension {
         mint_instance_id: Option<u32>,
     },
 }
+
+#[cw_serde]
+pub struct MigrateMsg {
+    // Factory that created the minter, recorded if the minter predates the global halt
+    pub factory: Option<String>,
+}
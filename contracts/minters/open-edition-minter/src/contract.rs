@@ -2,12 +2,16 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env,
-    MessageInfo, Response, StdResult, WasmMsg,
+    MessageInfo, Response, StdResult, Timestamp, WasmMsg,
 };
+use cw_ownable::{get_ownership, update_ownership, Action};
 use cw_utils::{may_pay, maybe_addr, must_pay, nonpayable};
+use factory_types::registry::CreatedContract;
 use minter_types::collection_details::{update_collection_details, CollectionDetails};
 use minter_types::config::Config;
 use minter_types::msg::{MintHistoryResponse, QueryMsg as BaseMinterQueryMsg};
+use minter_types::roles::{Role, RoleRegistry};
+use minter_types::timelock::{ConfigChange, PendingChange, Timelock, DEFAULT_TIMELOCK_DURATION};
 use minter_types::token_details::{Token, TokenDetails};
 use minter_types::types::{AuthDetails, UserDetails};
 use minter_types::utils::{
@@ -17,7 +21,7 @@ use minter_types::utils::{
 use std::str::FromStr;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, OEMQueryExtension};
+use crate::msg::{ExecuteMsg, MigrateMsg, OEMQueryExtension};
 use crate::state::{
     last_token_id, AUTH_DETAILS, COLLECTION, CONFIG, MINTED_COUNT, TOKEN_DETAILS,
     USER_MINTING_DETAILS,
@@ -28,9 +32,12 @@ use omniflix_open_edition_minter_factory::msg::{
 };
 use omniflix_round_whitelist::msg::ExecuteMsg as RoundWhitelistExecuteMsg;
 use omniflix_std::types::omniflix::onft::v1beta1::{MsgPurgeDenom, WeightedAddress};
+use pauser::halt::{halt_factory_attribute, GlobalHalt, HaltError, HaltStatus};
+use pauser::migration::migrate_version;
 use pauser::PauseState;
 use whitelist_types::{
-    check_if_address_is_member, check_if_whitelist_is_active, check_whitelist_price,
+    check_if_address_is_member, check_if_whitelist_is_active, check_public_rollover,
+    check_whitelist_price, MembershipProof,
 };
 
 // version info for migration info
@@ -45,6 +52,8 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     // Set the contract version
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    // Record the factory so minting stops when it halts its minters
+    let factory = GlobalHalt::new().set_factory(deps.storage, deps.querier, &info.sender)?;
 
     // Query factory parameters of the instantiator
     // If the instantiator is not our factory, we won't be able to parse the response
@@ -109,6 +118,14 @@ pub fn instantiate(
     CONFIG.save(deps.storage, &config)?;
     MINTED_COUNT.save(deps.storage, &0)?;
     AUTH_DETAILS.save(deps.storage, &auth_details)?;
+    // Admin is tracked as the owner for two-step ownership transfers
+    cw_ownable::initialize_owner(deps.storage, deps.api, Some(auth_details.admin.as_str()))?;
+    // Changes made after minting starts are delayed unless the creator disables the timelock
+    Timelock::new().set_duration(
+        deps.storage,
+        msg.timelock_duration.unwrap_or(DEFAULT_TIMELOCK_DURATION),
+        false,
+    )?;
 
     // Initialize pause state and set admin as pauser
     let pause_state = PauseState::new()?;
@@ -148,7 +165,8 @@ pub fn instantiate(
             auth_details.payment_collector.to_string(),
         )
         .add_attribute("mint_price", config.mint_price.to_string())
-        .add_attribute("start_time", config.start_time.to_string());
+        .add_attribute("start_time", config.start_time.to_string())
+        .add_attributes(vec![halt_factory_attribute(factory)]);
 
     Ok(res)
 }
@@ -161,7 +179,7 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Mint {} => execute_mint(deps, env, info),
+        ExecuteMsg::Mint { proof } => execute_mint(deps, env, info, proof),
         ExecuteMsg::MintAdmin { recipient } => execute_mint_admin(deps, env, info, recipient),
         ExecuteMsg::UpdateRoyaltyRatio { ratio } => {
             execute_update_royalty_ratio(deps, env, info, ratio)
@@ -172,13 +190,33 @@ pub fn execute(
         ExecuteMsg::UpdateWhitelistAddress { address } => {
             execute_update_whitelist_address(deps, env, info, address)
         }
-        ExecuteMsg::UpdateAdmin { admin } => execute_update_admin(deps, env, info, admin),
+        ExecuteMsg::UpdateOwnership(action) => execute_update_ownership(deps, env, info, action),
         ExecuteMsg::UpdatePaymentCollector { payment_collector } => {
             execute_update_payment_collector(deps, env, info, payment_collector)
         }
         ExecuteMsg::Pause {} => execute_pause(deps, env, info),
         ExecuteMsg::Unpause {} => execute_unpause(deps, env, info),
         ExecuteMsg::SetPausers { pausers } => execute_set_pausers(deps, env, info, pausers),
+        ExecuteMsg::ExecutePendingChange { change_id } => {
+            execute_pending_change(deps, env, info, change_id)
+        }
+        ExecuteMsg::CancelPendingChange { change_id } => {
+            execute_cancel_pending_change(deps, env, info, change_id)
+        }
+        ExecuteMsg::UpdateTimelockDuration { duration } => {
+            execute_update_timelock_duration(deps, env, info, duration)
+        }
+        ExecuteMsg::UpdateSaleConfig {
+            start_time,
+            end_time,
+            per_address_limit,
+        } => execute_update_sale_config(deps, env, info, start_time, end_time, per_address_limit),
+        ExecuteMsg::GrantRole { role, address } => {
+            execute_grant_role(deps, env, info, role, address)
+        }
+        ExecuteMsg::RevokeRole { role, address } => {
+            execute_revoke_role(deps, env, info, role, address)
+        }
         ExecuteMsg::UpdateRoyaltyReceivers { receivers } => {
             execute_update_royalty_receivers(deps, env, info, receivers)
         }
@@ -192,10 +230,17 @@ pub fn execute(
     }
 }
 
-pub fn execute_mint(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+pub fn execute_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proof: Option<MembershipProof>,
+) -> Result<Response, ContractError> {
     // Ensure the contract is not paused
     let pause_state = PauseState::new()?;
     pause_state.error_if_paused(deps.storage)?;
+    // Error if the factory halted its minters
+    GlobalHalt::new().error_if_halted(deps.storage, deps.querier)?;
 
     // Load contract configuration and authorization details
     let config = CONFIG.load(deps.storage)?;
@@ -242,23 +287,37 @@ pub fn execute_mint(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Respon
     if !is_public {
         // Check if any whitelist is active
         if let Some(whitelist_address) = config.whitelist_address {
-            let whitelist_price = check_whitelist_price(&whitelist_address, deps.as_ref())
-                .map_err(|_| ContractError::WhitelistNotActive {})?;
-            mint_price = whitelist_price;
+            // Check if whitelist is active
+            let is_active = check_if_whitelist_is_active(&whitelist_address, deps.as_ref())?;
+            if !is_active {
+                return Err(ContractError::WhitelistNotActive {});
+            }
 
             // Check if member is whitelisted
             let is_member = check_if_address_is_member(
                 &info.sender.clone(),
                 &whitelist_address,
+                proof.clone(),
                 deps.as_ref(),
             )?;
             if !is_member {
                 return Err(ContractError::AddressNotWhitelisted {});
             }
 
+            // Price of the round the collector mints from
+            let whitelist_price = check_whitelist_price(
+                &info.sender,
+                &whitelist_address,
+                &env.contract.address,
+                proof.clone(),
+                deps.as_ref(),
+            )?;
+            mint_price = whitelist_price;
+
             // If member is whitelisted, execute private mint
             let execute_msg = RoundWhitelistExecuteMsg::PrivateMint {
                 collector: info.sender.clone().into_string(),
+                proof,
             };
             let msg = CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: whitelist_address.into_string(),
@@ -279,7 +338,20 @@ pub fn execute_mint(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Respon
         // Check if per address limit is reached
         if let Some(per_address_limit) = config.per_address_limit {
             if user_details.public_mint_count > per_address_limit {
-                return Err(ContractError::AddressReachedMintLimit {});
+                // Unused whitelist allocation can roll over to the public limit
+                let public_rollover = match &config.whitelist_address {
+                    Some(whitelist_address) => check_public_rollover(
+                        &info.sender,
+                        whitelist_address,
+                        &env.contract.address,
+                        deps.as_ref(),
+                    )?,
+                    None => 0,
+                };
+                let public_limit = per_address_limit.saturating_add(public_rollover);
+                if user_details.public_mint_count > public_limit {
+                    return Err(ContractError::AddressReachedMintLimit {});
+                }
             }
         }
     }
@@ -315,6 +387,7 @@ pub fn execute_mint(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Respon
         token_id.to_string(),
         env.contract.address.clone(),
         info.sender.clone(),
+        config.num_tokens,
     )?
     .into();
     messages.push(mint_msg);
@@ -354,12 +427,15 @@ pub fn execute_mint_admin(
     let config = CONFIG.load(deps.storage)?;
     let pause_state = PauseState::new()?;
     pause_state.error_if_paused(deps.storage)?;
+    // Error if the factory halted its minters
+    GlobalHalt::new().error_if_halted(deps.storage, deps.querier)?;
     let collection = COLLECTION.load(deps.storage)?;
     let token_details = TOKEN_DETAILS.load(deps.storage)?;
     let auth_details = AUTH_DETAILS.load(deps.storage)?;
 
     // Check if the sender is authorized as admin
-    if info.sender != auth_details.admin {
+    let roles = RoleRegistry::new();
+    if !roles.is_authorized(deps.storage, Role::Airdrop, &auth_details.admin, &info.sender) {
         return Err(ContractError::Unauthorized {});
     }
 
@@ -412,6 +488,7 @@ pub fn execute_mint_admin(
         token_id.to_string(),
         env.contract.address,
         recipient,
+        config.num_tokens,
     )?
     .into();
 
@@ -448,7 +525,7 @@ pub fn execute_burn_remaining_tokens(
 
 pub fn execute_update_royalty_ratio(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     ratio: String,
 ) -> Result<Response, ContractError> {
@@ -464,36 +541,24 @@ pub fn execute_update_royalty_ratio(
     token_details.royalty_ratio = ratio;
     // Check if token details are valid
     token_details.check_integrity()?;
-    TOKEN_DETAILS.save(deps.storage, &token_details)?;
 
-    let res = Response::new()
-        .add_attribute("action", "update_royalty_ratio")
-        .add_attribute("ratio", ratio.to_string());
-    Ok(res)
+    queue_or_apply_config_change(deps, env, ConfigChange::RoyaltyRatio { ratio })
 }
 
 pub fn execute_update_mint_price(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     mint_price: Coin,
 ) -> Result<Response, ContractError> {
-    // Check if sender is admin
-    let mut config = CONFIG.load(deps.storage)?;
+    // Check if sender is admin or has the finance role
     let auth_details = AUTH_DETAILS.load(deps.storage)?;
-    // Check if admin
-    if info.sender != auth_details.admin {
+    let roles = RoleRegistry::new();
+    if !roles.is_authorized(deps.storage, Role::Finance, &auth_details.admin, &info.sender) {
         return Err(ContractError::Unauthorized {});
     }
-    config.mint_price = mint_price.clone();
-
-    CONFIG.save(deps.storage, &config)?;
 
-    let res = Response::new()
-        .add_attribute("action", "update_mint_price")
-        .add_attribute("mint_price_amount", mint_price.amount.to_string())
-        .add_attribute("denom", mint_price.denom);
-    Ok(res)
+    queue_or_apply_config_change(deps, env, ConfigChange::MintPrice { mint_price })
 }
 
 pub fn execute_update_whitelist_address(
@@ -537,7 +602,9 @@ pub fn execute_pause(
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
     let pause_state = PauseState::new()?;
-    pause_state.pause(deps.storage, &info.sender)?;
+    // Ops role can pause without being one of the pausers
+    let is_ops = RoleRegistry::new().has_role(deps.storage, Role::Ops, &info.sender);
+    pause_state.pause_as(deps.storage, &info.sender, is_ops)?;
     let res = Response::new().add_attribute("action", "pause");
     Ok(res)
 }
@@ -547,9 +614,10 @@ pub fn execute_unpause(
     _env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    // Check if sender is admin
     let pause_state = PauseState::new()?;
-    pause_state.unpause(deps.storage, &info.sender)?;
+    // Ops role can unpause without being one of the pausers
+    let is_ops = RoleRegistry::new().has_role(deps.storage, Role::Ops, &info.sender);
+    pause_state.unpause_as(deps.storage, &info.sender, is_ops)?;
     let res = Response::new().add_attribute("action", "unpause");
     Ok(res)
 }
@@ -575,47 +643,236 @@ pub fn execute_set_pausers(
     Ok(res)
 }
 
-pub fn execute_update_admin(
+pub fn execute_grant_role(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    admin: String,
+    role: Role,
+    address: String,
 ) -> Result<Response, ContractError> {
-    // Check if sender is admin
-    let mut auth_details = AUTH_DETAILS.load(deps.storage)?;
-    // Check if admin
+    let auth_details = AUTH_DETAILS.load(deps.storage)?;
+    // Only admin can grant roles
     if info.sender != auth_details.admin {
         return Err(ContractError::Unauthorized {});
     }
-    let new_admin = deps.api.addr_validate(&admin)?;
-    auth_details.admin = new_admin.clone();
-    AUTH_DETAILS.save(deps.storage, &auth_details)?;
+    let address = deps.api.addr_validate(&address)?;
+    let roles = RoleRegistry::new();
+    roles.grant(deps.storage, role, &address)?;
 
     let res = Response::new()
-        .add_attribute("action", "update_admin")
-        .add_attribute("admin", admin.to_string());
+        .add_attribute("action", "grant_role")
+        .add_attribute("role", role.as_str())
+        .add_attribute("address", address.to_string());
     Ok(res)
 }
 
-pub fn execute_update_payment_collector(
+pub fn execute_revoke_role(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    payment_collector: String,
+    role: Role,
+    address: String,
+) -> Result<Response, ContractError> {
+    let auth_details = AUTH_DETAILS.load(deps.storage)?;
+    // Only admin can revoke roles
+    if info.sender != auth_details.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let address = deps.api.addr_validate(&address)?;
+    let roles = RoleRegistry::new();
+    if !roles.has_role(deps.storage, role, &address) {
+        return Err(ContractError::RoleNotFound {});
+    }
+    roles.revoke(deps.storage, role, &address);
+
+    let res = Response::new()
+        .add_attribute("action", "revoke_role")
+        .add_attribute("role", role.as_str())
+        .add_attribute("address", address.to_string());
+    Ok(res)
+}
+
+pub fn execute_pending_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    change_id: u32,
+) -> Result<Response, ContractError> {
+    let auth_details = AUTH_DETAILS.load(deps.storage)?;
+    let timelock = Timelock::new();
+    let pending_change = timelock.load(deps.storage, change_id)?;
+    // Check if sender can manage this change
+    if !pending_change
+        .change
+        .is_authorized(deps.storage, &auth_details.admin, &info.sender)
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+    let pending_change = timelock.take_ready(deps.storage, change_id, env.block.time)?;
+    let res = apply_config_change(deps, pending_change.change)?;
+    Ok(res.add_attribute("change_id", change_id.to_string()))
+}
+
+pub fn execute_cancel_pending_change(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    change_id: u32,
+) -> Result<Response, ContractError> {
+    let auth_details = AUTH_DETAILS.load(deps.storage)?;
+    let timelock = Timelock::new();
+    let pending_change = timelock.load(deps.storage, change_id)?;
+    // Check if sender can manage this change
+    if !pending_change
+        .change
+        .is_authorized(deps.storage, &auth_details.admin, &info.sender)
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+    timelock.cancel(deps.storage, change_id)?;
+
+    let res = Response::new()
+        .add_attribute("action", "cancel_pending_change")
+        .add_attribute("change_id", change_id.to_string());
+    Ok(res)
+}
+
+pub fn execute_update_timelock_duration(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    duration: u64,
 ) -> Result<Response, ContractError> {
     // Check if sender is admin
-    let mut auth_details = AUTH_DETAILS.load(deps.storage)?;
-    // Check if admin
+    let auth_details = AUTH_DETAILS.load(deps.storage)?;
     if info.sender != auth_details.admin {
         return Err(ContractError::Unauthorized {});
     }
-    let new_payment_collector = deps.api.addr_validate(&payment_collector)?;
-    auth_details.payment_collector = new_payment_collector.clone();
+    let minting_started = env.block.time >= CONFIG.load(deps.storage)?.start_time;
+    let timelock = Timelock::new();
+    timelock.set_duration(deps.storage, duration, minting_started)?;
+
+    let res = Response::new()
+        .add_attribute("action", "update_timelock_duration")
+        .add_attribute("duration", duration.to_string());
+    Ok(res)
+}
+
+pub fn execute_update_sale_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_time: Option<Timestamp>,
+    end_time: Option<Option<Timestamp>>,
+    per_address_limit: Option<Option<u32>>,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin
+    let auth_details = AUTH_DETAILS.load(deps.storage)?;
+    if info.sender != auth_details.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let config =
+        config.update_sale_config(env.block.time, start_time, end_time, per_address_limit)?;
+    CONFIG.save(deps.storage, &config)?;
+
+    let res = Response::new()
+        .add_attribute("action", "update_sale_config")
+        .add_attributes(config.sale_attributes());
+    Ok(res)
+}
+
+pub fn execute_update_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: Action,
+) -> Result<Response, ContractError> {
+    // Roles are delegated by the owner, so they are revoked when the owner changes
+    let owner_changes = matches!(action, Action::AcceptOwnership | Action::RenounceOwnership);
+    let ownership = update_ownership(deps.branch(), &env.block, &info.sender, action)?;
+    if owner_changes {
+        RoleRegistry::new().clear(deps.storage)?;
+    }
+    // Keep the admin of auth details in sync with the owner
+    // If ownership is renounced, admin is set to the contract itself so no one can act as admin
+    let mut auth_details = AUTH_DETAILS.load(deps.storage)?;
+    auth_details.admin = ownership.owner.clone().unwrap_or(env.contract.address);
     AUTH_DETAILS.save(deps.storage, &auth_details)?;
 
     let res = Response::new()
-        .add_attribute("action", "update_payment_collector")
-        .add_attribute("payment_collector", new_payment_collector.to_string());
+        .add_attribute("action", "update_ownership")
+        .add_attributes(ownership.into_attributes());
+    Ok(res)
+}
+
+pub fn execute_update_payment_collector(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    payment_collector: String,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin or has the finance role
+    let auth_details = AUTH_DETAILS.load(deps.storage)?;
+    let roles = RoleRegistry::new();
+    if !roles.is_authorized(deps.storage, Role::Finance, &auth_details.admin, &info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    let payment_collector = deps.api.addr_validate(&payment_collector)?;
+
+    queue_or_apply_config_change(deps, env, ConfigChange::PaymentCollector { payment_collector })
+}
+
+fn queue_or_apply_config_change(
+    deps: DepsMut,
+    env: Env,
+    change: ConfigChange,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let timelock = Timelock::new();
+    // Once minting has started, changes are queued until the timelock passes
+    if timelock.is_active(deps.storage, env.block.time, config.start_time)? {
+        let pending_change = timelock.queue(deps.storage, env.block.time, change, None)?;
+        let res = Response::new()
+            .add_attribute("action", "queue_config_change")
+            .add_attributes(pending_change.attributes());
+        return Ok(res);
+    }
+    apply_config_change(deps, change)
+}
+
+fn apply_config_change(deps: DepsMut, change: ConfigChange) -> Result<Response, ContractError> {
+    let res = match change {
+        ConfigChange::MintPrice { mint_price } => {
+            let mut config = CONFIG.load(deps.storage)?;
+            config.mint_price = mint_price.clone();
+            CONFIG.save(deps.storage, &config)?;
+
+            Response::new()
+                .add_attribute("action", "update_mint_price")
+                .add_attribute("mint_price_amount", mint_price.amount.to_string())
+                .add_attribute("denom", mint_price.denom)
+        }
+        ConfigChange::PaymentCollector { payment_collector } => {
+            let mut auth_details = AUTH_DETAILS.load(deps.storage)?;
+            auth_details.payment_collector = payment_collector.clone();
+            AUTH_DETAILS.save(deps.storage, &auth_details)?;
+
+            Response::new()
+                .add_attribute("action", "update_payment_collector")
+                .add_attribute("payment_collector", payment_collector.to_string())
+        }
+        ConfigChange::RoyaltyRatio { ratio } => {
+            let mut token_details = TOKEN_DETAILS.load(deps.storage)?;
+            token_details.royalty_ratio = ratio;
+            token_details.check_integrity()?;
+            TOKEN_DETAILS.save(deps.storage, &token_details)?;
+
+            Response::new()
+                .add_attribute("action", "update_royalty_ratio")
+                .add_attribute("ratio", ratio.to_string())
+        }
+    };
     Ok(res)
 }
 pub fn execute_update_royalty_receivers(
@@ -657,11 +914,12 @@ pub fn execute_update_denom(
     description: Option<String>,
     preview_uri: Option<String>,
 ) -> Result<Response, ContractError> {
-    // Check if sender is admin
+    // Check if sender is admin or has the ops role
     let collection_details = COLLECTION.load(deps.storage)?;
     let auth_details = AUTH_DETAILS.load(deps.storage)?;
     // Check if admin
-    if info.sender != auth_details.admin {
+    let roles = RoleRegistry::new();
+    if !roles.is_authorized(deps.storage, Role::Ops, &auth_details.admin, &info.sender) {
         return Err(ContractError::Unauthorized {});
     }
     let new_collection_details = update_collection_details(
@@ -709,6 +967,44 @@ fn execute_purge_denom(
     Ok(res)
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    // Minters instantiated before ownership was tracked have no owner yet
+    if get_ownership(deps.storage).is_err() {
+        let auth_details = AUTH_DETAILS.load(deps.storage)?;
+        cw_ownable::initialize_owner(deps.storage, deps.api, Some(auth_details.admin.as_str()))?;
+    }
+    // Roles start empty, minters instantiated before the timelock get the default delay
+    let timelock = Timelock::new();
+    if timelock.duration.may_load(deps.storage)?.is_none() {
+        timelock
+            .duration
+            .save(deps.storage, &DEFAULT_TIMELOCK_DURATION)?;
+    }
+    let mut response = Response::new().add_attribute("action", "migrate");
+    // Minters created before the global halt record the factory given in the migrate msg
+    let halt = GlobalHalt::new();
+    if let Some(factory) = msg.factory {
+        if halt.factory.may_load(deps.storage)?.is_none() {
+            let factory = deps.api.addr_validate(&factory)?;
+            // Error if the factory has no record of creating this minter
+            let created: Option<CreatedContract> = deps.querier.query_wasm_smart(
+                &factory,
+                &OpenEditionMinterFactoryQueryMsg::CreatedContract {
+                    address: env.contract.address.to_string(),
+                },
+            )?;
+            if created.is_none() {
+                return Err(HaltError::NotCreatedByFactory { factory }.into());
+            }
+            let factory = halt.set_factory(deps.storage, deps.querier, &factory)?;
+            response = response.add_attributes(vec![halt_factory_attribute(factory)]);
+        }
+    }
+    Ok(response)
+}
+
 // Implement Queries
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(
@@ -731,7 +1027,23 @@ pub fn query(
         }
         BaseMinterQueryMsg::IsPaused {} => to_json_binary(&query_is_paused(deps, env)?),
         BaseMinterQueryMsg::Pausers {} => to_json_binary(&query_pausers(deps, env)?),
+        BaseMinterQueryMsg::HaltStatus {} => to_json_binary(&query_halt_status(deps, env)?),
+        BaseMinterQueryMsg::TimelockDuration {} => {
+            to_json_binary(&query_timelock_duration(deps, env)?)
+        }
+        BaseMinterQueryMsg::PendingChanges { start_after, limit } => {
+            to_json_binary(&query_pending_changes(deps, env, start_after, limit)?)
+        }
+        BaseMinterQueryMsg::RoleMembers {
+            role,
+            start_after,
+            limit,
+        } => to_json_binary(&query_role_members(deps, env, role, start_after, limit)?),
+        BaseMinterQueryMsg::AddressRoles { address } => {
+            to_json_binary(&query_address_roles(deps, env, address)?)
+        }
         BaseMinterQueryMsg::AuthDetails {} => to_json_binary(&query_auth_details(deps, env)?),
+        BaseMinterQueryMsg::Ownership {} => to_json_binary(&get_ownership(deps.storage)?),
         BaseMinterQueryMsg::Extension(ext) => match ext {
             OEMQueryExtension::TokensRemaining {} => {
                 to_json_binary(&query_tokens_remaining(deps, env)?)
@@ -793,6 +1105,49 @@ fn query_pausers(deps: Deps, _env: Env) -> Result<Vec<Addr>, ContractError> {
     let pausers = pause_state.pausers.load(deps.storage).unwrap_or(vec![]);
     Ok(pausers)
 }
+
+fn query_halt_status(deps: Deps, _env: Env) -> Result<HaltStatus, ContractError> {
+    let halt_status = GlobalHalt::new().status(deps.storage, deps.querier)?;
+    Ok(halt_status)
+}
+
+fn query_role_members(
+    deps: Deps,
+    _env: Env,
+    role: Role,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<Addr>, ContractError> {
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    let roles = RoleRegistry::new();
+    let members = roles.role_members(deps.storage, role, start_after, limit)?;
+    Ok(members)
+}
+
+fn query_address_roles(deps: Deps, _env: Env, address: String) -> Result<Vec<Role>, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    let roles = RoleRegistry::new();
+    Ok(roles.address_roles(deps.storage, &address))
+}
+
+fn query_timelock_duration(deps: Deps, _env: Env) -> Result<u64, ContractError> {
+    let timelock = Timelock::new();
+    let duration = timelock.duration(deps.storage)?;
+    Ok(duration)
+}
+
+fn query_pending_changes(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<u32>,
+    limit: Option<u32>,
+) -> Result<Vec<PendingChange>, ContractError> {
+    let timelock = Timelock::new();
+    let pending_changes = timelock.pending_changes(deps.storage, start_after, limit)?;
+    Ok(pending_changes)
+}
 fn query_auth_details(deps: Deps, _env: Env) -> Result<AuthDetails, ContractError> {
     let auth_details = AUTH_DETAILS.load(deps.storage)?;
     Ok(auth_details)
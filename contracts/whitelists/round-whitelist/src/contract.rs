@@ -1,25 +1,40 @@
-use core::str;
-
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
-use cosmwasm_std::{Coin, Order};
+use cosmwasm_std::{Addr, Coin, Empty, Order, Timestamp};
 use cw2::set_contract_version;
+use cw_ownable::{assert_owner, get_ownership, initialize_owner, update_ownership, Action};
 use cw_storage_plus::Bound;
 use omniflix_round_whitelist_factory::msg::ParamsResponse;
 use omniflix_round_whitelist_factory::msg::QueryMsg as QueryFactoryParams;
+use pauser::migration::migrate_version;
+use pauser::PauseState;
 
 use crate::error::ContractError;
-use crate::msg::ExecuteMsg;
+use crate::merkle::validate_merkle_root;
+use crate::migration::{
+    error_if_migrating, has_legacy_members, migrate_round_members, DEFAULT_MIGRATION_LIMIT,
+    MAX_MIGRATION_LIMIT,
+};
+use crate::msg::{ExecuteMsg, MigrateMsg};
+use crate::resolver::resolve_collector_round;
+use crate::rollover::{next_round_rollover, public_rollover};
 use crate::round::RoundMethods;
+use crate::voucher::{consume_voucher, voucher_nonce_usage};
 
 use crate::state::{
-    check_member, remove_members_with_round_index, save_members, Config, Rounds, UserMintDetails,
-    CONFIG, ROUNDMEMBERS, ROUNDS_KEY, USERMINTDETAILS_KEY,
+    check_member, member_allocation, remaining_round_supply, remove_members,
+    remove_members_with_round_index, save_members, save_round_members, Config, Rounds,
+    UserMintDetails, CONFIG, MINTERS, MINTER_ROUND_MINTS, ROUNDMEMBERS, ROUNDS_KEY, ROUND_MINTS,
+    USERMINTDETAILS_KEY,
 };
 use whitelist_types::{
-    check_if_minter, CreateWhitelistMsg, Round, RoundConfig, RoundWhitelistQueryMsgs,
+    CreateWhitelistMsg, EffectiveAllowance, MembershipProof, MinterUsage, Round, RoundConfig,
+    RoundWhitelistQueryMsgs, UsageRecord, UserRoundStatus,
 };
+const CONTRACT_NAME: &str = "whitelist-round";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -27,7 +42,7 @@ pub fn instantiate(
     info: MessageInfo,
     msg: CreateWhitelistMsg,
 ) -> Result<Response, ContractError> {
-    set_contract_version(deps.storage, "whitelist-round", "1.0.0")?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     let _factory_params: ParamsResponse = deps.querier.query_wasm_smart(
         info.sender.clone().into_string(),
@@ -43,15 +58,30 @@ pub fn instantiate(
             // Check round integrity
             round.check_integrity(env.block.time)?;
             let round_index = rounds_state.save(deps.storage, &round)?;
-            save_members(deps.storage, deps.api, round_index, &round_config.members)?;
+            save_round_members(
+                deps.storage,
+                deps.api,
+                round_index,
+                &round,
+                &round_config.members,
+            )?;
             Ok(())
         })?;
-    rounds_state.check_round_overlaps(deps.storage, None)?;
+    rounds_state.check_round_overlaps(deps.storage, None, None)?;
+
+    for minter in msg.minters.unwrap_or_default() {
+        let minter = deps.api.addr_validate(&minter)?;
+        MINTERS.save(deps.storage, &minter, &Empty {})?;
+    }
 
     let config = Config {
         admin: admin.clone(),
     };
     CONFIG.save(deps.storage, &config)?;
+    // Admin is tracked as the owner for two-step ownership transfers
+    initialize_owner(deps.storage, deps.api, Some(admin.as_str()))?;
+    let pause_state = PauseState::new()?;
+    pause_state.set_pausers(deps.storage, info.sender.clone(), vec![admin.clone()])?;
 
     Ok(Response::default())
 }
@@ -69,15 +99,46 @@ pub fn execute(
         ExecuteMsg::AddRound {
             round_config: RoundConfig { round, members },
         } => execute_add_round(deps, env, info, round, members),
-        ExecuteMsg::PrivateMint { collector } => execute_private_mint(deps, env, info, collector),
+        ExecuteMsg::PrivateMint { collector, proof } => {
+            execute_private_mint(deps, env, info, collector, proof)
+        }
         ExecuteMsg::AddMembers {
             members,
             round_index,
         } => execute_add_members(deps, env, info, members, round_index),
+        ExecuteMsg::RemoveMembers {
+            members,
+            round_index,
+        } => execute_remove_members(deps, env, info, members, round_index),
         ExecuteMsg::UpdatePrice {
             mint_price,
             round_index,
         } => execute_update_price(deps, env, info, mint_price, round_index),
+        ExecuteMsg::UpdateMerkleRoot {
+            merkle_root,
+            round_index,
+        } => execute_update_merkle_root(deps, env, info, merkle_root, round_index),
+        ExecuteMsg::UpdateRound {
+            round_index,
+            start_time,
+            end_time,
+            round_per_address_limit,
+        } => execute_update_round(
+            deps,
+            env,
+            info,
+            round_index,
+            start_time,
+            end_time,
+            round_per_address_limit,
+        ),
+        ExecuteMsg::AddMinters { minters } => execute_add_minters(deps, env, info, minters),
+        ExecuteMsg::RemoveMinters { minters } => execute_remove_minters(deps, env, info, minters),
+        ExecuteMsg::UpdateOwnership(action) => execute_update_ownership(deps, env, info, action),
+        ExecuteMsg::Pause {} => execute_pause(deps, env, info),
+        ExecuteMsg::Unpause {} => execute_unpause(deps, env, info),
+        ExecuteMsg::SetPausers { pausers } => execute_set_pausers(deps, env, info, pausers),
+        ExecuteMsg::MigrateMembers { limit } => execute_migrate_members(deps, env, info, limit),
     }
 }
 pub fn execute_remove_round(
@@ -92,6 +153,7 @@ pub fn execute_remove_round(
     if info.sender != config.admin {
         return Err(ContractError::Unauthorized {});
     }
+    error_if_migrating(deps.storage)?;
     // Check if the round exists
     let round = rounds.load(deps.storage, round_index)?;
     // Check if the round has started
@@ -126,10 +188,10 @@ pub fn execute_add_round(
 
     let rounds = Rounds::new(ROUNDS_KEY);
     // Check overlaps
-    rounds.check_round_overlaps(deps.storage, Some([round.clone()].to_vec()))?;
+    rounds.check_round_overlaps(deps.storage, Some([round.clone()].to_vec()), None)?;
     // Save the round
     let new_round_index = rounds.save(deps.storage, &round)?;
-    save_members(deps.storage, deps.api, new_round_index, &members)?;
+    save_round_members(deps.storage, deps.api, new_round_index, &round, &members)?;
 
     let res = Response::new()
         .add_attribute("action", "add_round")
@@ -143,34 +205,71 @@ pub fn execute_private_mint(
     env: Env,
     info: MessageInfo,
     collector: String,
+    proof: Option<MembershipProof>,
 ) -> Result<Response, ContractError> {
     // Load config
     let _config = CONFIG.load(deps.storage)?;
+    let pause_state = PauseState::new()?;
+    pause_state.error_if_paused(deps.storage)?;
+    error_if_migrating(deps.storage)?;
 
     let collector = deps.api.addr_validate(&collector)?;
 
-    check_if_minter(&info.sender.clone(), deps.as_ref())?;
-
-    let rounds = Rounds::new(ROUNDS_KEY);
+    // Only registered minters can consume the allocations of the collectors
+    if !MINTERS.has(deps.storage, &info.sender) {
+        return Err(ContractError::MinterNotRegistered {});
+    }
 
-    // Find active round
-    let active_round = rounds.load_active_round(deps.storage, env.block.time);
-    if active_round.is_none() {
-        return Err(ContractError::NoActiveRound {});
-    };
-    let active_round = active_round.unwrap();
+    // Resolve the round the collector mints from among the active rounds
+    // Merkle and voucher rounds prove membership with the proof sent by the minter
+    let collector_round = resolve_collector_round(
+        deps.api,
+        deps.storage,
+        &env,
+        &collector,
+        Some(&info.sender),
+        proof,
+    )?;
+    let round_index = &collector_round.round_index;
+    let remaining_supply = remaining_round_supply(
+        deps.storage,
+        *round_index,
+        &collector_round.round,
+        &info.sender,
+    )?;
+    if remaining_supply == Some(0) {
+        return Err(ContractError::RoundSoldOut {});
+    }
+    if let Some(payload) = &collector_round.voucher {
+        consume_voucher(deps.storage, payload)?;
+    }
 
     UserMintDetails::new(USERMINTDETAILS_KEY).mint_for_user(
         deps.storage,
         collector.clone(),
-        info.sender,
-        active_round.0,
-        &active_round.1,
+        info.sender.clone(),
+        *round_index,
+        &collector_round.round,
+        // Allocation with the rollover from the previous round
+        Some(collector_round.limit()),
+    )?;
+    let minter_mints = MINTER_ROUND_MINTS
+        .may_load(deps.storage, (&info.sender, *round_index))?
+        .unwrap_or(0);
+    MINTER_ROUND_MINTS.save(
+        deps.storage,
+        (&info.sender, *round_index),
+        &(minter_mints + 1),
     )?;
+    let round_mints = ROUND_MINTS
+        .may_load(deps.storage, *round_index)?
+        .unwrap_or(0);
+    ROUND_MINTS.save(deps.storage, *round_index, &(round_mints + 1))?;
 
     let res = Response::new()
         .add_attribute("action", "private_mint")
-        .add_attribute("minter", collector.to_string());
+        .add_attribute("minter", collector.to_string())
+        .add_attribute("round_index", round_index.to_string());
     Ok(res)
 }
 
@@ -178,7 +277,7 @@ pub fn execute_add_members(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    members: Vec<String>,
+    members: Vec<(String, u32)>,
     round_index: u8,
 ) -> Result<Response, ContractError> {
     // Check if sender is admin
@@ -186,18 +285,72 @@ pub fn execute_add_members(
     if info.sender != config.admin {
         return Err(ContractError::Unauthorized {});
     }
+    error_if_migrating(deps.storage)?;
     // Since we are adding members to a round
     // We are not checking if the round has started or ended
     let rounds = Rounds::new(ROUNDS_KEY);
     // Check if the round exists
-    rounds.load(deps.storage, round_index)?;
+    let round = rounds.load(deps.storage, round_index)?;
+    if !round.stores_members() {
+        return Err(ContractError::MembersNotAllowed {});
+    }
     // Add the address to the round
+    let members: Vec<(String, Option<u32>)> = members
+        .into_iter()
+        .map(|(address, allocation)| (address, Some(allocation)))
+        .collect();
     save_members(deps.storage, deps.api, round_index, &members)?;
 
     let res = Response::new()
         .add_attribute("action", "add_members")
         .add_attribute("round_index", round_index.to_string())
-        .add_attribute("addresses", members.join(","));
+        .add_attribute(
+            "addresses",
+            members
+                .iter()
+                .map(|(address, _)| address.clone())
+                .collect::<Vec<String>>()
+                .join(","),
+        );
+    Ok(res)
+}
+
+pub fn execute_remove_members(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    members: Vec<String>,
+    round_index: u8,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    error_if_migrating(deps.storage)?;
+    let rounds = Rounds::new(ROUNDS_KEY);
+    // Check if the round exists
+    let round = rounds.load(deps.storage, round_index)?;
+    // Members can be removed before and during the round
+    if round.has_ended(env.block.time) {
+        return Err(ContractError::RoundEnded {});
+    }
+    if !round.stores_members() {
+        return Err(ContractError::MembersNotAllowed {});
+    }
+    let removed = remove_members(deps.storage, deps.api, round_index, &members)?;
+
+    let res = Response::new()
+        .add_attribute("action", "remove_members")
+        .add_attribute("round_index", round_index.to_string())
+        .add_attribute(
+            "addresses",
+            removed
+                .iter()
+                .map(|address| address.to_string())
+                .collect::<Vec<String>>()
+                .join(","),
+        );
     Ok(res)
 }
 
@@ -233,6 +386,257 @@ pub fn execute_update_price(
     Ok(res)
 }
 
+pub fn execute_update_round(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    round_index: u8,
+    start_time: Option<Timestamp>,
+    end_time: Option<Timestamp>,
+    round_per_address_limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let rounds = Rounds::new(ROUNDS_KEY);
+    // Check if the round exists
+    let mut round = rounds.load(deps.storage, round_index)?;
+    // Check if the round has started
+    if round.has_started(env.block.time) {
+        return Err(ContractError::RoundAlreadyStarted {});
+    }
+    if let Some(start_time) = start_time {
+        round.start_time = start_time;
+    }
+    if let Some(end_time) = end_time {
+        round.end_time = end_time;
+    }
+    if let Some(round_per_address_limit) = round_per_address_limit {
+        round.round_per_address_limit = round_per_address_limit;
+    }
+    round.check_integrity(env.block.time)?;
+    // Check overlaps with the other rounds
+    rounds.check_round_overlaps(
+        deps.storage,
+        Some([round.clone()].to_vec()),
+        Some(round_index),
+    )?;
+    // Save the round
+    rounds.update(deps.storage, round_index, &round)?;
+
+    let res = Response::new()
+        .add_attribute("action", "update_round")
+        .add_attribute("round_index", round_index.to_string())
+        .add_attribute("start_time", round.start_time.to_string())
+        .add_attribute("end_time", round.end_time.to_string())
+        .add_attribute(
+            "round_per_address_limit",
+            round.round_per_address_limit.to_string(),
+        );
+    Ok(res)
+}
+
+pub fn execute_update_merkle_root(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    merkle_root: String,
+    round_index: u8,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    let rounds = Rounds::new(ROUNDS_KEY);
+    // Check if the round exists
+    let mut round = rounds.load(deps.storage, round_index)?;
+    // Check if the round has started
+    if round.has_started(env.block.time) {
+        return Err(ContractError::RoundAlreadyStarted {});
+    }
+    if round.merkle_root.is_none() {
+        return Err(ContractError::NotMerkleRound {});
+    }
+    validate_merkle_root(&merkle_root)?;
+    // Update the root
+    round.merkle_root = Some(merkle_root.clone());
+    // Save the round
+    rounds.update(deps.storage, round_index, &round)?;
+
+    let res = Response::new()
+        .add_attribute("action", "update_merkle_root")
+        .add_attribute("round_index", round_index.to_string())
+        .add_attribute("merkle_root", merkle_root);
+    Ok(res)
+}
+
+pub fn execute_add_minters(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    minters: Vec<String>,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    if minters.is_empty() {
+        return Err(ContractError::EmptyAddressList {});
+    }
+    for minter in &minters {
+        let minter = deps.api.addr_validate(minter)?;
+        MINTERS.save(deps.storage, &minter, &Empty {})?;
+    }
+
+    let res = Response::new()
+        .add_attribute("action", "add_minters")
+        .add_attribute("minters", minters.join(","));
+    Ok(res)
+}
+
+pub fn execute_remove_minters(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    minters: Vec<String>,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    if minters.is_empty() {
+        return Err(ContractError::EmptyAddressList {});
+    }
+    for minter in &minters {
+        let minter = deps.api.addr_validate(minter)?;
+        if !MINTERS.has(deps.storage, &minter) {
+            return Err(ContractError::MinterNotRegistered {});
+        }
+        MINTERS.remove(deps.storage, &minter);
+    }
+
+    let res = Response::new()
+        .add_attribute("action", "remove_minters")
+        .add_attribute("minters", minters.join(","));
+    Ok(res)
+}
+
+pub fn execute_update_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: Action,
+) -> Result<Response, ContractError> {
+    let ownership = update_ownership(deps.branch(), &env.block, &info.sender, action)?;
+    // Keep the admin of config in sync with the owner
+    // If ownership is renounced, admin is set to the contract itself so no one can act as admin
+    let mut config = CONFIG.load(deps.storage)?;
+    config.admin = ownership.owner.clone().unwrap_or(env.contract.address);
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new()
+        .add_attribute("action", "update_ownership")
+        .add_attributes(ownership.into_attributes()))
+}
+
+pub fn execute_pause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pause_state = PauseState::new()?;
+    pause_state.pause(deps.storage, &info.sender)?;
+    Ok(Response::new()
+        .add_attribute("action", "pause")
+        .add_attribute("pauser", info.sender))
+}
+
+pub fn execute_unpause(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pause_state = PauseState::new()?;
+    pause_state.unpause(deps.storage, &info.sender)?;
+    Ok(Response::new()
+        .add_attribute("action", "unpause")
+        .add_attribute("pauser", info.sender))
+}
+
+pub fn execute_set_pausers(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    pausers: Vec<String>,
+) -> Result<Response, ContractError> {
+    // Only the owner can choose who may pause the whitelist
+    assert_owner(deps.storage, &info.sender)?;
+    let validated_pausers = pausers
+        .iter()
+        .map(|pauser| deps.api.addr_validate(pauser))
+        .collect::<StdResult<Vec<Addr>>>()?;
+    let pause_state = PauseState::new()?;
+    pause_state.set_pausers_as(deps.storage, info.sender.clone(), validated_pausers, true)?;
+    Ok(Response::new()
+        .add_attribute("action", "set_pausers")
+        .add_attribute("pausers", pausers.join(",")))
+}
+
+pub fn execute_migrate_members(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    if !has_legacy_members(deps.storage) {
+        return Err(ContractError::MembersAlreadyMigrated {});
+    }
+    let limit = limit
+        .unwrap_or(DEFAULT_MIGRATION_LIMIT)
+        .min(MAX_MIGRATION_LIMIT);
+    let migrated_members = migrate_round_members(deps.storage, limit)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate_members")
+        .add_attribute("migrated_members", migrated_members.to_string())
+        .add_attribute(
+            "migration_complete",
+            (!has_legacy_members(deps.storage)).to_string(),
+        ))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    let config = CONFIG.load(deps.storage)?;
+    // Whitelists created before ownership was tracked have no owner yet
+    if get_ownership(deps.storage).is_err() {
+        initialize_owner(deps.storage, deps.api, Some(config.admin.as_str()))?;
+    }
+    // Whitelists created before pausing was added start unpaused with the admin as pauser
+    let pause_state = PauseState::new()?;
+    if pause_state.pausers.may_load(deps.storage)?.is_none() {
+        pause_state.set_pausers(deps.storage, config.admin.clone(), vec![config.admin])?;
+    }
+    for minter in msg.minters.unwrap_or_default() {
+        let minter = deps.api.addr_validate(&minter)?;
+        MINTERS.save(deps.storage, &minter, &Empty {})?;
+    }
+    // Move the first batch of members to the storage keyed by (round_index, address)
+    // Remaining members are moved with MigrateMembers
+    let migrated_members = migrate_round_members(deps.storage, DEFAULT_MIGRATION_LIMIT)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("migrated_members", migrated_members.to_string())
+        .add_attribute(
+            "migration_complete",
+            (!has_legacy_members(deps.storage)).to_string(),
+        ))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: RoundWhitelistQueryMsgs) -> StdResult<Binary> {
     match msg {
@@ -244,14 +648,41 @@ pub fn query(deps: Deps, env: Env, msg: RoundWhitelistQueryMsgs) -> StdResult<Bi
             limit,
         } => to_json_binary(&query_members(deps, env, round_index, start_after, limit)?),
         RoundWhitelistQueryMsgs::Price {} => to_json_binary(&query_price(deps, env)?),
+        RoundWhitelistQueryMsgs::CollectorPrice {
+            address,
+            minter,
+            proof,
+        } => to_json_binary(&query_collector_price(deps, env, address, minter, proof)?),
         RoundWhitelistQueryMsgs::Rounds {} => to_json_binary(&query_rounds(deps, env)?),
         RoundWhitelistQueryMsgs::Round { round_index } => {
             to_json_binary(&query_round(deps, round_index)?)
         }
-        RoundWhitelistQueryMsgs::IsMember { address } => {
-            to_json_binary(&query_is_member(deps, env, address)?)
+        RoundWhitelistQueryMsgs::IsMember { address, proof } => {
+            to_json_binary(&query_is_member(deps, env, address, proof)?)
         }
+        RoundWhitelistQueryMsgs::VoucherNonceUsage { nonce } => {
+            to_json_binary(&voucher_nonce_usage(deps.storage, &nonce)?)
+        }
+        RoundWhitelistQueryMsgs::Minters {} => to_json_binary(&query_minters(deps, env)?),
+        RoundWhitelistQueryMsgs::UserRoundStatus { address, minter } => {
+            to_json_binary(&query_user_round_status(deps, env, address, minter)?)
+        }
+        RoundWhitelistQueryMsgs::UsageRecords { start_after, limit } => {
+            to_json_binary(&query_usage_records(deps, env, start_after, limit)?)
+        }
+        RoundWhitelistQueryMsgs::RemainingRoundSupply {
+            round_index,
+            minter,
+        } => to_json_binary(&query_remaining_round_supply(deps, round_index, minter)?),
+        RoundWhitelistQueryMsgs::EffectiveAllowance {
+            address,
+            minter,
+            proof,
+        } => to_json_binary(&query_effective_allowance(deps, env, address, minter, proof)?),
         RoundWhitelistQueryMsgs::Admin {} => to_json_binary(&query_admin(deps, env)?),
+        RoundWhitelistQueryMsgs::Ownership {} => to_json_binary(&get_ownership(deps.storage)?),
+        RoundWhitelistQueryMsgs::IsPaused {} => to_json_binary(&query_is_paused(deps, env)?),
+        RoundWhitelistQueryMsgs::Pausers {} => to_json_binary(&query_pausers(deps, env)?),
     }
 }
 
@@ -280,35 +711,22 @@ pub fn query_members(
     limit: Option<u32>,
 ) -> Result<Vec<String>, ContractError> {
     const MAX_LIMIT: u32 = 100;
-    let round_index_str = round_index.to_string();
-    let prefix: String = round_index_str.clone();
-
-    let start = match start_after {
-        Some(start_after) => {
-            let start_after = start_after.as_bytes().to_vec();
-            Bound::exclusive(start_after)
-        }
-        None => Bound::inclusive(prefix.as_bytes().to_vec()),
-    };
+    let start_after = start_after
+        .map(|start_after| deps.api.addr_validate(&start_after))
+        .transpose()?;
+    let start = start_after.as_ref().map(Bound::exclusive);
     let limit: u32 = match limit {
         Some(limit) => limit.min(MAX_LIMIT),
         None => MAX_LIMIT,
     };
 
-    let members: Vec<(Vec<u8>, bool)> = ROUNDMEMBERS
-        .prefix(prefix.as_bytes().to_vec())
-        .range(deps.storage, Some(start), None, Order::Ascending)
+    // Members are paginated within the prefix of the round
+    let members: Vec<String> = ROUNDMEMBERS
+        .prefix(round_index)
+        .keys(deps.storage, start, None, Order::Ascending)
         .take(limit as usize)
-        .map(|item| item.unwrap())
-        .collect();
-
-    let members: Vec<String> = members
-        .iter()
-        .map(|member| {
-            let member = str::from_utf8(&member.0).unwrap();
-            member.to_string()
-        })
-        .collect();
+        .map(|member| member.map(|member| member.to_string()))
+        .collect::<StdResult<Vec<String>>>()?;
     Ok(members)
 }
 
@@ -335,18 +753,208 @@ pub fn query_round(deps: Deps, round_index: u8) -> Result<Round, ContractError>
     Ok(round)
 }
 
-pub fn query_is_member(deps: Deps, env: Env, address: String) -> Result<bool, ContractError> {
-    let rounds = Rounds::new(ROUNDS_KEY);
-    let active_round = rounds.load_active_round(deps.storage, env.block.time);
-    let active_round = match active_round {
-        Some(active_round) => active_round,
-        None => return Err(ContractError::NoActiveRound {}),
+pub fn query_is_member(
+    deps: Deps,
+    env: Env,
+    address: String,
+    proof: Option<MembershipProof>,
+) -> Result<bool, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    // Address is a member if it belongs to any of the active rounds
+    match resolve_collector_round(deps.api, deps.storage, &env, &address, None, proof) {
+        Ok(_) => Ok(true),
+        Err(ContractError::NoActiveRound {}) => Err(ContractError::NoActiveRound {}),
+        Err(_) => Ok(false),
+    }
+}
+
+pub fn query_collector_price(
+    deps: Deps,
+    env: Env,
+    address: String,
+    minter: Option<String>,
+    proof: Option<MembershipProof>,
+) -> Result<Coin, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    let minter = minter
+        .map(|minter| deps.api.addr_validate(&minter))
+        .transpose()?;
+    let collector_round = resolve_collector_round(
+        deps.api,
+        deps.storage,
+        &env,
+        &address,
+        minter.as_ref(),
+        proof,
+    )?;
+    Ok(collector_round.round.mint_price())
+}
+
+pub fn query_minters(deps: Deps, _env: Env) -> Result<Vec<MinterUsage>, ContractError> {
+    let minters = MINTERS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    let minters = minters
+        .into_iter()
+        .map(|minter| -> StdResult<MinterUsage> {
+            let round_mints = MINTER_ROUND_MINTS
+                .prefix(&minter)
+                .range(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<(u8, u32)>>>()?;
+            Ok(MinterUsage {
+                minter,
+                round_mints,
+            })
+        })
+        .collect::<StdResult<Vec<MinterUsage>>>()?;
+    Ok(minters)
+}
+
+pub fn query_user_round_status(
+    deps: Deps,
+    env: Env,
+    address: String,
+    minter: String,
+) -> Result<Vec<UserRoundStatus>, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    let minter = deps.api.addr_validate(&minter)?;
+    let rounds = Rounds::new(ROUNDS_KEY).load_all_rounds(deps.storage)?;
+    let user_mint_details = UserMintDetails::new(USERMINTDETAILS_KEY);
+    rounds
+        .into_iter()
+        .map(|(round_index, round)| -> Result<UserRoundStatus, ContractError> {
+            let minted = user_mint_details.mint_count(
+                deps.storage,
+                address.clone(),
+                minter.clone(),
+                round_index,
+            )?;
+            // Membership of merkle and voucher rounds can not be checked without the proof
+            if !round.stores_members() {
+                return Ok(UserRoundStatus {
+                    round_index,
+                    is_member: None,
+                    minted,
+                    remaining: None,
+                });
+            }
+            let is_member = check_member(deps.storage, deps.api, round_index, address.as_str())?;
+            let remaining = if is_member {
+                // Unused allocation of the ended rounds before it is added as on private mint
+                let rollover = next_round_rollover(
+                    deps.storage,
+                    env.block.time,
+                    round_index,
+                    &address,
+                    &minter,
+                )?;
+                let remaining = member_allocation(deps.storage, round_index, &address)?
+                    .unwrap_or(round.round_per_address_limit)
+                    .saturating_add(rollover)
+                    .saturating_sub(minted);
+                // Collector can not mint more than what is left of the round supply
+                remaining_round_supply(deps.storage, round_index, &round, &minter)?
+                    .map_or(remaining, |remaining_supply| remaining.min(remaining_supply))
+            } else {
+                0
+            };
+            Ok(UserRoundStatus {
+                round_index,
+                is_member: Some(is_member),
+                minted,
+                remaining: Some(remaining),
+            })
+        })
+        .collect()
+}
+
+pub fn query_usage_records(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<(String, String, u8)>,
+    limit: Option<u32>,
+) -> Result<Vec<UsageRecord>, ContractError> {
+    const MAX_LIMIT: u32 = 100;
+    let limit = limit.unwrap_or(MAX_LIMIT).min(MAX_LIMIT);
+    let start_after = match start_after {
+        Some((address, minter, round_index)) => Some((
+            deps.api.addr_validate(&address)?,
+            deps.api.addr_validate(&minter)?,
+            round_index,
+        )),
+        None => None,
+    };
+    let user_mint_details = UserMintDetails::new(USERMINTDETAILS_KEY);
+    let records = user_mint_details.load_records(deps.storage, start_after, limit as usize)?;
+    Ok(records)
+}
+
+pub fn query_remaining_round_supply(
+    deps: Deps,
+    round_index: u8,
+    minter: String,
+) -> Result<Option<u32>, ContractError> {
+    let minter = deps.api.addr_validate(&minter)?;
+    let round = Rounds::new(ROUNDS_KEY).load(deps.storage, round_index)?;
+    let remaining = remaining_round_supply(deps.storage, round_index, &round, &minter)?;
+    Ok(remaining)
+}
+
+pub fn query_effective_allowance(
+    deps: Deps,
+    env: Env,
+    address: String,
+    minter: String,
+    proof: Option<MembershipProof>,
+) -> Result<EffectiveAllowance, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    let minter = deps.api.addr_validate(&minter)?;
+    let public_rollover = public_rollover(deps.storage, env.block.time, &address, &minter)?;
+    let collector_round = match resolve_collector_round(
+        deps.api,
+        deps.storage,
+        &env,
+        &address,
+        Some(&minter),
+        proof,
+    ) {
+        Ok(collector_round) => collector_round,
+        // Address can not mint from any round, only the public rollover is left
+        Err(ContractError::NoActiveRound {}) | Err(ContractError::AddressNotMember {}) => {
+            return Ok(EffectiveAllowance {
+                round_index: None,
+                remaining: 0,
+                public_rollover,
+            })
+        }
+        Err(err) => return Err(err),
     };
-    let is_member = check_member(deps.storage, deps.api, active_round.0, &address)?;
-    Ok(is_member)
+    let minted = UserMintDetails::new(USERMINTDETAILS_KEY).mint_count(
+        deps.storage,
+        address,
+        minter,
+        collector_round.round_index,
+    )?;
+    Ok(EffectiveAllowance {
+        round_index: Some(collector_round.round_index),
+        remaining: collector_round.limit().saturating_sub(minted),
+        public_rollover,
+    })
 }
 
 pub fn query_admin(deps: Deps, _env: Env) -> Result<String, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     Ok(config.admin.to_string())
 }
+
+pub fn query_is_paused(deps: Deps, _env: Env) -> Result<bool, ContractError> {
+    let pause_state = PauseState::new()?;
+    let is_paused = pause_state.is_paused(deps.storage)?;
+    Ok(is_paused)
+}
+
+pub fn query_pausers(deps: Deps, _env: Env) -> Result<Vec<Addr>, ContractError> {
+    let pause_state = PauseState::new()?;
+    let pausers = pause_state.pausers.load(deps.storage).unwrap_or(vec![]);
+    Ok(pausers)
+}
@@ -1,8 +1,12 @@
 use std::convert::Infallible;
 
 use cosmwasm_std::{ConversionOverflowError, StdError};
+use cw_ownable::OwnershipError;
 use cw_utils::PaymentError;
+use pauser::migration::MigrationError;
+use pauser::PauseError;
 use thiserror::Error;
+use whitelist_types::round::RoundError;
 use whitelist_types::Round;
 
 #[derive(Error, Debug, PartialEq)]
@@ -10,6 +14,21 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error(transparent)]
+    Pause(#[from] PauseError),
+
+    #[error(transparent)]
+    Ownership(#[from] OwnershipError),
+
+    #[error(transparent)]
+    Migration(#[from] MigrationError),
+
+    #[error("Members of the whitelist are still being migrated")]
+    MembersNotMigrated {},
+
+    #[error("Members of the whitelist are already migrated")]
+    MembersAlreadyMigrated {},
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -22,6 +41,9 @@ pub enum ContractError {
     #[error("Invalid per address limit")]
     InvalidPerAddressLimit {},
 
+    #[error("Invalid member allocation")]
+    InvalidMemberAllocation {},
+
     #[error("{0}")]
     PaymentError(#[from] PaymentError),
 
@@ -63,6 +85,51 @@ pub enum ContractError {
 
     #[error("Whitelist member limit exceeded")]
     WhitelistMemberLimitExceeded {},
+
+    #[error("Invalid merkle root")]
+    InvalidMerkleRoot {},
+
+    #[error("Invalid merkle proof")]
+    InvalidMerkleProof {},
+
+    #[error("Membership proof is required for this round")]
+    MembershipProofRequired {},
+
+    #[error("Address is not a member of the round")]
+    AddressNotMember {},
+
+    #[error("Members can not be added to a round with a merkle root or a voucher signer")]
+    MembersNotAllowed {},
+
+    #[error("Round is not a merkle round")]
+    NotMerkleRound {},
+
+    #[error("Minter is not registered")]
+    MinterNotRegistered {},
+
+    #[error("Invalid voucher signer")]
+    InvalidVoucherSigner {},
+
+    #[error("Invalid voucher signature")]
+    InvalidVoucherSignature {},
+
+    #[error("Voucher has expired")]
+    VoucherExpired {},
+
+    #[error("Voucher nonce has already been used")]
+    VoucherNonceUsed {},
+
+    #[error("Round can not have both a merkle root and a voucher signer")]
+    ConflictingMembershipProofs {},
+
+    #[error("Only rounds that store their members can roll over")]
+    RolloverNotAllowed {},
+
+    #[error("Invalid round supply")]
+    InvalidRoundSupply {},
+
+    #[error("Round is sold out")]
+    RoundSoldOut {},
 }
 impl From<ContractError> for StdError {
     fn from(err: ContractError) -> StdError {
@@ -80,3 +147,27 @@ impl From<ConversionOverflowError> for ContractError {
         ContractError::OverflowError {}
     }
 }
+
+// Round checks are shared with the factory, they keep the errors of the whitelist
+impl From<RoundError> for ContractError {
+    fn from(err: RoundError) -> Self {
+        match err {
+            RoundError::InvalidEndTime {} => ContractError::InvalidEndTime {},
+            RoundError::RoundAlreadyStarted {} => ContractError::RoundAlreadyStarted {},
+            RoundError::InvalidPerAddressLimit {} => ContractError::InvalidPerAddressLimit {},
+            RoundError::InvalidRoundSupply {} => ContractError::InvalidRoundSupply {},
+            RoundError::InvalidMerkleRoot {} => ContractError::InvalidMerkleRoot {},
+            RoundError::InvalidVoucherSigner {} => ContractError::InvalidVoucherSigner {},
+            RoundError::ConflictingMembershipProofs {} => {
+                ContractError::ConflictingMembershipProofs {}
+            }
+            RoundError::RolloverNotAllowed {} => ContractError::RolloverNotAllowed {},
+            RoundError::RoundsOverlapped {} => ContractError::RoundsOverlapped {},
+            RoundError::MembersNotAllowed {} => ContractError::MembersNotAllowed {},
+            RoundError::EmptyAddressList {} => ContractError::EmptyAddressList {},
+            RoundError::WhitelistMemberLimitExceeded {} => {
+                ContractError::WhitelistMemberLimitExceeded {}
+            }
+        }
+    }
+}
@@ -1,5 +1,10 @@
 pub mod contract;
 pub mod error;
+pub mod merkle;
+pub mod migration;
 pub mod msg;
+pub mod resolver;
+pub mod rollover;
 pub mod round;
 pub mod state;
+pub mod voucher;
@@ -0,0 +1,127 @@
+use cosmwasm_std::Addr;
+use sha2::{Digest, Sha256};
+use whitelist_types::round::check_merkle_root;
+use whitelist_types::MembershipProof;
+
+use crate::error::ContractError;
+
+// A tree of depth 32 can hold more members than any round will ever have
+const MAX_PROOF_LENGTH: usize = 32;
+
+/// Leaf of a member is sha256(address) or sha256("{address}:{allocation}")
+/// if the member has its own allocation.
+pub fn leaf_hash(address: &Addr, allocation: Option<u32>) -> [u8; 32] {
+    let leaf = match allocation {
+        Some(allocation) => format!("{}:{}", address, allocation),
+        None => address.to_string(),
+    };
+    Sha256::digest(leaf.as_bytes()).into()
+}
+
+fn decode_hash(hash: &str) -> Option<[u8; 32]> {
+    let mut decoded = [0u8; 32];
+    hex::decode_to_slice(hash, &mut decoded).ok()?;
+    Some(decoded)
+}
+
+pub fn validate_merkle_root(merkle_root: &str) -> Result<(), ContractError> {
+    check_merkle_root(merkle_root)?;
+    Ok(())
+}
+
+/// Verifies a proof of a tree built with sorted pair hashing
+pub fn verify_merkle_proof(
+    merkle_root: &str,
+    address: &Addr,
+    allocation: Option<u32>,
+    proof: &[String],
+) -> Result<bool, ContractError> {
+    let root = decode_hash(merkle_root).ok_or(ContractError::InvalidMerkleRoot {})?;
+    if proof.len() > MAX_PROOF_LENGTH {
+        return Err(ContractError::InvalidMerkleProof {});
+    }
+    let hash = proof
+        .iter()
+        .try_fold(leaf_hash(address, allocation), |hash, sibling| {
+            let sibling = decode_hash(sibling).ok_or(ContractError::InvalidMerkleProof {})?;
+            let mut pair = [hash, sibling];
+            pair.sort_unstable();
+            Ok::<[u8; 32], ContractError>(Sha256::digest(pair.concat()).into())
+        })?;
+    Ok(hash == root)
+}
+
+/// Checks the membership proof against the merkle root of the round
+/// and returns the allocation committed in the leaf
+pub fn check_merkle_membership(
+    merkle_root: &str,
+    address: &Addr,
+    proof: Option<MembershipProof>,
+) -> Result<Option<u32>, ContractError> {
+    match proof {
+        Some(MembershipProof::Merkle { proof, allocation }) => {
+            if !verify_merkle_proof(merkle_root, address, allocation, &proof)? {
+                return Err(ContractError::AddressNotMember {});
+            }
+            Ok(allocation)
+        }
+        _ => Err(ContractError::MembershipProofRequired {}),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let mut pair = [a, b];
+        pair.sort_unstable();
+        Sha256::digest(pair.concat()).into()
+    }
+
+    #[test]
+    fn test_verify_merkle_proof() {
+        let member1 = Addr::unchecked("member1");
+        let member2 = Addr::unchecked("member2");
+        let member3 = Addr::unchecked("member3");
+        let leaf1 = leaf_hash(&member1, None);
+        let leaf2 = leaf_hash(&member2, Some(5));
+        let leaf3 = leaf_hash(&member3, None);
+        let node = hash_pair(leaf1, leaf2);
+        let root = hex::encode(hash_pair(node, leaf3));
+
+        let proof = vec![hex::encode(leaf2), hex::encode(leaf3)];
+        assert!(verify_merkle_proof(&root, &member1, None, &proof).unwrap());
+
+        let proof = vec![hex::encode(leaf1), hex::encode(leaf3)];
+        assert!(verify_merkle_proof(&root, &member2, Some(5), &proof).unwrap());
+        // Allocation is part of the leaf
+        assert!(!verify_merkle_proof(&root, &member2, Some(6), &proof).unwrap());
+        assert!(!verify_merkle_proof(&root, &member2, None, &proof).unwrap());
+
+        let proof = vec![hex::encode(node)];
+        assert!(verify_merkle_proof(&root, &member3, None, &proof).unwrap());
+        assert_eq!(
+            check_merkle_membership(
+                &root,
+                &member1,
+                Some(MembershipProof::Merkle {
+                    proof: proof.clone(),
+                    allocation: None,
+                })
+            ),
+            Err(ContractError::AddressNotMember {})
+        );
+        assert_eq!(
+            check_merkle_membership(&root, &member3, None),
+            Err(ContractError::MembershipProofRequired {})
+        );
+
+        // Invalid hashes
+        assert_eq!(
+            verify_merkle_proof(&root, &member3, None, &["node".to_string()]),
+            Err(ContractError::InvalidMerkleProof {})
+        );
+        assert_eq!(validate_merkle_root("root"), Err(ContractError::InvalidMerkleRoot {}));
+    }
+}
@@ -0,0 +1,202 @@
+use cosmwasm_std::{Addr, Order, StdError, StdResult, Storage};
+use cw_storage_plus::Map;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::state::ROUNDMEMBERS;
+
+/// Membership value of the legacy storage
+/// Older versions stored `true`, later versions stored the allocation of the member
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum LegacyMembership {
+    Member(bool),
+    Allocation(Option<u32>),
+}
+
+// Members keyed by the round index and the address as string bytes
+pub const LEGACY_ROUNDMEMBERS: Map<(Vec<u8>, Vec<u8>), LegacyMembership> =
+    Map::new("round_members");
+// Number of members moved by the migration and by each `MigrateMembers` call
+pub const DEFAULT_MIGRATION_LIMIT: u32 = 500;
+pub const MAX_MIGRATION_LIMIT: u32 = 1000;
+
+/// Returns true while members of the legacy storage are left to migrate
+pub fn has_legacy_members(storage: &dyn Storage) -> bool {
+    LEGACY_ROUNDMEMBERS
+        .keys_raw(storage, None, None, Order::Ascending)
+        .next()
+        .is_some()
+}
+
+/// Errors while members of the legacy storage are left to migrate
+/// Membership can not be checked or changed until every member is moved
+pub fn error_if_migrating(storage: &dyn Storage) -> Result<(), ContractError> {
+    if has_legacy_members(storage) {
+        return Err(ContractError::MembersNotMigrated {});
+    }
+    Ok(())
+}
+
+/// Moves up to `limit` members of the legacy storage to the storage keyed by (round_index, address)
+/// Returns the number of migrated members
+pub fn migrate_round_members(storage: &mut dyn Storage, limit: u32) -> Result<u32, ContractError> {
+    let legacy_members = LEGACY_ROUNDMEMBERS
+        .range(storage, None, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut migrated_members = 0;
+    for ((round_index, address), membership) in legacy_members {
+        LEGACY_ROUNDMEMBERS.remove(storage, (round_index.clone(), address.clone()));
+        let allocation = match membership {
+            LegacyMembership::Member(true) => None,
+            LegacyMembership::Member(false) => continue,
+            LegacyMembership::Allocation(allocation) => allocation,
+        };
+        let round_index = String::from_utf8(round_index)
+            .map_err(StdError::from)?
+            .parse::<u8>()
+            .map_err(|_| StdError::generic_err("Invalid round index"))?;
+        let address = Addr::unchecked(String::from_utf8(address).map_err(StdError::from)?);
+        ROUNDMEMBERS.save(storage, (round_index, &address), &allocation)?;
+        migrated_members += 1;
+    }
+    Ok(migrated_members)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::migrate;
+    use crate::msg::MigrateMsg;
+    use crate::state::{
+        check_member, member_allocation, Config, Rounds, CONFIG, MINTERS, ROUNDS_KEY,
+    };
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{coin, Coin, Timestamp};
+    use cw_ownable::get_ownership;
+    use pauser::migration::MigrationError;
+    use pauser::PauseState;
+
+    // Round as stored before 1.1.0
+    #[cw_serde]
+    struct LegacyRound {
+        start_time: Timestamp,
+        end_time: Timestamp,
+        mint_price: Coin,
+        round_per_address_limit: u8,
+    }
+
+    #[test]
+    fn test_migrate_round_members() {
+        let mut deps = mock_dependencies();
+        let legacy_members = [
+            ("1", "member1", LegacyMembership::Member(true)),
+            ("1", "member2", LegacyMembership::Allocation(Some(3))),
+            ("10", "member3", LegacyMembership::Allocation(None)),
+            ("11", "member4", LegacyMembership::Member(true)),
+        ];
+        for (round_index, address, membership) in legacy_members {
+            let key = (
+                round_index.as_bytes().to_vec(),
+                address.as_bytes().to_vec(),
+            );
+            LEGACY_ROUNDMEMBERS.save(&mut deps.storage, key, &membership).unwrap();
+        }
+
+        // Members are moved in batches
+        let migrated_members = migrate_round_members(&mut deps.storage, 3).unwrap();
+        assert_eq!(migrated_members, 3);
+        assert!(has_legacy_members(&deps.storage));
+        assert_eq!(
+            error_if_migrating(&deps.storage),
+            Err(ContractError::MembersNotMigrated {})
+        );
+        let migrated_members = migrate_round_members(&mut deps.storage, 3).unwrap();
+        assert_eq!(migrated_members, 1);
+        assert!(!has_legacy_members(&deps.storage));
+        assert_eq!(error_if_migrating(&deps.storage), Ok(()));
+
+        assert!(check_member(&deps.storage, &deps.api, 1, "member1").unwrap());
+        assert!(!check_member(&deps.storage, &deps.api, 1, "member3").unwrap());
+        assert!(check_member(&deps.storage, &deps.api, 10, "member3").unwrap());
+        assert!(check_member(&deps.storage, &deps.api, 11, "member4").unwrap());
+        let allocation = member_allocation(&deps.storage, 1, &Addr::unchecked("member2")).unwrap();
+        assert_eq!(allocation, Some(3));
+        let allocation = member_allocation(&deps.storage, 1, &Addr::unchecked("member1")).unwrap();
+        assert_eq!(allocation, None);
+
+        // Running the migration again is a no-op
+        let migrated_members = migrate_round_members(&mut deps.storage, 3).unwrap();
+        assert_eq!(migrated_members, 0);
+    }
+
+    #[test]
+    fn test_migrate_baseline_whitelist() {
+        let mut deps = mock_dependencies();
+        let admin = Addr::unchecked("admin");
+        // Whitelists created before 1.1.0 stored only the config, the rounds and the members
+        CONFIG
+            .save(
+                &mut deps.storage,
+                &Config {
+                    admin: admin.clone(),
+                },
+            )
+            .unwrap();
+        let legacy_rounds: Map<u8, LegacyRound> = Map::new(ROUNDS_KEY);
+        legacy_rounds
+            .save(
+                &mut deps.storage,
+                1,
+                &LegacyRound {
+                    start_time: Timestamp::from_seconds(100),
+                    end_time: Timestamp::from_seconds(200),
+                    mint_price: coin(1000000, "uflix"),
+                    round_per_address_limit: 2,
+                },
+            )
+            .unwrap();
+        for member in ["member1", "member2"] {
+            let key = (b"1".to_vec(), member.as_bytes().to_vec());
+            LEGACY_ROUNDMEMBERS
+                .save(&mut deps.storage, key, &LegacyMembership::Member(true))
+                .unwrap();
+        }
+
+        let msg = MigrateMsg {
+            minters: Some(vec!["minter".to_string()]),
+        };
+        let res = migrate(deps.as_mut(), mock_env(), msg).unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "migration_complete" && attr.value == "true"));
+
+        let ownership = get_ownership(&deps.storage).unwrap();
+        assert_eq!(ownership.owner, Some(admin.clone()));
+        let pause_state = PauseState::new().unwrap();
+        assert!(!pause_state.is_paused(&deps.storage).unwrap());
+        assert_eq!(pause_state.pausers.load(&deps.storage).unwrap(), vec![admin]);
+        assert!(MINTERS.has(&deps.storage, &Addr::unchecked("minter")));
+        assert!(check_member(&deps.storage, &deps.api, 1, "member2").unwrap());
+
+        let round = Rounds::new(ROUNDS_KEY).load(&deps.storage, 1).unwrap();
+        assert_eq!(round.round_per_address_limit, 2);
+        assert_eq!(round.priority, 0);
+        assert!(!round.free_claim);
+        assert_eq!(round.merkle_root, None);
+
+        // Whitelists can not be migrated back to an older version
+        cw2::set_contract_version(&mut deps.storage, "whitelist-round", "2.0.0").unwrap();
+        let res = migrate(deps.as_mut(), mock_env(), MigrateMsg { minters: None });
+        assert_eq!(
+            res.unwrap_err(),
+            ContractError::Migration(MigrationError::Downgrade {
+                stored: "2.0.0".to_string(),
+                new: "1.1.0".to_string(),
+            })
+        );
+    }
+}
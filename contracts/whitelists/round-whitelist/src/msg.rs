@@ -1,8 +1,10 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Coin;
+use cosmwasm_std::{Coin, Timestamp};
+use cw_ownable::cw_ownable_execute;
 
-use whitelist_types::RoundConfig;
+use whitelist_types::{MembershipProof, RoundConfig};
 
+#[cw_ownable_execute]
 #[cw_serde]
 pub enum ExecuteMsg {
     RemoveRound {
@@ -13,8 +15,15 @@ pub enum ExecuteMsg {
     },
     PrivateMint {
         collector: String,
+        // Required by merkle and voucher rounds
+        proof: Option<MembershipProof>,
     },
     AddMembers {
+        // Address and allocation pairs, allocation overrides the round per address limit
+        members: Vec<(String, u32)>,
+        round_index: u8,
+    },
+    RemoveMembers {
         members: Vec<String>,
         round_index: u8,
     },
@@ -22,4 +31,39 @@ pub enum ExecuteMsg {
         mint_price: Coin,
         round_index: u8,
     },
+    UpdateMerkleRoot {
+        merkle_root: String,
+        round_index: u8,
+    },
+    // Only allowed before the round starts
+    UpdateRound {
+        round_index: u8,
+        start_time: Option<Timestamp>,
+        end_time: Option<Timestamp>,
+        round_per_address_limit: Option<u32>,
+    },
+    AddMinters {
+        minters: Vec<String>,
+    },
+    RemoveMinters {
+        minters: Vec<String>,
+    },
+    // Pausing blocks PrivateMint for every linked minter
+    Pause {},
+    Unpause {},
+    SetPausers {
+        pausers: Vec<String>,
+    },
+    // Moves members left in the storage of whitelists created before 1.1.0
+    // Anyone can call it until every member is moved
+    MigrateMembers {
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {
+    // Minters allowed to call PrivateMint
+    // Whitelists created before minters were registered accepted every minter contract
+    pub minters: Option<Vec<String>>,
 }
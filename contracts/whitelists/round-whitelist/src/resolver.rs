@@ -0,0 +1,130 @@
+use cosmwasm_std::{Addr, Api, Env, Storage};
+use whitelist_types::{MembershipProof, Round, VoucherPayload};
+
+use crate::error::ContractError;
+use crate::merkle::check_merkle_membership;
+use crate::rollover::next_round_rollover;
+use crate::state::{
+    check_member, member_allocation, remaining_round_supply, Rounds, UserMintDetails, ROUNDS_KEY,
+    USERMINTDETAILS_KEY,
+};
+use crate::voucher::check_voucher_membership;
+
+/// Round a collector mints from when rounds overlap
+#[derive(Debug, PartialEq)]
+pub struct CollectorRound {
+    pub round_index: u8,
+    pub round: Round,
+    // Allocation of the collector, overrides the round per address limit
+    pub allocation: Option<u32>,
+    // Voucher used to prove the membership, consumed on mint
+    pub voucher: Option<VoucherPayload>,
+    // Unused allocation rolled over from the previous round
+    pub rollover: u32,
+}
+
+impl CollectorRound {
+    pub fn limit(&self) -> u32 {
+        self.allocation
+            .unwrap_or(self.round.round_per_address_limit)
+            .saturating_add(self.rollover)
+    }
+}
+
+/// Checks the membership of the address in the round
+/// Returns the allocation of the member and the voucher used as proof
+pub fn check_round_membership(
+    api: &dyn Api,
+    storage: &dyn Storage,
+    env: &Env,
+    round_index: u8,
+    round: &Round,
+    address: &Addr,
+    proof: Option<MembershipProof>,
+) -> Result<(Option<u32>, Option<VoucherPayload>), ContractError> {
+    match (&round.merkle_root, &round.voucher_signer) {
+        (Some(merkle_root), _) => {
+            let allocation = check_merkle_membership(merkle_root, address, proof)?;
+            Ok((allocation, None))
+        }
+        (None, Some(voucher_signer)) => {
+            let payload = check_voucher_membership(
+                api,
+                storage,
+                env,
+                round_index,
+                voucher_signer,
+                address,
+                proof,
+            )?;
+            Ok((Some(payload.max_quantity), Some(payload)))
+        }
+        (None, None) => {
+            if !check_member(storage, api, round_index, address.as_str())? {
+                return Err(ContractError::AddressNotMember {});
+            }
+            let allocation = member_allocation(storage, round_index, address)?;
+            Ok((allocation, None))
+        }
+    }
+}
+
+/// Resolves the active round the address mints from
+/// Rounds are checked by priority and the first round the address is a member of is used
+/// If a minter is given, rounds where the address used its allocation with the minter are skipped
+/// unless the address has used its allocation in every round
+/// Sold out rounds are skipped the same way
+/// Rollover is tracked per minter, so it is only added when a minter is given
+pub fn resolve_collector_round(
+    api: &dyn Api,
+    storage: &dyn Storage,
+    env: &Env,
+    address: &Addr,
+    minter: Option<&Addr>,
+    proof: Option<MembershipProof>,
+) -> Result<CollectorRound, ContractError> {
+    let rounds = Rounds::new(ROUNDS_KEY);
+    let active_rounds = rounds.load_active_rounds(storage, env.block.time);
+    if active_rounds.is_empty() {
+        return Err(ContractError::NoActiveRound {});
+    }
+    let mint_details = UserMintDetails::new(USERMINTDETAILS_KEY);
+    let mut exhausted_round: Option<CollectorRound> = None;
+    for (round_index, round) in active_rounds {
+        let membership = check_round_membership(
+            api,
+            storage,
+            env,
+            round_index,
+            &round,
+            address,
+            proof.clone(),
+        );
+        let (allocation, voucher) = match membership {
+            Ok(membership) => membership,
+            Err(_) => continue,
+        };
+        let mut collector_round = CollectorRound {
+            round_index,
+            round,
+            allocation,
+            voucher,
+            rollover: 0,
+        };
+        if let Some(minter) = minter {
+            collector_round.rollover =
+                next_round_rollover(storage, env.block.time, round_index, address, minter)?;
+            let remaining_supply =
+                remaining_round_supply(storage, round_index, &collector_round.round, minter)?;
+            let minter = minter.clone();
+            let minted = mint_details.mint_count(storage, address.clone(), minter, round_index)?;
+            if minted >= collector_round.limit() || remaining_supply == Some(0) {
+                exhausted_round.get_or_insert(collector_round);
+                continue;
+            }
+        }
+        return Ok(collector_round);
+    }
+    // Minting from an exhausted round fails with the round limit or sold out error
+    exhausted_round.ok_or(ContractError::AddressNotMember {})
+}
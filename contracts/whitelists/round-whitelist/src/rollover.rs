@@ -0,0 +1,173 @@
+use cosmwasm_std::{Addr, Storage, Timestamp};
+use whitelist_types::{RolloverPolicy, Round};
+
+use crate::error::ContractError;
+use crate::round::RoundMethods;
+use crate::state::{
+    member_allocation, Rounds, UserMintDetails, ROUNDMEMBERS, ROUNDS_KEY, USERMINTDETAILS_KEY,
+};
+
+// Returns the index of the first round that starts after the round ends
+fn next_round_index(rounds: &[(u8, Round)], round_index: u8, round: &Round) -> Option<u8> {
+    rounds
+        .iter()
+        .filter(|(index, next_round)| {
+            *index != round_index && next_round.start_time >= round.end_time
+        })
+        .min_by_key(|(index, next_round)| (next_round.start_time, *index))
+        .map(|(index, _)| *index)
+}
+
+// Returns the allocation the member did not use with the minter in the round
+fn unused_allocation(
+    store: &dyn Storage,
+    round_index: u8,
+    round: &Round,
+    address: &Addr,
+    minter: &Addr,
+) -> Result<u32, ContractError> {
+    if !ROUNDMEMBERS.has(store, (round_index, address)) {
+        return Ok(0);
+    }
+    let limit = member_allocation(store, round_index, address)?
+        .unwrap_or(round.round_per_address_limit);
+    let minted = UserMintDetails::new(USERMINTDETAILS_KEY).mint_count(
+        store,
+        address.clone(),
+        minter.clone(),
+        round_index,
+    )?;
+    Ok(limit.saturating_sub(minted))
+}
+
+/// Returns the unused allocation rolled into the round from the ended rounds before it
+pub fn next_round_rollover(
+    store: &dyn Storage,
+    now: Timestamp,
+    round_index: u8,
+    address: &Addr,
+    minter: &Addr,
+) -> Result<u32, ContractError> {
+    let rounds = Rounds::new(ROUNDS_KEY).load_all_rounds(store)?;
+    let mut rollover: u32 = 0;
+    for (index, round) in &rounds {
+        if round.rollover != Some(RolloverPolicy::NextRound {}) || !round.has_ended(now) {
+            continue;
+        }
+        if next_round_index(&rounds, *index, round) != Some(round_index) {
+            continue;
+        }
+        let unused = unused_allocation(store, *index, round, address, minter)?;
+        rollover = rollover.saturating_add(unused);
+    }
+    Ok(rollover)
+}
+
+/// Returns the unused allocation of the ended rounds added to the public per address limit
+pub fn public_rollover(
+    store: &dyn Storage,
+    now: Timestamp,
+    address: &Addr,
+    minter: &Addr,
+) -> Result<u32, ContractError> {
+    let rounds = Rounds::new(ROUNDS_KEY).load_all_rounds(store)?;
+    let mut rollover: u32 = 0;
+    for (index, round) in &rounds {
+        if round.rollover != Some(RolloverPolicy::Public {}) || !round.has_ended(now) {
+            continue;
+        }
+        let unused = unused_allocation(store, *index, round, address, minter)?;
+        rollover = rollover.saturating_add(unused);
+    }
+    Ok(rollover)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::coin;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    fn round(start: u64, end: u64, rollover: Option<RolloverPolicy>) -> Round {
+        Round {
+            start_time: Timestamp::from_nanos(start),
+            end_time: Timestamp::from_nanos(end),
+            mint_price: coin(1000000, "uflix"),
+            round_per_address_limit: 3,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover,
+            round_supply: None,
+        }
+    }
+
+    #[test]
+    fn test_rollover() {
+        let mut deps = mock_dependencies();
+        let rounds = Rounds::new(ROUNDS_KEY);
+        let collector = Addr::unchecked("collector");
+        let minter = Addr::unchecked("minter");
+        let next_round = Some(RolloverPolicy::NextRound {});
+        let public = Some(RolloverPolicy::Public {});
+
+        // Round 3 starts first after round 1, round 2 follows round 3
+        rounds
+            .save(&mut deps.storage, &round(1000, 2000, next_round))
+            .unwrap();
+        rounds
+            .save(&mut deps.storage, &round(5000, 6000, public))
+            .unwrap();
+        rounds
+            .save(&mut deps.storage, &round(3000, 4000, None))
+            .unwrap();
+        ROUNDMEMBERS
+            .save(&mut deps.storage, (1, &collector), &None)
+            .unwrap();
+        ROUNDMEMBERS
+            .save(&mut deps.storage, (2, &collector), &Some(5))
+            .unwrap();
+        let user_mint_details = UserMintDetails::new(USERMINTDETAILS_KEY);
+        user_mint_details
+            .mint_for_user(
+                &mut deps.storage,
+                collector.clone(),
+                minter.clone(),
+                1,
+                &round(1000, 2000, None),
+                None,
+            )
+            .unwrap();
+
+        // Round 1 has not ended yet
+        let now = Timestamp::from_nanos(1500);
+        let rollover = next_round_rollover(&deps.storage, now, 3, &collector, &minter).unwrap();
+        assert_eq!(rollover, 0);
+
+        // Unused allocation of round 1 rolls into round 3
+        let now = Timestamp::from_nanos(3500);
+        let rollover = next_round_rollover(&deps.storage, now, 3, &collector, &minter).unwrap();
+        assert_eq!(rollover, 2);
+        let rollover = next_round_rollover(&deps.storage, now, 2, &collector, &minter).unwrap();
+        assert_eq!(rollover, 0);
+
+        // Usage is tracked per minter
+        let other_minter = Addr::unchecked("other_minter");
+        let rollover =
+            next_round_rollover(&deps.storage, now, 3, &collector, &other_minter).unwrap();
+        assert_eq!(rollover, 3);
+
+        // Non members have nothing to roll over
+        let creator = Addr::unchecked("creator");
+        let rollover = next_round_rollover(&deps.storage, now, 3, &creator, &minter).unwrap();
+        assert_eq!(rollover, 0);
+
+        // Unused allocation of round 2 goes to the public limit once it ends
+        let rollover = public_rollover(&deps.storage, now, &collector, &minter).unwrap();
+        assert_eq!(rollover, 0);
+        let now = Timestamp::from_nanos(6500);
+        let rollover = public_rollover(&deps.storage, now, &collector, &minter).unwrap();
+        assert_eq!(rollover, 5);
+    }
+}
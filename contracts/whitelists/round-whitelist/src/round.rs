@@ -1,5 +1,6 @@
 use crate::error::ContractError;
-use cosmwasm_std::{Coin, Timestamp};
+use cosmwasm_std::{coin, Coin, Timestamp};
+use whitelist_types::round::{check_round_integrity, stores_members};
 use whitelist_types::Round;
 
 pub trait RoundMethods {
@@ -8,6 +9,7 @@ pub trait RoundMethods {
     fn has_ended(&self, current_time: Timestamp) -> bool;
     fn mint_price(&self) -> Coin;
     fn check_integrity(&self, now: Timestamp) -> Result<(), ContractError>;
+    fn stores_members(&self) -> bool;
 }
 impl RoundMethods for Round {
     fn is_active(&self, current_time: Timestamp) -> bool {
@@ -20,20 +22,19 @@ impl RoundMethods for Round {
         current_time > self.end_time
     }
 
+    // Free claim rounds keep the denom of the mint price
     fn mint_price(&self) -> Coin {
+        if self.free_claim {
+            return coin(0, self.mint_price.denom.clone());
+        }
         self.mint_price.clone()
     }
     fn check_integrity(&self, now: Timestamp) -> Result<(), ContractError> {
-        if self.start_time > self.end_time {
-            return Err(ContractError::InvalidEndTime {});
-        }
-        if self.start_time < now {
-            return Err(ContractError::RoundAlreadyStarted {});
-        }
-        if self.round_per_address_limit == 0 {
-            return Err(ContractError::InvalidPerAddressLimit {});
-        }
-
+        check_round_integrity(self, now)?;
         Ok(())
     }
+    // Merkle and voucher rounds prove membership at mint time
+    fn stores_members(&self) -> bool {
+        stores_members(self)
+    }
 }
@@ -1,13 +1,21 @@
 use crate::round::RoundMethods;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Api, Order, StdError, StdResult, Storage, Timestamp};
-use cw_storage_plus::{Item, Map, PrefixBound};
+use cosmwasm_std::{Addr, Api, Empty, Order, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Bound, Item, Map};
 
 use crate::error::ContractError;
-use whitelist_types::Round;
+use whitelist_types::round::{check_batch_size, check_round_members, check_rounds_overlap};
+use whitelist_types::{Round, UsageRecord};
 
 pub const CONFIG: Item<Config> = Item::new("config");
-pub const ROUNDMEMBERS: Map<(Vec<u8>, Vec<u8>), bool> = Map::new("round_members");
+// Allocation of the member, None means the round per address limit is used
+pub const ROUNDMEMBERS: Map<(u8, &Addr), Option<u32>> = Map::new("members");
+// Minter contracts allowed to call PrivateMint
+pub const MINTERS: Map<&Addr, Empty> = Map::new("minters");
+// Number of tokens minted by each minter in each round
+pub const MINTER_ROUND_MINTS: Map<(&Addr, u8), u32> = Map::new("minter_round_mints");
+// Number of tokens minted by all minters in each round
+pub const ROUND_MINTS: Map<u8, u32> = Map::new("round_mints");
 pub const ROUNDS_KEY: &str = "rounds";
 pub const USERMINTDETAILS_KEY: &str = "user_mint_details";
 
@@ -17,7 +25,7 @@ pub struct Config {
 }
 
 pub type RoundIndex = u8;
-pub type MintCount = u8;
+pub type MintCount = u32;
 pub type MinterAddress = Addr;
 pub type UserAddress = Addr;
 
@@ -34,6 +42,8 @@ impl<'a> UserMintDetails<'a> {
         minter_address: MinterAddress,
         round_index: u8,
         round: &Round,
+        // Allocation of the member, overrides the round per address limit
+        allocation: Option<u32>,
     ) -> Result<(), ContractError> {
         // Load mint count for the use
         let mint_count = self
@@ -44,7 +54,8 @@ impl<'a> UserMintDetails<'a> {
             )?
             .unwrap_or(0);
         // Check if the user has reached the mint limit
-        if mint_count >= round.round_per_address_limit {
+        let limit = allocation.unwrap_or(round.round_per_address_limit);
+        if mint_count >= limit {
             return Err(ContractError::RoundReachedMintLimit {});
         }
         // Increment the mint count
@@ -55,6 +66,42 @@ impl<'a> UserMintDetails<'a> {
         )?;
         Ok(())
     }
+
+    pub fn mint_count(
+        &self,
+        store: &dyn Storage,
+        user_address: UserAddress,
+        minter_address: MinterAddress,
+        round_index: u8,
+    ) -> StdResult<u32> {
+        let mint_count = self
+            .0
+            .may_load(store, (user_address, minter_address, round_index))?
+            .unwrap_or(0);
+        Ok(mint_count)
+    }
+
+    pub fn load_records(
+        &self,
+        store: &dyn Storage,
+        start_after: Option<(UserAddress, MinterAddress, RoundIndex)>,
+        limit: usize,
+    ) -> StdResult<Vec<UsageRecord>> {
+        let start = start_after.map(Bound::exclusive);
+        self.0
+            .range(store, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let ((address, minter, round_index), minted) = item?;
+                Ok(UsageRecord {
+                    address,
+                    minter,
+                    round_index,
+                    minted,
+                })
+            })
+            .collect()
+    }
 }
 
 pub struct Rounds<'a>(Map<'a, RoundIndex, Round>);
@@ -99,12 +146,27 @@ impl<'a> Rounds<'a> {
         store: &dyn Storage,
         current_time: Timestamp,
     ) -> Option<(u8, Round)> {
-        self.0
+        // Active round with the highest priority
+        self.load_active_rounds(store, current_time)
+            .into_iter()
+            .next()
+    }
+
+    // Returns the active rounds sorted by priority, rounds with the same priority by index
+    pub fn load_active_rounds(
+        &self,
+        store: &dyn Storage,
+        current_time: Timestamp,
+    ) -> Vec<(u8, Round)> {
+        let mut active_rounds: Vec<(u8, Round)> = self
+            .0
             .range(store, None, None, Order::Ascending)
             .filter_map(|result| result.ok())
-            // We are assuming that there are no overlapping rounds in storage
-            // So the first round that is active is the active round
-            .find(|(_, round)| round.is_active(current_time))
+            .filter(|(_, round)| round.is_active(current_time))
+            .collect();
+        // Sort is stable so rounds with the same priority keep their index order
+        active_rounds.sort_by(|(_, a), (_, b)| b.priority.cmp(&a.priority));
+        active_rounds
     }
 
     pub fn load_all_rounds(&self, store: &dyn Storage) -> StdResult<Vec<(u8, Round)>> {
@@ -116,84 +178,90 @@ impl<'a> Rounds<'a> {
         store: &dyn Storage,
         // It has option to check if the provided rounds overlap with the rounds in storage
         round: Option<Vec<Round>>,
+        // Round in storage that is left out of the check, used when a round is being edited
+        excluded_round_index: Option<u8>,
     ) -> Result<(), ContractError> {
-        let last_index = self.last_id(store)?;
-        let mut rounds = self.load_all_rounds(store)?;
-
-        // Put indexes of the provided rounds
-        // Its not needed to start at last index + 1 because we are only checking for overlaps
-        if let Some(round) = round {
-            for r in round {
-                rounds.push((last_index + 1, r));
-            }
-        }
-
-        // Sort the rounds by start time
-        rounds.sort_by(|(_, a), (_, b)| a.start_time.cmp(&b.start_time));
-
-        // Check if any round overlaps
-        for i in 0..rounds.len() - 1 {
-            let current_round = &rounds[i];
-            let next_round = &rounds[i + 1];
-
-            if current_round.1.end_time > next_round.1.start_time {
-                return Err(ContractError::RoundsOverlapped {});
-            }
+        let mut stored_rounds = self.load_all_rounds(store)?;
+        stored_rounds.retain(|(index, _)| Some(*index) != excluded_round_index);
+        let mut rounds: Vec<&Round> = stored_rounds.iter().map(|(_, round)| round).collect();
+        // Provided rounds are checked with the rounds in storage
+        if let Some(round) = &round {
+            rounds.extend(round.iter());
         }
+        check_rounds_overlap(rounds)?;
         Ok(())
     }
 }
-// Validates and saves the members to the storage
+// Validates and saves the members with their allocations to the storage
 pub fn save_members(
     store: &mut dyn Storage,
     api: &dyn Api,
     round_index: u8,
-    members: &Vec<String>,
+    members: &[(String, Option<u32>)],
 ) -> Result<(), ContractError> {
-    // Maximum number of members that can be added to a round at once
-    const MAX_MEMBERS: usize = 5000;
-    if members.len() > MAX_MEMBERS {
-        return Err(ContractError::WhitelistMemberLimitExceeded {});
+    check_batch_size(members)?;
+    for (member, allocation) in members {
+        if *allocation == Some(0) {
+            return Err(ContractError::InvalidMemberAllocation {});
+        }
+        let validated = api.addr_validate(member.as_str())?;
+        ROUNDMEMBERS.save(store, (round_index, &validated), allocation)?;
     }
-    if members.is_empty() {
-        return Err(ContractError::EmptyAddressList {});
+
+    Ok(())
+}
+
+// Merkle and voucher rounds do not store their members, membership is proven at mint time
+pub fn save_round_members(
+    store: &mut dyn Storage,
+    api: &dyn Api,
+    round_index: u8,
+    round: &Round,
+    members: &Vec<String>,
+) -> Result<(), ContractError> {
+    check_round_members(round, members)?;
+    if !round.stores_members() {
+        return Ok(());
     }
+    // Members of the round config use the round per address limit
+    let members: Vec<(String, Option<u32>)> = members
+        .iter()
+        .map(|member| (member.clone(), None))
+        .collect();
+    save_members(store, api, round_index, &members)
+}
+
+// Validates and removes the members from the storage
+// Returns the addresses that were members of the round
+pub fn remove_members(
+    store: &mut dyn Storage,
+    api: &dyn Api,
+    round_index: u8,
+    members: &[String],
+) -> Result<Vec<Addr>, ContractError> {
+    check_batch_size(members)?;
+    let mut removed = vec![];
     for member in members {
         let validated = api.addr_validate(member.as_str())?;
-        let address_str = validated.as_str();
-        let round_index_str = round_index.to_string();
-        ROUNDMEMBERS.save(
-            store,
-            (
-                round_index_str.as_bytes().to_vec(),
-                address_str.as_bytes().to_vec(),
-            ),
-            &true,
-        )?;
+        if ROUNDMEMBERS.has(store, (round_index, &validated)) {
+            ROUNDMEMBERS.remove(store, (round_index, &validated));
+            removed.push(validated);
+        }
     }
-
-    Ok(())
+    Ok(removed)
 }
 
 pub fn remove_members_with_round_index(
     store: &mut dyn Storage,
     round_index: u8,
 ) -> Result<(), ContractError> {
-    let round_index_str = round_index.to_string();
-    let next_round_index_str = (round_index + 1).to_string();
+    let members_to_remove = ROUNDMEMBERS
+        .prefix(round_index)
+        .keys(store, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
 
-    let keys_to_remove: Vec<(Vec<u8>, Vec<u8>)> = ROUNDMEMBERS
-        .prefix_range(
-            store,
-            Some(PrefixBound::inclusive(round_index_str.as_bytes())),
-            Some(PrefixBound::exclusive(next_round_index_str.as_bytes())),
-            Order::Ascending,
-        )
-        .map(|result| result.map(|((round_index, address), _)| (round_index, address)))
-        .collect::<Result<Vec<(Vec<u8>, Vec<u8>)>, StdError>>()?;
-
-    for key in keys_to_remove {
-        ROUNDMEMBERS.remove(store, key);
+    for member in members_to_remove {
+        ROUNDMEMBERS.remove(store, (round_index, &member));
     }
 
     Ok(())
@@ -206,20 +274,42 @@ pub fn check_member(
     address: &str,
 ) -> Result<bool, ContractError> {
     let validated = api.addr_validate(address)?;
-    let address_str = validated.as_str();
-    let round_index_str = round_index.to_string();
-    let is_member = ROUNDMEMBERS
-        .may_load(
-            store,
-            (
-                round_index_str.as_bytes().to_vec(),
-                address_str.as_bytes().to_vec(),
-            ),
-        )?
-        .unwrap_or(false);
+    let is_member = ROUNDMEMBERS.has(store, (round_index, &validated));
     Ok(is_member)
 }
 
+// Returns the allocation of the member, None if the member uses the round per address limit
+pub fn member_allocation(
+    store: &dyn Storage,
+    round_index: u8,
+    address: &Addr,
+) -> Result<Option<u32>, ContractError> {
+    let allocation = ROUNDMEMBERS
+        .may_load(store, (round_index, address))?
+        .flatten();
+    Ok(allocation)
+}
+
+// Returns the remaining supply of the round for the minter, None if the round has no cap
+pub fn remaining_round_supply(
+    store: &dyn Storage,
+    round_index: u8,
+    round: &Round,
+    minter: &Addr,
+) -> StdResult<Option<u32>> {
+    let round_supply = match &round.round_supply {
+        Some(round_supply) => round_supply,
+        None => return Ok(None),
+    };
+    let minted = if round_supply.per_minter {
+        MINTER_ROUND_MINTS.may_load(store, (minter, round_index))?
+    } else {
+        ROUND_MINTS.may_load(store, round_index)?
+    };
+    let remaining = round_supply.limit.saturating_sub(minted.unwrap_or(0));
+    Ok(Some(remaining))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -235,12 +325,24 @@ mod tests {
             end_time: Timestamp::from_seconds(2000),
             mint_price: coin(100, "flix"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
         let round2 = Round {
             start_time: Timestamp::from_seconds(3000),
             end_time: Timestamp::from_seconds(4000),
             mint_price: coin(100, "atom"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
         let round1_index = rounds.save(&mut deps.storage, &round).unwrap();
         assert_eq!(round1_index, 1);
@@ -265,12 +367,24 @@ mod tests {
             end_time: Timestamp::from_seconds(2000),
             mint_price: coin(100, "flix"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
         let round2 = Round {
             start_time: Timestamp::from_seconds(3000),
             end_time: Timestamp::from_seconds(4000),
             mint_price: coin(100, "atom"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
         let round1_index = rounds.save(&mut deps.storage, &round).unwrap();
         let _round2_index = rounds.save(&mut deps.storage, &round2).unwrap();
@@ -285,6 +399,12 @@ mod tests {
             end_time: Timestamp::from_seconds(6000),
             mint_price: coin(100, "atom"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
         let round3_index = rounds.save(&mut deps.storage, &round3).unwrap();
         assert_eq!(round3_index, 3);
@@ -301,12 +421,24 @@ mod tests {
             end_time: Timestamp::from_seconds(2000),
             mint_price: coin(100, "flix"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
         let round2 = Round {
             start_time: Timestamp::from_seconds(3000),
             end_time: Timestamp::from_seconds(4000),
             mint_price: coin(100, "atom"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
         // Try to load active round when no round is saved
         let active_round = rounds.load_active_round(&deps.storage, Timestamp::from_seconds(1500));
@@ -338,14 +470,39 @@ mod tests {
             end_time: Timestamp::from_seconds(2500),
             mint_price: coin(100, "atom"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
 
         let _round3_index = rounds.save(&mut deps.storage, &round3).unwrap();
         let active_round = rounds
             .load_active_round(&deps.storage, Timestamp::from_seconds(1600))
             .unwrap();
-        // We wont let that happen but if it does we will return the first round that is active
+        // Rounds with the same priority are ordered by index
         assert_eq!(active_round.1, round);
+
+        // Round with the higher priority comes first
+        let round4 = Round {
+            priority: 1,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
+            ..round3.clone()
+        };
+        let _round4_index = rounds.save(&mut deps.storage, &round4).unwrap();
+        let active_rounds = rounds.load_active_rounds(&deps.storage, Timestamp::from_seconds(1600));
+        assert_eq!(
+            active_rounds,
+            vec![(4, round4.clone()), (1, round), (3, round3)]
+        );
+        let active_round = rounds
+            .load_active_round(&deps.storage, Timestamp::from_seconds(1600))
+            .unwrap();
+        assert_eq!(active_round.1, round4);
     }
 
     #[test]
@@ -357,27 +514,66 @@ mod tests {
             end_time: Timestamp::from_seconds(2000),
             mint_price: coin(100, "flix"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
         let round2 = Round {
             start_time: Timestamp::from_seconds(3000),
             end_time: Timestamp::from_seconds(4000),
             mint_price: coin(100, "atom"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
         let round3 = Round {
             start_time: Timestamp::from_seconds(1500),
             end_time: Timestamp::from_seconds(2500),
             mint_price: coin(100, "atom"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
         let _round1_index = rounds.save(&mut deps.storage, &round).unwrap();
         let _round2_index = rounds.save(&mut deps.storage, &round2).unwrap();
         // No overlap so unwrap should not fail
-        rounds.check_round_overlaps(&deps.storage, None).unwrap();
+        rounds.check_round_overlaps(&deps.storage, None, None).unwrap();
+        let error = rounds
+            .check_round_overlaps(&deps.storage, Some([round3.clone()].to_vec()), None)
+            .unwrap_err();
+        assert_eq!(error, ContractError::RoundsOverlapped {});
+
+        // Round 1 is replaced by round 3 so they do not overlap
+        rounds
+            .check_round_overlaps(&deps.storage, Some([round3.clone()].to_vec()), Some(1))
+            .unwrap();
         let error = rounds
-            .check_round_overlaps(&deps.storage, Some([round3.clone()].to_vec()))
+            .check_round_overlaps(&deps.storage, Some([round3.clone()].to_vec()), Some(2))
             .unwrap_err();
         assert_eq!(error, ContractError::RoundsOverlapped {});
+
+        // Rounds with different priorities can overlap
+        let round3 = Round {
+            priority: 1,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
+            ..round3
+        };
+        rounds
+            .check_round_overlaps(&deps.storage, Some([round3].to_vec()), None)
+            .unwrap();
     }
     #[test]
     fn test_try_mint() {
@@ -389,6 +585,12 @@ mod tests {
             end_time: Timestamp::from_seconds(2000),
             mint_price: coin(100, "flix"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
 
         let _round_2 = Round {
@@ -396,6 +598,12 @@ mod tests {
             end_time: Timestamp::from_seconds(4000),
             mint_price: coin(100, "atom"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
 
         // Create a new user
@@ -410,6 +618,7 @@ mod tests {
                 minter_address.clone(),
                 1,
                 &round_1,
+                None,
             )
             .unwrap();
         // Check if the user_mint_details is saved
@@ -424,7 +633,14 @@ mod tests {
 
         // Try to mint for a user again
         let res = user_details
-            .mint_for_user(&mut deps.storage, user_address, minter_address, 1, &round_1)
+            .mint_for_user(
+                &mut deps.storage,
+                user_address,
+                minter_address,
+                1,
+                &round_1,
+                None,
+            )
             .unwrap_err();
         assert_eq!(res, ContractError::RoundReachedMintLimit {});
     }
@@ -438,6 +654,12 @@ mod tests {
             end_time: Timestamp::from_seconds(2000),
             mint_price: coin(100, "flix"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
 
         let index = rounds.save(&mut deps.storage, &round).unwrap();
@@ -452,6 +674,12 @@ mod tests {
             end_time: Timestamp::from_seconds(4000),
             mint_price: coin(100, "atom"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
         let index2 = rounds.save(&mut deps.storage, &round2).unwrap();
         // last_index is 2
@@ -462,6 +690,12 @@ mod tests {
             end_time: Timestamp::from_seconds(6000),
             mint_price: coin(100, "atom"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
         let index3 = rounds.save(&mut deps.storage, &round3).unwrap();
         // last_index is 3
@@ -472,6 +706,12 @@ mod tests {
             end_time: Timestamp::from_seconds(8000),
             mint_price: coin(100, "atom"),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
         };
         let index4 = rounds.save(&mut deps.storage, &round4).unwrap();
         // last_index is 4
@@ -499,7 +739,7 @@ mod tests {
     #[test]
     fn test_save_load_members() {
         let mut deps = mock_dependencies();
-        let members = vec!["member1".to_string(), "member2".to_string()];
+        let members = vec![("member1".to_string(), None), ("member2".to_string(), Some(5))];
         save_members(&mut deps.storage, &deps.api, 1, &members.clone()).unwrap();
         let is_member = check_member(&deps.storage, &deps.api, 1, "member1").unwrap();
         assert!(is_member);
@@ -507,6 +747,20 @@ mod tests {
         assert!(is_member);
         let is_member = check_member(&deps.storage, &deps.api, 1, "member3").unwrap();
         assert!(!is_member);
+        let allocation = member_allocation(&deps.storage, 1, &Addr::unchecked("member1")).unwrap();
+        assert_eq!(allocation, None);
+        let allocation = member_allocation(&deps.storage, 1, &Addr::unchecked("member2")).unwrap();
+        assert_eq!(allocation, Some(5));
+
+        // Zero allocation is not allowed
+        let res = save_members(
+            &mut deps.storage,
+            &deps.api,
+            1,
+            &[("member3".to_string(), Some(0))],
+        )
+        .unwrap_err();
+        assert_eq!(res, ContractError::InvalidMemberAllocation {});
 
         // Overwrite saves should not affect anything
         save_members(&mut deps.storage, &deps.api, 1, &members.clone()).unwrap();
@@ -518,14 +772,14 @@ mod tests {
         assert!(!is_member);
 
         // Save empty members
-        let members = vec![];
+        let members: Vec<(String, Option<u32>)> = vec![];
         let res = save_members(&mut deps.storage, &deps.api, 1, &members.clone()).unwrap_err();
         assert_eq!(res, ContractError::EmptyAddressList {});
     }
     #[test]
     fn test_remove_members() {
         let mut deps = mock_dependencies();
-        let members = vec!["member1".to_string(), "member2".to_string()];
+        let members = vec![("member1".to_string(), None), ("member2".to_string(), None)];
         save_members(&mut deps.storage, &deps.api, 1, &members.clone()).unwrap();
         let is_member = check_member(&deps.storage, &deps.api, 1, "member1").unwrap();
         assert!(is_member);
@@ -540,10 +794,10 @@ mod tests {
 
         let mut deps = mock_dependencies();
         // Remove members when there are multiple rounds
-        let members = vec!["member1".to_string(), "member2".to_string()];
+        let members = vec![("member1".to_string(), None), ("member2".to_string(), None)];
         save_members(&mut deps.storage, &deps.api, 1, &members.clone()).unwrap();
 
-        let members_2 = vec!["member3".to_string(), "member4".to_string()];
+        let members_2 = vec![("member3".to_string(), None), ("member4".to_string(), None)];
         save_members(&mut deps.storage, &deps.api, 2, &members_2.clone()).unwrap();
 
         remove_members_with_round_index(&mut deps.storage, 1).unwrap();
@@ -559,5 +813,49 @@ mod tests {
 
         let is_member = check_member(&deps.storage, &deps.api, 2, "member4").unwrap();
         assert!(is_member);
+
+        // Remove some of the members, only existing members are returned
+        let removed = remove_members(
+            &mut deps.storage,
+            &deps.api,
+            2,
+            &["member3".to_string(), "member5".to_string()],
+        )
+        .unwrap();
+        assert_eq!(removed, vec![Addr::unchecked("member3")]);
+        let is_member = check_member(&deps.storage, &deps.api, 2, "member3").unwrap();
+        assert!(!is_member);
+        let is_member = check_member(&deps.storage, &deps.api, 2, "member4").unwrap();
+        assert!(is_member);
+
+        let res = remove_members(&mut deps.storage, &deps.api, 2, &[]).unwrap_err();
+        assert_eq!(res, ContractError::EmptyAddressList {});
+        let res = remove_members(
+            &mut deps.storage,
+            &deps.api,
+            2,
+            &vec!["member4".to_string(); 5001],
+        )
+        .unwrap_err();
+        assert_eq!(res, ContractError::WhitelistMemberLimitExceeded {});
+    }
+
+    #[test]
+    fn test_remove_members_with_round_index_many_rounds() {
+        let mut deps = mock_dependencies();
+        // Round 1 shares its leading digit with rounds 10 to 19
+        for round_index in [1, 10, 11, 12] {
+            let members = vec![(format!("member{}", round_index), None)];
+            save_members(&mut deps.storage, &deps.api, round_index, &members).unwrap();
+        }
+
+        remove_members_with_round_index(&mut deps.storage, 1).unwrap();
+        let is_member = check_member(&deps.storage, &deps.api, 1, "member1").unwrap();
+        assert!(!is_member);
+        for round_index in [10, 11, 12] {
+            let member = format!("member{}", round_index);
+            let is_member = check_member(&deps.storage, &deps.api, round_index, &member).unwrap();
+            assert!(is_member);
+        }
     }
 }
@@ -0,0 +1,170 @@
+use cosmwasm_std::{to_json_vec, Addr, Api, Binary, Env, StdResult, Storage};
+use cw_storage_plus::Map;
+use sha2::{Digest, Sha256};
+use whitelist_types::round::check_voucher_signer;
+use whitelist_types::{MembershipProof, VoucherPayload};
+
+use crate::error::ContractError;
+
+// Number of tokens minted with each voucher nonce
+pub const VOUCHER_NONCES: Map<&str, u32> = Map::new("voucher_nonces");
+
+pub fn validate_voucher_signer(voucher_signer: &Binary) -> Result<(), ContractError> {
+    check_voucher_signer(voucher_signer)?;
+    Ok(())
+}
+
+pub fn verify_voucher_signature(
+    api: &dyn Api,
+    voucher_signer: &Binary,
+    payload: &VoucherPayload,
+    signature: &Binary,
+) -> Result<(), ContractError> {
+    let message_hash = Sha256::digest(to_json_vec(payload)?);
+    let is_valid = api
+        .secp256k1_verify(&message_hash, signature, voucher_signer)
+        .map_err(|_| ContractError::InvalidVoucherSignature {})?;
+    if !is_valid {
+        return Err(ContractError::InvalidVoucherSignature {});
+    }
+    Ok(())
+}
+
+pub fn voucher_nonce_usage(storage: &dyn Storage, nonce: &str) -> StdResult<u32> {
+    Ok(VOUCHER_NONCES.may_load(storage, nonce)?.unwrap_or(0))
+}
+
+/// Checks the voucher of the address against the signer of the round
+/// and returns the signed payload
+pub fn check_voucher_membership(
+    api: &dyn Api,
+    storage: &dyn Storage,
+    env: &Env,
+    round_index: u8,
+    voucher_signer: &Binary,
+    address: &Addr,
+    proof: Option<MembershipProof>,
+) -> Result<VoucherPayload, ContractError> {
+    let (max_quantity, expiry, nonce, signature) = match proof {
+        Some(MembershipProof::Voucher {
+            max_quantity,
+            expiry,
+            nonce,
+            signature,
+        }) => (max_quantity, expiry, nonce, signature),
+        _ => return Err(ContractError::MembershipProofRequired {}),
+    };
+    if env.block.time > expiry {
+        return Err(ContractError::VoucherExpired {});
+    }
+    let payload = VoucherPayload {
+        whitelist_address: env.contract.address.to_string(),
+        round_index,
+        address: address.to_string(),
+        max_quantity,
+        expiry,
+        nonce,
+    };
+    verify_voucher_signature(api, voucher_signer, &payload, &signature)?;
+    if voucher_nonce_usage(storage, &payload.nonce)? >= max_quantity {
+        return Err(ContractError::VoucherNonceUsed {});
+    }
+    Ok(payload)
+}
+
+/// Records a mint with the voucher so it can not be replayed past its max quantity
+pub fn consume_voucher(
+    storage: &mut dyn Storage,
+    payload: &VoucherPayload,
+) -> Result<(), ContractError> {
+    VOUCHER_NONCES.update(storage, &payload.nonce, |used| -> StdResult<_> {
+        Ok(used.unwrap_or(0) + 1)
+    })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::Timestamp;
+    use k256::ecdsa::signature::hazmat::PrehashSigner;
+    use k256::ecdsa::{Signature, SigningKey};
+
+    fn sign(signing_key: &SigningKey, payload: &VoucherPayload) -> Binary {
+        let message_hash = Sha256::digest(to_json_vec(payload).unwrap());
+        let signature: Signature = signing_key.sign_prehash(&message_hash).unwrap();
+        Binary::from(signature.to_bytes().as_slice())
+    }
+
+    #[test]
+    fn test_check_voucher_membership() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let signing_key = SigningKey::from_slice(&[7u8; 32]).unwrap();
+        let voucher_signer =
+            Binary::from(signing_key.verifying_key().to_encoded_point(true).as_bytes());
+        validate_voucher_signer(&voucher_signer).unwrap();
+        assert_eq!(
+            validate_voucher_signer(&Binary::from(vec![1u8; 32])),
+            Err(ContractError::InvalidVoucherSigner {})
+        );
+
+        let member = Addr::unchecked("member");
+        let expiry = env.block.time.plus_seconds(100);
+        let payload = VoucherPayload {
+            whitelist_address: env.contract.address.to_string(),
+            round_index: 1,
+            address: member.to_string(),
+            max_quantity: 2,
+            expiry,
+            nonce: "nonce1".to_string(),
+        };
+        let voucher = MembershipProof::Voucher {
+            max_quantity: 2,
+            expiry,
+            nonce: "nonce1".to_string(),
+            signature: sign(&signing_key, &payload),
+        };
+        let check = |storage: &dyn Storage, env: &Env, address: &Addr, round_index: u8| {
+            check_voucher_membership(
+                &deps.api,
+                storage,
+                env,
+                round_index,
+                &voucher_signer,
+                address,
+                Some(voucher.clone()),
+            )
+        };
+        assert_eq!(check(&deps.storage, &env, &member, 1), Ok(payload.clone()));
+
+        // Voucher is bound to the address and the round
+        assert_eq!(
+            check(&deps.storage, &env, &Addr::unchecked("other"), 1),
+            Err(ContractError::InvalidVoucherSignature {})
+        );
+        assert_eq!(
+            check(&deps.storage, &env, &member, 2),
+            Err(ContractError::InvalidVoucherSignature {})
+        );
+
+        // Expired voucher
+        let mut expired_env = env.clone();
+        expired_env.block.time = Timestamp::from_nanos(expiry.nanos() + 1);
+        assert_eq!(
+            check(&deps.storage, &expired_env, &member, 1),
+            Err(ContractError::VoucherExpired {})
+        );
+
+        // Nonce can not be used more than max quantity
+        consume_voucher(&mut deps.storage, &payload).unwrap();
+        assert_eq!(check(&deps.storage, &env, &member, 1), Ok(payload.clone()));
+        consume_voucher(&mut deps.storage, &payload).unwrap();
+        assert_eq!(voucher_nonce_usage(&deps.storage, "nonce1").unwrap(), 2);
+        assert_eq!(
+            check(&deps.storage, &env, &member, 1),
+            Err(ContractError::VoucherNonceUsed {})
+        );
+    }
+}
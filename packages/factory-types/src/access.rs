@@ -0,0 +1,211 @@
+use crate::registry::Registry;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, StdError, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Item, Map};
+use thiserror::Error;
+
+/// Who can create contracts through a factory
+#[cw_serde]
+pub enum CreatorAccessMode {
+    // Anyone who pays the creation fee
+    Open,
+    // Only approved creators
+    ApprovedOnly,
+    // Approved creators, and others up to max_contracts in every window_seconds
+    RateLimited {
+        max_contracts: u32,
+        window_seconds: u64,
+    },
+}
+
+#[cw_serde]
+pub struct CreatorStatus {
+    pub mode: CreatorAccessMode,
+    pub approved: bool,
+    pub can_create: bool,
+    // Contracts the creator can still create in the current window, None if not limited
+    pub remaining_contracts: Option<u32>,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum AccessError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error("Creator is not approved")]
+    CreatorNotApproved {},
+
+    #[error("Creator reached the limit of {max_contracts} contracts in {window_seconds} seconds")]
+    RateLimited {
+        max_contracts: u32,
+        window_seconds: u64,
+    },
+}
+
+/// Access mode and approved creators of a factory
+pub struct CreatorAccess<'a> {
+    pub mode: Item<'a, CreatorAccessMode>,
+    pub approved: Map<'a, &'a Addr, bool>,
+}
+
+impl<'a> Default for CreatorAccess<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> CreatorAccess<'a> {
+    pub fn new() -> Self {
+        CreatorAccess {
+            mode: Item::new("creator_access_mode"),
+            approved: Map::new("approved_creators"),
+        }
+    }
+
+    // Factories are open until the admin sets another mode
+    pub fn load_mode(&self, storage: &dyn Storage) -> StdResult<CreatorAccessMode> {
+        Ok(self
+            .mode
+            .may_load(storage)?
+            .unwrap_or(CreatorAccessMode::Open))
+    }
+
+    pub fn approve(&self, storage: &mut dyn Storage, creators: &[Addr]) -> StdResult<()> {
+        for creator in creators {
+            self.approved.save(storage, creator, &true)?;
+        }
+        Ok(())
+    }
+
+    pub fn revoke(&self, storage: &mut dyn Storage, creators: &[Addr]) {
+        for creator in creators {
+            self.approved.remove(storage, creator);
+        }
+    }
+
+    /// Returns the status of the creator, counting its contracts in the registry of the factory
+    pub fn status(
+        &self,
+        storage: &dyn Storage,
+        creator: &Addr,
+        now: Timestamp,
+    ) -> StdResult<CreatorStatus> {
+        let mode = self.load_mode(storage)?;
+        let approved = self.approved.has(storage, creator);
+        let (can_create, remaining_contracts) = match &mode {
+            CreatorAccessMode::Open => (true, None),
+            CreatorAccessMode::ApprovedOnly => (approved, None),
+            CreatorAccessMode::RateLimited { .. } if approved => (true, None),
+            CreatorAccessMode::RateLimited {
+                max_contracts,
+                window_seconds,
+            } => {
+                let window = window_seconds.saturating_mul(1_000_000_000);
+                let window_start = Timestamp::from_nanos(now.nanos().saturating_sub(window));
+                let registry = Registry::new();
+                let created = registry.count_created_after(storage, creator.clone(), window_start)?;
+                let remaining = max_contracts.saturating_sub(created);
+                (remaining > 0, Some(remaining))
+            }
+        };
+        Ok(CreatorStatus {
+            mode,
+            approved,
+            can_create,
+            remaining_contracts,
+        })
+    }
+
+    /// Returns an error if the creator can not create a contract now
+    pub fn check(
+        &self,
+        storage: &dyn Storage,
+        creator: &Addr,
+        now: Timestamp,
+    ) -> Result<(), AccessError> {
+        let status = self.status(storage, creator, now)?;
+        if status.can_create {
+            return Ok(());
+        }
+        match status.mode {
+            CreatorAccessMode::RateLimited {
+                max_contracts,
+                window_seconds,
+            } => Err(AccessError::RateLimited {
+                max_contracts,
+                window_seconds,
+            }),
+            _ => Err(AccessError::CreatorNotApproved {}),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::{ContractType, CreatedContract};
+    use cosmwasm_std::testing::mock_dependencies;
+
+    fn save_contract(storage: &mut dyn Storage, address: &str, created_at: Timestamp) {
+        let contract = CreatedContract {
+            address: Addr::unchecked(address),
+            creator: Addr::unchecked("creator"),
+            code_id: 1,
+            contract_type: ContractType::Minter,
+            collection_id: None,
+            created_at,
+            factory_admin: false,
+        };
+        Registry::new().save(storage, &contract).unwrap();
+    }
+
+    #[test]
+    fn test_creator_access() {
+        let mut deps = mock_dependencies();
+        let access = CreatorAccess::new();
+        let creator = Addr::unchecked("creator");
+        let now = Timestamp::from_seconds(1_000);
+
+        // Factories are open by default
+        access.check(&deps.storage, &creator, now).unwrap();
+
+        access
+            .mode
+            .save(&mut deps.storage, &CreatorAccessMode::ApprovedOnly)
+            .unwrap();
+        let res = access.check(&deps.storage, &creator, now);
+        assert_eq!(res, Err(AccessError::CreatorNotApproved {}));
+        access
+            .approve(&mut deps.storage, &[creator.clone()])
+            .unwrap();
+        access.check(&deps.storage, &creator, now).unwrap();
+
+        let mode = CreatorAccessMode::RateLimited {
+            max_contracts: 1,
+            window_seconds: 100,
+        };
+        access.mode.save(&mut deps.storage, &mode).unwrap();
+        save_contract(&mut deps.storage, "minter_1", Timestamp::from_seconds(950));
+
+        // Approved creators are not limited
+        let status = access.status(&deps.storage, &creator, now).unwrap();
+        assert!(status.can_create);
+        assert_eq!(status.remaining_contracts, None);
+
+        access.revoke(&mut deps.storage, &[creator.clone()]);
+        let res = access.check(&deps.storage, &creator, now);
+        assert_eq!(
+            res,
+            Err(AccessError::RateLimited {
+                max_contracts: 1,
+                window_seconds: 100,
+            })
+        );
+
+        // Contracts created before the window are not counted
+        let now = Timestamp::from_seconds(1_050);
+        let status = access.status(&deps.storage, &creator, now).unwrap();
+        assert!(status.can_create);
+        assert_eq!(status.remaining_contracts, Some(1));
+    }
+}
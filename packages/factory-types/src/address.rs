@@ -0,0 +1,32 @@
+use cosmwasm_std::{instantiate2_address, Addr, Binary, Deps, StdError, StdResult};
+use sha2::{Digest, Sha256};
+
+/// Returns the instantiate2 salt of a minter, derived from its creator and collection id
+pub fn minter_salt(creator: &Addr, collection_id: &str) -> Binary {
+    let hash = Sha256::digest(format!("{}/{}", creator, collection_id).as_bytes());
+    Binary::from(hash.as_slice())
+}
+
+/// Returns the address the factory instantiates the code id with the salt at
+pub fn predict_address(deps: Deps, factory: &Addr, code_id: u64, salt: &Binary) -> StdResult<Addr> {
+    let checksum = deps.querier.query_wasm_code_info(code_id)?.checksum;
+    let factory = deps.api.addr_canonicalize(factory.as_str())?;
+    let address = instantiate2_address(checksum.as_slice(), &factory, salt.as_slice())
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+    deps.api.addr_humanize(&address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minter_salt() {
+        let creator = Addr::unchecked("creator");
+        let salt = minter_salt(&creator, "id");
+        assert_eq!(salt.len(), 32);
+        assert_eq!(salt, minter_salt(&creator, "id"));
+        assert_ne!(salt, minter_salt(&creator, "id2"));
+        assert_ne!(salt, minter_salt(&Addr::unchecked("collector"), "id"));
+    }
+}
@@ -0,0 +1,341 @@
+use crate::registry::{ContractType, Registry};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Binary, Order, StdError, StdResult, Storage, WasmMsg};
+use cw_storage_plus::{Bound, Map};
+use thiserror::Error;
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
+/// Code id approved by the factory admin for a contract type
+#[cw_serde]
+pub struct CodeVersion {
+    pub contract_type: ContractType,
+    pub version: String,
+    pub code_id: u64,
+    pub label: String,
+    // Deprecated versions can not be used for new contracts or migrations
+    pub deprecated: bool,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum CatalogError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error("Contract type {contract_type} is not created by this factory")]
+    UnsupportedContractType { contract_type: String },
+
+    #[error("Version {version} already exists")]
+    VersionExists { version: String },
+
+    #[error("Version {version} not found")]
+    VersionNotFound { version: String },
+
+    #[error("Version {version} is deprecated")]
+    VersionDeprecated { version: String },
+
+    #[error("Contract {address} is not a {contract_type} created by this factory")]
+    ContractNotRegistered {
+        address: String,
+        contract_type: String,
+    },
+
+    #[error("Factory is not the wasm admin of contract {address}")]
+    NotFactoryAdmin { address: String },
+}
+
+/// Code versions of the contracts created by a factory
+pub struct CodeCatalog<'a> {
+    // Keyed by contract type and version
+    pub versions: Map<'a, (&'a str, &'a str), CodeVersion>,
+}
+
+impl<'a> Default for CodeCatalog<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> CodeCatalog<'a> {
+    pub fn new() -> Self {
+        CodeCatalog {
+            versions: Map::new("code_versions"),
+        }
+    }
+
+    /// Adds the version if its contract type is one of the supported types
+    pub fn add(
+        &self,
+        storage: &mut dyn Storage,
+        version: CodeVersion,
+        supported_types: &[ContractType],
+    ) -> Result<(), CatalogError> {
+        if !supported_types.contains(&version.contract_type) {
+            return Err(CatalogError::UnsupportedContractType {
+                contract_type: version.contract_type.as_str().to_string(),
+            });
+        }
+        let key = (version.contract_type.as_str(), version.version.as_str());
+        if self.versions.has(storage, key) {
+            return Err(CatalogError::VersionExists {
+                version: version.version,
+            });
+        }
+        self.versions.save(storage, key, &version)?;
+        Ok(())
+    }
+
+    pub fn load(
+        &self,
+        storage: &dyn Storage,
+        contract_type: &ContractType,
+        version: &str,
+    ) -> Result<CodeVersion, CatalogError> {
+        self.versions
+            .may_load(storage, (contract_type.as_str(), version))?
+            .ok_or(CatalogError::VersionNotFound {
+                version: version.to_string(),
+            })
+    }
+
+    /// Returns the code id of the version, if it is not deprecated
+    pub fn active_code_id(
+        &self,
+        storage: &dyn Storage,
+        contract_type: &ContractType,
+        version: &str,
+    ) -> Result<u64, CatalogError> {
+        let code_version = self.load(storage, contract_type, version)?;
+        if code_version.deprecated {
+            return Err(CatalogError::VersionDeprecated {
+                version: code_version.version,
+            });
+        }
+        Ok(code_version.code_id)
+    }
+
+    pub fn set_deprecated(
+        &self,
+        storage: &mut dyn Storage,
+        contract_type: &ContractType,
+        version: &str,
+        deprecated: bool,
+    ) -> Result<(), CatalogError> {
+        let mut code_version = self.load(storage, contract_type, version)?;
+        code_version.deprecated = deprecated;
+        self.versions
+            .save(storage, (contract_type.as_str(), version), &code_version)?;
+        Ok(())
+    }
+
+    pub fn list(
+        &self,
+        storage: &dyn Storage,
+        contract_type: &ContractType,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<CodeVersion>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.as_deref().map(Bound::exclusive);
+        self.versions
+            .prefix(contract_type.as_str())
+            .range(storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| item.map(|(_, code_version)| code_version))
+            .collect()
+    }
+
+    /// Returns the messages migrating the contracts to the version
+    /// Contracts must be registered by the factory with the contract type of the version
+    /// and have the factory as their wasm admin
+    pub fn migrate_msgs(
+        &self,
+        storage: &mut dyn Storage,
+        contract_type: &ContractType,
+        version: &str,
+        contracts: &[Addr],
+        msg: Binary,
+    ) -> Result<Vec<WasmMsg>, CatalogError> {
+        let code_id = self.active_code_id(storage, contract_type, version)?;
+        let registry = Registry::new();
+        let mut msgs = vec![];
+        for address in contracts {
+            let contract = registry
+                .load(storage, address)?
+                .filter(|contract| contract.contract_type == *contract_type)
+                .ok_or(CatalogError::ContractNotRegistered {
+                    address: address.to_string(),
+                    contract_type: contract_type.as_str().to_string(),
+                })?;
+            // Contracts of creators who did not opt in are migrated by their own admin
+            if !contract.factory_admin {
+                return Err(CatalogError::NotFactoryAdmin {
+                    address: address.to_string(),
+                });
+            }
+            // Registry keeps the code id the contract runs
+            registry.set_code_id(storage, address, code_id)?;
+            msgs.push(WasmMsg::Migrate {
+                contract_addr: address.to_string(),
+                new_code_id: code_id,
+                msg: msg.clone(),
+            });
+        }
+        Ok(msgs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::CreatedContract;
+    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::Timestamp;
+
+    fn code_version(contract_type: ContractType, version: &str, code_id: u64) -> CodeVersion {
+        CodeVersion {
+            contract_type,
+            version: version.to_string(),
+            code_id,
+            label: format!("Minter {}", version),
+            deprecated: false,
+        }
+    }
+
+    #[test]
+    fn test_code_catalog() {
+        let mut deps = mock_dependencies();
+        let catalog = CodeCatalog::new();
+        let supported_types = [ContractType::Minter];
+
+        let res = catalog.add(
+            &mut deps.storage,
+            code_version(ContractType::RoundWhitelist, "v1", 1),
+            &supported_types,
+        );
+        assert_eq!(
+            res,
+            Err(CatalogError::UnsupportedContractType {
+                contract_type: "round_whitelist".to_string(),
+            })
+        );
+        catalog
+            .add(
+                &mut deps.storage,
+                code_version(ContractType::Minter, "v1", 1),
+                &supported_types,
+            )
+            .unwrap();
+        catalog
+            .add(
+                &mut deps.storage,
+                code_version(ContractType::Minter, "v2", 2),
+                &supported_types,
+            )
+            .unwrap();
+        let res = catalog.add(
+            &mut deps.storage,
+            code_version(ContractType::Minter, "v2", 3),
+            &supported_types,
+        );
+        assert_eq!(
+            res,
+            Err(CatalogError::VersionExists {
+                version: "v2".to_string(),
+            })
+        );
+
+        let versions = catalog
+            .list(&deps.storage, &ContractType::Minter, None, None)
+            .unwrap();
+        assert_eq!(versions.len(), 2);
+        let versions = catalog
+            .list(&deps.storage, &ContractType::Minter, Some("v1".to_string()), None)
+            .unwrap();
+        assert_eq!(versions[0].code_id, 2);
+
+        catalog
+            .set_deprecated(&mut deps.storage, &ContractType::Minter, "v1", true)
+            .unwrap();
+        let res = catalog.active_code_id(&deps.storage, &ContractType::Minter, "v1");
+        assert_eq!(
+            res,
+            Err(CatalogError::VersionDeprecated {
+                version: "v1".to_string(),
+            })
+        );
+
+        // Only registered contracts of the contract type can be migrated
+        let contract = CreatedContract {
+            address: Addr::unchecked("minter"),
+            creator: Addr::unchecked("creator"),
+            code_id: 1,
+            contract_type: ContractType::Minter,
+            collection_id: Some("id".to_string()),
+            created_at: Timestamp::from_nanos(1_000),
+            factory_admin: false,
+        };
+        Registry::new()
+            .contracts
+            .save(&mut deps.storage, &contract.address, &contract)
+            .unwrap();
+        let res = catalog.migrate_msgs(
+            &mut deps.storage,
+            &ContractType::Minter,
+            "v2",
+            &[Addr::unchecked("unknown")],
+            Binary::default(),
+        );
+        assert_eq!(
+            res,
+            Err(CatalogError::ContractNotRegistered {
+                address: "unknown".to_string(),
+                contract_type: "minter".to_string(),
+            })
+        );
+        // Factory can not migrate contracts it is not the wasm admin of
+        let res = catalog.migrate_msgs(
+            &mut deps.storage,
+            &ContractType::Minter,
+            "v2",
+            &[contract.address.clone()],
+            Binary::default(),
+        );
+        assert_eq!(
+            res,
+            Err(CatalogError::NotFactoryAdmin {
+                address: "minter".to_string(),
+            })
+        );
+        let contract = CreatedContract {
+            factory_admin: true,
+            ..contract
+        };
+        Registry::new()
+            .contracts
+            .save(&mut deps.storage, &contract.address, &contract)
+            .unwrap();
+        let msgs = catalog
+            .migrate_msgs(
+                &mut deps.storage,
+                &ContractType::Minter,
+                "v2",
+                &[contract.address.clone()],
+                Binary::default(),
+            )
+            .unwrap();
+        assert_eq!(
+            msgs,
+            vec![WasmMsg::Migrate {
+                contract_addr: "minter".to_string(),
+                new_code_id: 2,
+                msg: Binary::default(),
+            }]
+        );
+        let contract = Registry::new()
+            .load(&deps.storage, &contract.address)
+            .unwrap();
+        assert_eq!(contract.unwrap().code_id, 2);
+    }
+}
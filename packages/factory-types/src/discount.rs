@@ -0,0 +1,177 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Decimal, Order, StdError, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Map};
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
+/// Discount applied to the creation fee of a creator
+#[cw_serde]
+pub enum FeeDiscount {
+    // Share of the creation fee that is waived, 1 waives the fee entirely
+    Percentage(Decimal),
+    // Amount charged instead of the creation fee, never more than the creation fee
+    Fixed(Uint128),
+}
+
+impl FeeDiscount {
+    pub fn validate(&self) -> StdResult<()> {
+        if let FeeDiscount::Percentage(percentage) = self {
+            if *percentage > Decimal::one() {
+                return Err(StdError::generic_err(
+                    "Discount percentage can not be more than 100%",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the fee after the discount, in the denom of the fee
+    pub fn apply(&self, fee: &Coin) -> Coin {
+        let amount = match self {
+            FeeDiscount::Percentage(percentage) => fee.amount - fee.amount.mul_floor(*percentage),
+            FeeDiscount::Fixed(amount) => (*amount).min(fee.amount),
+        };
+        Coin {
+            denom: fee.denom.clone(),
+            amount,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct CreatorFeeDiscount {
+    pub creator: Addr,
+    pub discount: FeeDiscount,
+}
+
+/// Creation fee discounts set by the admin of a factory
+pub struct CreatorDiscounts<'a> {
+    pub discounts: Map<'a, &'a Addr, FeeDiscount>,
+}
+
+impl<'a> Default for CreatorDiscounts<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> CreatorDiscounts<'a> {
+    pub fn new() -> Self {
+        CreatorDiscounts {
+            discounts: Map::new("creator_discounts"),
+        }
+    }
+
+    /// Sets the discount of the creator, None removes it
+    pub fn set(
+        &self,
+        storage: &mut dyn Storage,
+        creator: &Addr,
+        discount: Option<FeeDiscount>,
+    ) -> StdResult<()> {
+        match discount {
+            Some(discount) => {
+                discount.validate()?;
+                self.discounts.save(storage, creator, &discount)
+            }
+            None => {
+                self.discounts.remove(storage, creator);
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the creation fee the creator pays
+    pub fn creator_fee(
+        &self,
+        storage: &dyn Storage,
+        creator: &Addr,
+        fee: &Coin,
+    ) -> StdResult<Coin> {
+        let discount = self.discounts.may_load(storage, creator)?;
+        Ok(match discount {
+            Some(discount) => discount.apply(fee),
+            None => fee.clone(),
+        })
+    }
+
+    pub fn list(
+        &self,
+        storage: &dyn Storage,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<CreatorFeeDiscount>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.as_ref().map(Bound::exclusive);
+        self.discounts
+            .range(storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let (creator, discount) = item?;
+                Ok(CreatorFeeDiscount { creator, discount })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::coin;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn test_creator_fee() {
+        let mut deps = mock_dependencies();
+        let discounts = CreatorDiscounts::new();
+        let creator = Addr::unchecked("creator");
+        let fee = coin(1000000, "uflix");
+
+        // Creators without a discount pay the creation fee
+        let creator_fee = discounts
+            .creator_fee(&deps.storage, &creator, &fee)
+            .unwrap();
+        assert_eq!(creator_fee, fee);
+
+        let discount = FeeDiscount::Percentage(Decimal::percent(25));
+        discounts
+            .set(&mut deps.storage, &creator, Some(discount))
+            .unwrap();
+        let creator_fee = discounts
+            .creator_fee(&deps.storage, &creator, &fee)
+            .unwrap();
+        assert_eq!(creator_fee, coin(750000, "uflix"));
+
+        // Fixed fee is capped at the creation fee
+        let discount = FeeDiscount::Fixed(Uint128::new(2000000));
+        discounts
+            .set(&mut deps.storage, &creator, Some(discount))
+            .unwrap();
+        let creator_fee = discounts
+            .creator_fee(&deps.storage, &creator, &fee)
+            .unwrap();
+        assert_eq!(creator_fee, fee);
+
+        let discount = FeeDiscount::Fixed(Uint128::zero());
+        discounts
+            .set(&mut deps.storage, &creator, Some(discount))
+            .unwrap();
+        let creator_fee = discounts
+            .creator_fee(&deps.storage, &creator, &fee)
+            .unwrap();
+        assert_eq!(creator_fee, coin(0, "uflix"));
+
+        let discount = FeeDiscount::Percentage(Decimal::percent(101));
+        assert!(discounts
+            .set(&mut deps.storage, &creator, Some(discount))
+            .is_err());
+
+        discounts.set(&mut deps.storage, &creator, None).unwrap();
+        let creator_fee = discounts
+            .creator_fee(&deps.storage, &creator, &fee)
+            .unwrap();
+        assert_eq!(creator_fee, fee);
+        assert!(discounts.list(&deps.storage, None, None).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,51 @@
+use cosmwasm_std::{from_json, Addr, Reply, StdError, StdResult, SubMsgResult};
+
+// Reply id of the whitelist creation sent by the factories when creating a launch
+pub const CREATE_LAUNCH_WHITELIST_REPLY_ID: u64 = 2;
+
+/// Returns the address of the whitelist instantiated by the whitelist factory
+/// Whitelist factory sets the address as the data of its `CreateWhitelistFor` response
+pub fn whitelist_address_from_reply(reply: Reply) -> StdResult<Addr> {
+    let response = match reply.result {
+        SubMsgResult::Ok(response) => response,
+        SubMsgResult::Err(err) => return Err(StdError::generic_err(err)),
+    };
+    let data = response
+        .data
+        .ok_or_else(|| StdError::generic_err("Whitelist address not found in reply"))?;
+    from_json(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{to_json_binary, Event, SubMsgResponse};
+
+    #[test]
+    fn test_whitelist_address_from_reply() {
+        // Address is read from the data, not from the instantiate events
+        let reply = Reply {
+            id: CREATE_LAUNCH_WHITELIST_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![
+                    Event::new("instantiate").add_attribute("_contract_address", "other"),
+                    Event::new("instantiate").add_attribute("_contract_address", "whitelist"),
+                ],
+                data: Some(to_json_binary(&Addr::unchecked("whitelist")).unwrap()),
+            }),
+        };
+        let address = whitelist_address_from_reply(reply).unwrap();
+        assert_eq!(address, Addr::unchecked("whitelist"));
+
+        let reply = Reply {
+            id: CREATE_LAUNCH_WHITELIST_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![
+                    Event::new("instantiate").add_attribute("_contract_address", "whitelist"),
+                ],
+                data: None,
+            }),
+        };
+        assert!(whitelist_address_from_reply(reply).is_err());
+    }
+}
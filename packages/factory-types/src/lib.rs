@@ -1,3 +1,12 @@
+pub mod access;
+pub mod address;
+pub mod catalog;
+pub mod discount;
+pub mod launch;
+pub mod registry;
+pub mod upgrades;
+pub mod validation;
+
 use cosmwasm_std::Coin;
 use cosmwasm_std::StdError;
 use cw_utils::NativeBalance;
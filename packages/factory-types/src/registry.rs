@@ -0,0 +1,351 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Order, Reply, StdError, StdResult, Storage, SubMsg, Timestamp, WasmMsg};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+use cw_utils::parse_reply_instantiate_data;
+
+// Reply id of the instantiate messages sent by the factories
+pub const CREATE_CONTRACT_REPLY_ID: u64 = 1;
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
+#[cw_serde]
+pub enum ContractType {
+    Minter,
+    OpenEditionMinter,
+    MultiMintOpenEditionMinter,
+    RoundWhitelist,
+}
+
+impl ContractType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ContractType::Minter => "minter",
+            ContractType::OpenEditionMinter => "open_edition_minter",
+            ContractType::MultiMintOpenEditionMinter => "multi_mint_open_edition_minter",
+            ContractType::RoundWhitelist => "round_whitelist",
+        }
+    }
+}
+
+/// Contract instantiated by a factory
+#[cw_serde]
+pub struct CreatedContract {
+    pub address: Addr,
+    pub creator: Addr,
+    pub code_id: u64,
+    pub contract_type: ContractType,
+    // None for contracts without a collection, like whitelists
+    pub collection_id: Option<String>,
+    pub created_at: Timestamp,
+    // Factory is the wasm admin, so the factory admin can migrate the contract
+    pub factory_admin: bool,
+}
+
+/// Details of a contract kept until the instantiate reply returns its address
+#[cw_serde]
+pub struct PendingContract {
+    pub creator: Addr,
+    pub code_id: u64,
+    pub contract_type: ContractType,
+    pub collection_id: Option<String>,
+    pub factory_admin: bool,
+}
+
+pub struct RegistryIndexes<'a> {
+    pub creator: MultiIndex<'a, Addr, CreatedContract, Addr>,
+    pub contract_type: MultiIndex<'a, String, CreatedContract, Addr>,
+    pub collection_id: MultiIndex<'a, String, CreatedContract, Addr>,
+}
+
+impl<'a> IndexList<CreatedContract> for RegistryIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<CreatedContract>> + '_> {
+        let v: Vec<&dyn Index<CreatedContract>> =
+            vec![&self.creator, &self.contract_type, &self.collection_id];
+        Box::new(v.into_iter())
+    }
+}
+
+pub struct Registry<'a> {
+    pub contracts: IndexedMap<'a, &'a Addr, CreatedContract, RegistryIndexes<'a>>,
+    pub pending: Item<'a, PendingContract>,
+    // Number of contracts each creator created at a block time, so rate limits read the window only
+    pub created_at: Map<'a, (&'a Addr, u64), u32>,
+}
+
+impl<'a> Default for Registry<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Registry<'a> {
+    pub fn new() -> Self {
+        let indexes = RegistryIndexes {
+            creator: MultiIndex::new(
+                |_pk, contract| contract.creator.clone(),
+                "created_contracts",
+                "created_contracts__creator",
+            ),
+            contract_type: MultiIndex::new(
+                |_pk, contract| contract.contract_type.as_str().to_string(),
+                "created_contracts",
+                "created_contracts__contract_type",
+            ),
+            // Contracts without a collection are indexed under an empty collection id
+            collection_id: MultiIndex::new(
+                |_pk, contract| contract.collection_id.clone().unwrap_or_default(),
+                "created_contracts",
+                "created_contracts__collection_id",
+            ),
+        };
+        Registry {
+            contracts: IndexedMap::new("created_contracts", indexes),
+            pending: Item::new("pending_contract"),
+            created_at: Map::new("created_contracts_by_time"),
+        }
+    }
+
+    /// Wraps the instantiate message in a sub message that is registered on reply
+    pub fn instantiate_msg(
+        &self,
+        storage: &mut dyn Storage,
+        msg: WasmMsg,
+        pending: PendingContract,
+    ) -> StdResult<SubMsg> {
+        self.pending.save(storage, &pending)?;
+        Ok(SubMsg::reply_on_success(msg, CREATE_CONTRACT_REPLY_ID))
+    }
+
+    /// Registers the contract instantiated by the pending creation
+    pub fn register(
+        &self,
+        storage: &mut dyn Storage,
+        reply: Reply,
+        created_at: Timestamp,
+    ) -> StdResult<CreatedContract> {
+        let res = parse_reply_instantiate_data(reply)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+        let pending = self.pending.load(storage)?;
+        self.pending.remove(storage);
+        let contract = CreatedContract {
+            address: Addr::unchecked(res.contract_address),
+            creator: pending.creator,
+            code_id: pending.code_id,
+            contract_type: pending.contract_type,
+            collection_id: pending.collection_id,
+            created_at,
+            factory_admin: pending.factory_admin,
+        };
+        self.save(storage, &contract)?;
+        Ok(contract)
+    }
+
+    /// Saves the contract and counts it at its creation time
+    pub fn save(&self, storage: &mut dyn Storage, contract: &CreatedContract) -> StdResult<()> {
+        self.contracts.save(storage, &contract.address, contract)?;
+        self.created_at.update(
+            storage,
+            (&contract.creator, contract.created_at.nanos()),
+            |count| -> StdResult<u32> { Ok(count.unwrap_or_default() + 1) },
+        )?;
+        Ok(())
+    }
+
+    /// Returns the contract if it was created by the factory
+    pub fn load(
+        &self,
+        storage: &dyn Storage,
+        address: &Addr,
+    ) -> StdResult<Option<CreatedContract>> {
+        self.contracts.may_load(storage, address)
+    }
+
+    pub fn by_creator(
+        &self,
+        storage: &dyn Storage,
+        creator: Addr,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<CreatedContract>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+        self.contracts
+            .idx
+            .creator
+            .prefix(creator)
+            .range(storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| item.map(|(_, contract)| contract))
+            .collect()
+    }
+
+    /// Updates the code id of the contract after a migration by the factory
+    pub fn set_code_id(
+        &self,
+        storage: &mut dyn Storage,
+        address: &Addr,
+        code_id: u64,
+    ) -> StdResult<()> {
+        let mut contract = self.contracts.load(storage, address)?;
+        contract.code_id = code_id;
+        self.contracts.save(storage, address, &contract)
+    }
+
+    /// Returns the number of contracts the creator created after the time
+    /// Only the creation times after it are read, not every contract of the creator
+    pub fn count_created_after(
+        &self,
+        storage: &dyn Storage,
+        creator: Addr,
+        after: Timestamp,
+    ) -> StdResult<u32> {
+        self.created_at
+            .prefix(&creator)
+            .range(
+                storage,
+                Some(Bound::exclusive(after.nanos())),
+                None,
+                Order::Ascending,
+            )
+            .map(|item| item.map(|(_, count)| count))
+            .sum()
+    }
+
+    pub fn by_type(
+        &self,
+        storage: &dyn Storage,
+        contract_type: ContractType,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<CreatedContract>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+        self.contracts
+            .idx
+            .contract_type
+            .prefix(contract_type.as_str().to_string())
+            .range(storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| item.map(|(_, contract)| contract))
+            .collect()
+    }
+
+    pub fn by_collection_id(
+        &self,
+        storage: &dyn Storage,
+        collection_id: String,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<CreatedContract>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+        self.contracts
+            .idx
+            .collection_id
+            .prefix(collection_id)
+            .range(storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| item.map(|(_, contract)| contract))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    fn save_contract(
+        storage: &mut dyn Storage,
+        address: &str,
+        creator: &str,
+        contract_type: ContractType,
+        collection_id: Option<&str>,
+    ) {
+        let contract = CreatedContract {
+            address: Addr::unchecked(address),
+            creator: Addr::unchecked(creator),
+            code_id: 1,
+            contract_type,
+            collection_id: collection_id.map(|id| id.to_string()),
+            created_at: Timestamp::from_nanos(1_000),
+            factory_admin: false,
+        };
+        Registry::new().save(storage, &contract).unwrap();
+    }
+
+    #[test]
+    fn test_registry_queries() {
+        let mut deps = mock_dependencies();
+        let registry = Registry::new();
+        let creator = Addr::unchecked("creator");
+        save_contract(
+            &mut deps.storage,
+            "minter_1",
+            "creator",
+            ContractType::Minter,
+            Some("id_1"),
+        );
+        save_contract(
+            &mut deps.storage,
+            "minter_2",
+            "creator",
+            ContractType::OpenEditionMinter,
+            Some("id_2"),
+        );
+        save_contract(
+            &mut deps.storage,
+            "minter_3",
+            "other_creator",
+            ContractType::Minter,
+            Some("id_1"),
+        );
+
+        let contracts = registry
+            .by_creator(&deps.storage, creator.clone(), None, None)
+            .unwrap();
+        assert_eq!(contracts.len(), 2);
+
+        // Paginate with the address of the last contract
+        let contracts = registry
+            .by_creator(&deps.storage, creator, None, Some(1))
+            .unwrap();
+        assert_eq!(contracts[0].address, Addr::unchecked("minter_1"));
+        let start_after = Some(contracts[0].address.clone());
+        let contracts = registry
+            .by_creator(&deps.storage, Addr::unchecked("creator"), start_after, None)
+            .unwrap();
+        assert_eq!(contracts.len(), 1);
+        assert_eq!(contracts[0].address, Addr::unchecked("minter_2"));
+
+        let contracts = registry
+            .by_type(&deps.storage, ContractType::Minter, None, None)
+            .unwrap();
+        assert_eq!(contracts.len(), 2);
+
+        let contracts = registry
+            .by_collection_id(&deps.storage, "id_1".to_string(), None, None)
+            .unwrap();
+        assert_eq!(contracts.len(), 2);
+
+        let contract = registry
+            .load(&deps.storage, &Addr::unchecked("minter_3"))
+            .unwrap();
+        assert_eq!(contract.unwrap().creator, Addr::unchecked("other_creator"));
+        let contract = registry
+            .load(&deps.storage, &Addr::unchecked("unknown"))
+            .unwrap();
+        assert!(contract.is_none());
+
+        // Contracts created at the time are not counted
+        let creator = Addr::unchecked("creator");
+        let count = registry
+            .count_created_after(&deps.storage, creator.clone(), Timestamp::from_nanos(999))
+            .unwrap();
+        assert_eq!(count, 2);
+        let count = registry
+            .count_created_after(&deps.storage, creator, Timestamp::from_nanos(1_000))
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+}
@@ -0,0 +1,85 @@
+use cosmwasm_std::{Addr, Empty, StdResult, Storage};
+use cw_storage_plus::Map;
+
+/// Creators who let the factory admin migrate the contracts they create
+/// Contracts of other creators keep the admin of the contract as their wasm admin
+pub struct ManagedUpgrades<'a> {
+    pub creators: Map<'a, &'a Addr, Empty>,
+}
+
+impl<'a> Default for ManagedUpgrades<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> ManagedUpgrades<'a> {
+    pub fn new() -> Self {
+        ManagedUpgrades {
+            creators: Map::new("managed_upgrades"),
+        }
+    }
+
+    /// Opts the creator in or out, contracts created before keep their wasm admin
+    pub fn set(&self, storage: &mut dyn Storage, creator: &Addr, enabled: bool) -> StdResult<()> {
+        if enabled {
+            self.creators.save(storage, creator, &Empty {})
+        } else {
+            self.creators.remove(storage, creator);
+            Ok(())
+        }
+    }
+
+    pub fn is_enabled(&self, storage: &dyn Storage, creator: &Addr) -> bool {
+        self.creators.has(storage, creator)
+    }
+
+    /// Returns the wasm admin of a contract created by the creator and whether it is the factory
+    /// Factory is the wasm admin only if the creator opted in
+    pub fn wasm_admin(
+        &self,
+        storage: &dyn Storage,
+        creator: &Addr,
+        factory: &Addr,
+        admin: &str,
+    ) -> (String, bool) {
+        if self.is_enabled(storage, creator) {
+            (factory.to_string(), true)
+        } else {
+            (admin.to_string(), false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn test_managed_upgrades() {
+        let mut deps = mock_dependencies();
+        let upgrades = ManagedUpgrades::new();
+        let creator = Addr::unchecked("creator");
+        let factory = Addr::unchecked("factory");
+
+        // Admin of the contract is the wasm admin by default
+        assert!(!upgrades.is_enabled(&deps.storage, &creator));
+        assert_eq!(
+            upgrades.wasm_admin(&deps.storage, &creator, &factory, "admin"),
+            ("admin".to_string(), false)
+        );
+
+        upgrades.set(&mut deps.storage, &creator, true).unwrap();
+        assert_eq!(
+            upgrades.wasm_admin(&deps.storage, &creator, &factory, "admin"),
+            ("factory".to_string(), true)
+        );
+
+        upgrades.set(&mut deps.storage, &creator, false).unwrap();
+        assert_eq!(
+            upgrades.wasm_admin(&deps.storage, &creator, &factory, "admin"),
+            ("admin".to_string(), false)
+        );
+    }
+}
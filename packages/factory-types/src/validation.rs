@@ -0,0 +1,98 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Coin;
+use cw_utils::NativeBalance;
+
+/// Result of checking a creation message without creating the contract
+#[cw_serde]
+pub struct ValidationResponse {
+    pub valid: bool,
+    // Every problem the creation would fail with, empty if the message is valid
+    pub errors: Vec<String>,
+    // Funds the creation message must be sent with
+    pub required_funds: Vec<Coin>,
+}
+
+/// Collects every problem of a creation message instead of stopping at the first one
+#[derive(Default)]
+pub struct Validation {
+    errors: Vec<String>,
+    required_funds: NativeBalance,
+}
+
+impl Validation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the error of a failed check, returns the value of a passed one
+    pub fn check<T, E: ToString>(&mut self, result: Result<T, E>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.errors.push(err.to_string());
+                None
+            }
+        }
+    }
+
+    pub fn error(&mut self, err: impl ToString) {
+        self.errors.push(err.to_string());
+    }
+
+    pub fn require_funds(&mut self, funds: Coin) {
+        self.required_funds += funds;
+    }
+
+    pub fn into_response(self) -> ValidationResponse {
+        // Funds are normalized the way check_payment compares them
+        let mut required_funds = self.required_funds;
+        required_funds.normalize();
+        ValidationResponse {
+            valid: self.errors.is_empty(),
+            errors: self.errors,
+            required_funds: required_funds.into_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_payment, CustomPaymentError};
+    use cosmwasm_std::{coin, StdError};
+
+    #[test]
+    fn test_validation() {
+        let mut validation = Validation::new();
+        assert_eq!(validation.check(Ok::<u32, StdError>(1)), Some(1));
+        validation.require_funds(coin(1000000, "uflix"));
+        validation.require_funds(coin(0, "uatom"));
+        validation.require_funds(coin(1000000, "uflix"));
+        let res = validation.into_response();
+        assert!(res.valid);
+        // Zero fees are dropped and fees of the same denom are merged
+        assert_eq!(res.required_funds, vec![coin(2000000, "uflix")]);
+        check_payment(
+            &res.required_funds,
+            &[coin(1000000, "uflix"), coin(1000000, "uflix")],
+        )
+        .unwrap();
+
+        let mut validation = Validation::new();
+        let value: Option<u32> = validation.check(Err(CustomPaymentError::InsufficientFunds {
+            expected: vec![],
+            actual: vec![],
+        }));
+        assert_eq!(value, None);
+        validation.error(StdError::generic_err("invalid"));
+        let res = validation.into_response();
+        assert!(!res.valid);
+        assert_eq!(
+            res.errors,
+            vec![
+                "Insufficient funds sent".to_string(),
+                "Generic error: invalid".to_string(),
+            ]
+        );
+    }
+}
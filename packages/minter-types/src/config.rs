@@ -1,5 +1,6 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Coin, Timestamp};
+use cosmwasm_std::{attr, Addr, Attribute, Coin, Timestamp};
+use serde::{Deserialize, Deserializer};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -16,6 +17,25 @@ pub enum ConfigurationError {
     InvalidWhitelistAddress {},
     #[error("Invalid number of tokens")]
     InvalidNumberOfTokens {},
+    #[error("Start time can not be changed after minting has started")]
+    StartTimeLocked {},
+    #[error("End time can only be extended after minting has started")]
+    EndTimeNotExtended {},
+    #[error("Per address limit can only be increased after minting has started")]
+    PerAddressLimitNotIncreased {},
+    #[error("Sale config can not be changed after the sale has ended")]
+    SaleEnded {},
+}
+
+/// Deserializes a present field as `Some`, even when it is `null`.
+/// With `#[serde(default)]` a missing field stays `None`, so messages can tell
+/// "keep the current value" (missing) apart from "clear the value" (`null`).
+pub fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
 }
 
 #[cw_serde]
@@ -48,4 +68,100 @@ impl Config {
         }
         Ok(())
     }
+
+    /// Returns the config with the new sale schedule and limits applied.
+    /// `Some(None)` clears the end time or the per address limit.
+    /// Once minting has started start time is locked, end time can only be extended or cleared
+    /// and per address limit can only be increased or cleared.
+    /// Nothing can be changed after the sale has ended.
+    pub fn update_sale_config(
+        &self,
+        now: Timestamp,
+        start_time: Option<Timestamp>,
+        end_time: Option<Option<Timestamp>>,
+        per_address_limit: Option<Option<u32>>,
+    ) -> Result<Config, ConfigurationError> {
+        if let Some(current_end_time) = self.end_time {
+            if now > current_end_time {
+                return Err(ConfigurationError::SaleEnded {});
+            }
+        }
+        let minting_started = now >= self.start_time;
+        let mut config = self.clone();
+        if let Some(start_time) = start_time {
+            if minting_started && start_time != self.start_time {
+                return Err(ConfigurationError::StartTimeLocked {});
+            }
+            config.start_time = start_time;
+        }
+        if let Some(end_time) = end_time {
+            if minting_started {
+                // An open ended sale can not be given an end time once it has started
+                match (self.end_time, end_time) {
+                    (_, None) => {}
+                    (Some(current_end_time), Some(end_time)) if end_time >= current_end_time => {}
+                    _ => return Err(ConfigurationError::EndTimeNotExtended {}),
+                }
+            }
+            config.end_time = end_time;
+        }
+        if let Some(per_address_limit) = per_address_limit {
+            if minting_started {
+                match (self.per_address_limit, per_address_limit) {
+                    (_, None) => {}
+                    (Some(current_limit), Some(limit)) if limit >= current_limit => {}
+                    _ => return Err(ConfigurationError::PerAddressLimitNotIncreased {}),
+                }
+            }
+            config.per_address_limit = per_address_limit;
+        }
+        // Start time is already in the past once minting has started
+        let integrity_time = if minting_started {
+            config.start_time
+        } else {
+            now
+        };
+        config.check_integrity(integrity_time)?;
+        Ok(config)
+    }
+
+    pub fn sale_attributes(&self) -> Vec<Attribute> {
+        vec![
+            attr("start_time", self.start_time.to_string()),
+            attr("end_time", self.end_time.map_or("none".to_string(), |t| t.to_string())),
+            attr(
+                "per_address_limit",
+                self.per_address_limit.map_or("none".to_string(), |limit| limit.to_string()),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cw_serde]
+    struct UpdateMsg {
+        #[serde(
+            default,
+            deserialize_with = "deserialize_some",
+            skip_serializing_if = "Option::is_none"
+        )]
+        end_time: Option<Option<Timestamp>>,
+    }
+
+    #[test]
+    fn test_deserialize_some() {
+        let msg: UpdateMsg = serde_json::from_str("{}").unwrap();
+        assert_eq!(msg.end_time, None);
+        let msg: UpdateMsg = serde_json::from_str(r#"{"end_time":null}"#).unwrap();
+        assert_eq!(msg.end_time, Some(None));
+        let msg: UpdateMsg = serde_json::from_str(r#"{"end_time":"10"}"#).unwrap();
+        assert_eq!(msg.end_time, Some(Some(Timestamp::from_nanos(10))));
+
+        // Missing values round trip as missing
+        let json = serde_json::to_string(&UpdateMsg { end_time: None }).unwrap();
+        assert_eq!(json, "{}");
+    }
 }
@@ -1,6 +1,8 @@
 pub mod collection_details;
 pub mod config;
 pub mod msg;
+pub mod roles;
+pub mod timelock;
 pub mod token_details;
 pub mod types;
 pub mod utils;
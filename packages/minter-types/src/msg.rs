@@ -1,11 +1,15 @@
 use crate::{
     collection_details::CollectionDetails,
     config::Config,
+    roles::Role,
+    timelock::PendingChange,
     token_details::TokenDetails,
     types::{AuthDetails, UserDetails},
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Addr;
+use cw_ownable::cw_ownable_query;
+use pauser::halt::HaltStatus;
 
 #[cw_serde]
 pub struct MinterInstantiateMsg<T> {
@@ -13,8 +17,11 @@ pub struct MinterInstantiateMsg<T> {
     pub token_details: Option<TokenDetails>,
     pub auth_details: AuthDetails,
     pub init: Option<T>,
+    // Timelock delay in seconds, defaults to one day and 0 disables the timelock
+    pub timelock_duration: Option<u64>,
 }
 
+#[cw_ownable_query]
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg<T> {
@@ -32,12 +39,30 @@ pub enum QueryMsg<T> {
     IsPaused {},
     #[returns(Vec<Addr>)]
     Pausers {},
+    // Returns whether the minter is paused by its pausers or halted by its factory
+    #[returns(HaltStatus)]
+    HaltStatus {},
     #[returns(u32)]
     Extension(T),
     #[returns(u32)]
     TotalMintedCount {},
     #[returns(MintHistoryResponse)]
     MintHistory { address: String },
+    #[returns(Vec<Addr>)]
+    RoleMembers {
+        role: Role,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<Role>)]
+    AddressRoles { address: String },
+    #[returns(u64)]
+    TimelockDuration {},
+    #[returns(Vec<PendingChange>)]
+    PendingChanges {
+        start_after: Option<u32>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
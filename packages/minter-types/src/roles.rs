@@ -0,0 +1,181 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Empty, Order, StdResult, Storage};
+use cw_storage_plus::{Bound, Map};
+
+pub const ROLES_KEY: &str = "roles";
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
+/// Roles that can be delegated by the admin of a minter.
+/// Admin is always authorized for every role.
+#[cw_serde]
+#[derive(Copy)]
+pub enum Role {
+    /// Can update the denom and pause/unpause the minter
+    Ops,
+    /// Can update the payment collector and mint prices
+    Finance,
+    /// Can mint tokens with `MintAdmin`
+    Airdrop,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Ops => "ops",
+            Role::Finance => "finance",
+            Role::Airdrop => "airdrop",
+        }
+    }
+
+    pub fn all() -> Vec<Role> {
+        vec![Role::Ops, Role::Finance, Role::Airdrop]
+    }
+}
+
+pub struct RoleRegistry<'a> {
+    pub members: Map<'a, (&'a str, &'a Addr), Empty>,
+}
+
+impl<'a> RoleRegistry<'a> {
+    pub fn new() -> Self {
+        RoleRegistry {
+            members: Map::new(ROLES_KEY),
+        }
+    }
+
+    pub fn grant(&self, storage: &mut dyn Storage, role: Role, address: &Addr) -> StdResult<()> {
+        self.members.save(storage, (role.as_str(), address), &Empty {})
+    }
+
+    pub fn revoke(&self, storage: &mut dyn Storage, role: Role, address: &Addr) {
+        self.members.remove(storage, (role.as_str(), address));
+    }
+
+    pub fn has_role(&self, storage: &dyn Storage, role: Role, address: &Addr) -> bool {
+        self.members.has(storage, (role.as_str(), address))
+    }
+
+    /// Returns true if the address is the admin or holds the given role
+    pub fn is_authorized(
+        &self,
+        storage: &dyn Storage,
+        role: Role,
+        admin: &Addr,
+        address: &Addr,
+    ) -> bool {
+        address == admin || self.has_role(storage, role, address)
+    }
+
+    pub fn role_members(
+        &self,
+        storage: &dyn Storage,
+        role: Role,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<Addr>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.as_ref().map(Bound::exclusive);
+        self.members
+            .prefix(role.as_str())
+            .keys(storage, start, None, Order::Ascending)
+            .take(limit)
+            .collect()
+    }
+
+    /// Revokes every role, delegates of a previous owner are not kept by the next one
+    pub fn clear(&self, storage: &mut dyn Storage) -> StdResult<()> {
+        let keys = self
+            .members
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (role, address) in keys {
+            self.members.remove(storage, (role.as_str(), &address));
+        }
+        Ok(())
+    }
+
+    pub fn address_roles(&self, storage: &dyn Storage, address: &Addr) -> Vec<Role> {
+        Role::all()
+            .into_iter()
+            .filter(|role| self.has_role(storage, *role, address))
+            .collect()
+    }
+}
+
+impl<'a> Default for RoleRegistry<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn test_role_registry() {
+        let mut deps = mock_dependencies();
+        let registry = RoleRegistry::new();
+        let admin = Addr::unchecked("admin");
+        let ops = Addr::unchecked("ops");
+        let finance = Addr::unchecked("finance");
+
+        registry.grant(&mut deps.storage, Role::Ops, &ops).unwrap();
+        registry
+            .grant(&mut deps.storage, Role::Finance, &finance)
+            .unwrap();
+
+        // Admin is authorized for every role
+        for role in Role::all() {
+            assert!(registry.is_authorized(&deps.storage, role, &admin, &admin));
+        }
+        assert!(registry.is_authorized(&deps.storage, Role::Ops, &admin, &ops));
+        assert!(!registry.is_authorized(&deps.storage, Role::Finance, &admin, &ops));
+        assert!(!registry.is_authorized(&deps.storage, Role::Airdrop, &admin, &finance));
+
+        assert_eq!(
+            registry
+                .role_members(&deps.storage, Role::Ops, None, None)
+                .unwrap(),
+            vec![ops.clone()]
+        );
+        assert_eq!(
+            registry.address_roles(&deps.storage, &finance),
+            vec![Role::Finance]
+        );
+
+        // Revoke ops role
+        registry.revoke(&mut deps.storage, Role::Ops, &ops);
+        assert!(!registry.has_role(&deps.storage, Role::Ops, &ops));
+        assert_eq!(
+            registry
+                .role_members(&deps.storage, Role::Ops, None, None)
+                .unwrap(),
+            Vec::<Addr>::new()
+        );
+
+        // Members are paginated by address
+        let members: Vec<Addr> = (0..3)
+            .map(|i| Addr::unchecked(format!("airdrop{}", i)))
+            .collect();
+        for member in &members {
+            registry
+                .grant(&mut deps.storage, Role::Airdrop, member)
+                .unwrap();
+        }
+        assert_eq!(
+            registry
+                .role_members(&deps.storage, Role::Airdrop, None, Some(2))
+                .unwrap(),
+            members[..2].to_vec()
+        );
+        assert_eq!(
+            registry
+                .role_members(&deps.storage, Role::Airdrop, Some(members[1].clone()), None)
+                .unwrap(),
+            members[2..].to_vec()
+        );
+    }
+}
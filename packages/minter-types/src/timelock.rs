@@ -0,0 +1,303 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    attr, Addr, Attribute, Coin, Decimal, Order, StdError, StdResult, Storage, Timestamp,
+};
+use cw_storage_plus::{Bound, Item, Map};
+use thiserror::Error;
+
+use crate::roles::{Role, RoleRegistry};
+
+pub const TIMELOCK_DURATION_KEY: &str = "timelock_duration";
+pub const PENDING_CHANGES_KEY: &str = "pending_changes";
+pub const LAST_PENDING_CHANGE_ID_KEY: &str = "last_pending_change_id";
+// One day, used when the creator does not set a duration
+pub const DEFAULT_TIMELOCK_DURATION: u64 = 86_400;
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum TimelockError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error("Pending change not found")]
+    PendingChangeNotFound {},
+
+    #[error("Pending change can not be executed before {effective_time}")]
+    PendingChangeNotReady { effective_time: Timestamp },
+
+    #[error("Timelock duration can not be decreased after minting has started")]
+    TimelockDurationDecreased {},
+}
+
+/// Sensitive configuration changes that are delayed by the timelock once minting has started
+#[cw_serde]
+pub enum ConfigChange {
+    MintPrice { mint_price: Coin },
+    PaymentCollector { payment_collector: Addr },
+    RoyaltyRatio { ratio: Decimal },
+}
+
+impl ConfigChange {
+    /// Role which can manage the change besides the admin
+    pub fn role(&self) -> Option<Role> {
+        match self {
+            ConfigChange::MintPrice { .. } | ConfigChange::PaymentCollector { .. } => {
+                Some(Role::Finance)
+            }
+            ConfigChange::RoyaltyRatio { .. } => None,
+        }
+    }
+
+    /// Returns true if the address can queue, execute or cancel the change
+    pub fn is_authorized(&self, storage: &dyn Storage, admin: &Addr, address: &Addr) -> bool {
+        match self.role() {
+            Some(role) => RoleRegistry::new().is_authorized(storage, role, admin, address),
+            None => address == admin,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct PendingChange {
+    pub id: u32,
+    pub change: ConfigChange,
+    // Only used by minters with multiple mint instances
+    pub mint_instance_id: Option<u32>,
+    pub queued_at: Timestamp,
+    pub effective_time: Timestamp,
+}
+
+impl PendingChange {
+    pub fn attributes(&self) -> Vec<Attribute> {
+        vec![
+            attr("change_id", self.id.to_string()),
+            attr("effective_time", self.effective_time.to_string()),
+        ]
+    }
+}
+
+pub struct Timelock<'a> {
+    // Delay in seconds, 0 means timelock is disabled
+    pub duration: Item<'a, u64>,
+    pub pending_changes: Map<'a, u32, PendingChange>,
+    pub last_id: Item<'a, u32>,
+}
+
+impl<'a> Timelock<'a> {
+    pub fn new() -> Self {
+        Timelock {
+            duration: Item::new(TIMELOCK_DURATION_KEY),
+            pending_changes: Map::new(PENDING_CHANGES_KEY),
+            last_id: Item::new(LAST_PENDING_CHANGE_ID_KEY),
+        }
+    }
+
+    pub fn duration(&self, storage: &dyn Storage) -> StdResult<u64> {
+        Ok(self
+            .duration
+            .may_load(storage)?
+            .unwrap_or(DEFAULT_TIMELOCK_DURATION))
+    }
+
+    /// Changes are timelocked only if a duration is set and minting has started
+    pub fn is_active(
+        &self,
+        storage: &dyn Storage,
+        now: Timestamp,
+        start_time: Timestamp,
+    ) -> StdResult<bool> {
+        Ok(self.duration(storage)? > 0 && now >= start_time)
+    }
+
+    /// Once minting has started duration can only be increased
+    pub fn set_duration(
+        &self,
+        storage: &mut dyn Storage,
+        duration: u64,
+        minting_started: bool,
+    ) -> Result<(), TimelockError> {
+        if minting_started && duration < self.duration(storage)? {
+            return Err(TimelockError::TimelockDurationDecreased {});
+        }
+        self.duration.save(storage, &duration)?;
+        Ok(())
+    }
+
+    pub fn queue(
+        &self,
+        storage: &mut dyn Storage,
+        now: Timestamp,
+        change: ConfigChange,
+        mint_instance_id: Option<u32>,
+    ) -> StdResult<PendingChange> {
+        let id = self.last_id.may_load(storage)?.unwrap_or(0) + 1;
+        let pending_change = PendingChange {
+            id,
+            change,
+            mint_instance_id,
+            queued_at: now,
+            effective_time: now.plus_seconds(self.duration(storage)?),
+        };
+        self.pending_changes.save(storage, id, &pending_change)?;
+        self.last_id.save(storage, &id)?;
+        Ok(pending_change)
+    }
+
+    pub fn load(&self, storage: &dyn Storage, id: u32) -> Result<PendingChange, TimelockError> {
+        self.pending_changes
+            .may_load(storage, id)?
+            .ok_or(TimelockError::PendingChangeNotFound {})
+    }
+
+    /// Removes the change from the queue if its effective time has passed
+    pub fn take_ready(
+        &self,
+        storage: &mut dyn Storage,
+        id: u32,
+        now: Timestamp,
+    ) -> Result<PendingChange, TimelockError> {
+        let pending_change = self.load(storage, id)?;
+        if now < pending_change.effective_time {
+            return Err(TimelockError::PendingChangeNotReady {
+                effective_time: pending_change.effective_time,
+            });
+        }
+        self.pending_changes.remove(storage, id);
+        Ok(pending_change)
+    }
+
+    pub fn cancel(
+        &self,
+        storage: &mut dyn Storage,
+        id: u32,
+    ) -> Result<PendingChange, TimelockError> {
+        let pending_change = self.load(storage, id)?;
+        self.pending_changes.remove(storage, id);
+        Ok(pending_change)
+    }
+
+    pub fn pending_changes(
+        &self,
+        storage: &dyn Storage,
+        start_after: Option<u32>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<PendingChange>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+        self.pending_changes
+            .range(storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| item.map(|(_, pending_change)| pending_change))
+            .collect()
+    }
+}
+
+impl<'a> Default for Timelock<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn test_timelock() {
+        let mut deps = mock_dependencies();
+        let timelock = Timelock::new();
+        let start_time = Timestamp::from_seconds(100);
+
+        // Enabled by default
+        assert_eq!(
+            timelock.duration(&deps.storage).unwrap(),
+            DEFAULT_TIMELOCK_DURATION
+        );
+        assert!(timelock
+            .is_active(&deps.storage, Timestamp::from_seconds(200), start_time)
+            .unwrap());
+
+        // Creator can disable it before minting starts
+        timelock.set_duration(&mut deps.storage, 0, false).unwrap();
+        assert!(!timelock
+            .is_active(&deps.storage, Timestamp::from_seconds(200), start_time)
+            .unwrap());
+
+        timelock.set_duration(&mut deps.storage, 50, false).unwrap();
+        assert!(!timelock
+            .is_active(&deps.storage, Timestamp::from_seconds(50), start_time)
+            .unwrap());
+        assert!(timelock
+            .is_active(&deps.storage, Timestamp::from_seconds(100), start_time)
+            .unwrap());
+
+        // Duration can not be decreased after minting has started
+        assert_eq!(
+            timelock.set_duration(&mut deps.storage, 10, true),
+            Err(TimelockError::TimelockDurationDecreased {})
+        );
+
+        let pending_change = timelock
+            .queue(
+                &mut deps.storage,
+                Timestamp::from_seconds(200),
+                ConfigChange::RoyaltyRatio {
+                    ratio: Decimal::percent(10),
+                },
+                None,
+            )
+            .unwrap();
+        assert_eq!(pending_change.id, 1);
+        assert_eq!(pending_change.effective_time, Timestamp::from_seconds(250));
+
+        // Can not be executed before effective time
+        assert_eq!(
+            timelock.take_ready(&mut deps.storage, 1, Timestamp::from_seconds(249)),
+            Err(TimelockError::PendingChangeNotReady {
+                effective_time: Timestamp::from_seconds(250)
+            })
+        );
+        assert_eq!(
+            timelock.take_ready(&mut deps.storage, 1, Timestamp::from_seconds(250)),
+            Ok(pending_change)
+        );
+        assert_eq!(
+            timelock.cancel(&mut deps.storage, 1),
+            Err(TimelockError::PendingChangeNotFound {})
+        );
+        assert_eq!(
+            timelock.pending_changes(&deps.storage, None, None).unwrap(),
+            vec![]
+        );
+
+        // Pending changes are paginated by id
+        let pending_changes: Vec<PendingChange> = (1..=3)
+            .map(|percent| {
+                timelock
+                    .queue(
+                        &mut deps.storage,
+                        Timestamp::from_seconds(300),
+                        ConfigChange::RoyaltyRatio {
+                            ratio: Decimal::percent(percent),
+                        },
+                        None,
+                    )
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(
+            timelock
+                .pending_changes(&deps.storage, None, Some(2))
+                .unwrap(),
+            pending_changes[..2].to_vec()
+        );
+        assert_eq!(
+            timelock
+                .pending_changes(&deps.storage, Some(pending_changes[1].id), None)
+                .unwrap(),
+            pending_changes[2..].to_vec()
+        );
+    }
+}
@@ -24,8 +24,16 @@ pub enum TokenDetailsError {
     TokenNameTooShort {},
     #[error("Data too long")]
     DataTooLong {},
+    #[error("Invalid metadata template")]
+    InvalidMetadataTemplate {},
+    #[error("Metadata template too long")]
+    MetadataTemplateTooLong {},
 }
 
+/// Placeholders supported by metadata templates
+pub const TEMPLATE_PLACEHOLDERS: [&str; 4] = ["id", "edition", "instance", "supply"];
+const MAX_TEMPLATE_PADDING: usize = 32;
+
 #[cw_serde]
 pub struct TokenDetails {
     // Name of each individual token
@@ -42,6 +50,8 @@ pub struct TokenDetails {
     pub base_token_uri: String,
     // Preview_uri is used for the preview of the token. If provided, it will be used as the preview_uri+token_id
     pub preview_uri: Option<String>,
+    // Optional templates overriding the default token name and uris
+    pub metadata_template: Option<MetadataTemplate>,
 }
 impl TokenDetails {
     pub fn check_integrity(&self) -> Result<(), TokenDetailsError> {
@@ -80,10 +90,121 @@ impl TokenDetails {
                 return Err(TokenDetailsError::DataTooLong {});
             }
         }
+        if let Some(metadata_template) = &self.metadata_template {
+            metadata_template.check_integrity()?;
+        }
         Ok(())
     }
 }
 
+/// Templates used to build the metadata of each minted token.
+/// Supported placeholders are `{id}`, `{edition}`, `{instance}` and `{supply}`.
+/// Placeholders can be zero padded by giving a width, `{id:4}` renders token 12 as `0012`.
+/// `{instance}` and `{supply}` render empty if the minter has no mint instances or no supply limit.
+#[cw_serde]
+#[derive(Default)]
+pub struct MetadataTemplate {
+    // FE: "Baby Tardigrade #{edition}/{supply}"
+    pub name: Option<String>,
+    // FE: "ipfs://cid/{id:4}.json"
+    pub media_uri: Option<String>,
+    pub preview_uri: Option<String>,
+}
+
+impl MetadataTemplate {
+    pub fn check_integrity(&self) -> Result<(), TokenDetailsError> {
+        for template in [&self.name, &self.media_uri, &self.preview_uri]
+            .into_iter()
+            .flatten()
+        {
+            if template.chars().count() > 256 {
+                return Err(TokenDetailsError::MetadataTemplateTooLong {});
+            }
+            parse_template(template)?;
+        }
+        Ok(())
+    }
+}
+
+/// Values used to fill the placeholders of a metadata template
+pub struct TemplateValues {
+    pub id: String,
+    pub edition: String,
+    pub instance: Option<String>,
+    pub supply: Option<u32>,
+}
+
+impl TemplateValues {
+    fn value(&self, placeholder: &str) -> String {
+        match placeholder {
+            "id" => self.id.clone(),
+            "edition" => self.edition.clone(),
+            "instance" => self.instance.clone().unwrap_or_default(),
+            "supply" => self.supply.map(|supply| supply.to_string()).unwrap_or_default(),
+            _ => String::new(),
+        }
+    }
+}
+
+enum TemplateSegment<'a> {
+    Text(&'a str),
+    Placeholder { name: &'a str, width: usize },
+}
+
+fn parse_template(template: &str) -> Result<Vec<TemplateSegment>, TokenDetailsError> {
+    let mut segments = vec![];
+    let mut rest = template;
+    while let Some(open) = rest.find(['{', '}']) {
+        // Closing brace without an opening one
+        if rest[open..].starts_with('}') {
+            return Err(TokenDetailsError::InvalidMetadataTemplate {});
+        }
+        if open > 0 {
+            segments.push(TemplateSegment::Text(&rest[..open]));
+        }
+        let after_open = &rest[open + 1..];
+        let close = after_open
+            .find('}')
+            .ok_or(TokenDetailsError::InvalidMetadataTemplate {})?;
+        let placeholder = &after_open[..close];
+        let (name, width) = match placeholder.split_once(':') {
+            Some((name, width)) => (
+                name,
+                width
+                    .parse::<usize>()
+                    .map_err(|_| TokenDetailsError::InvalidMetadataTemplate {})?,
+            ),
+            None => (placeholder, 0),
+        };
+        if !TEMPLATE_PLACEHOLDERS.contains(&name) || width > MAX_TEMPLATE_PADDING {
+            return Err(TokenDetailsError::InvalidMetadataTemplate {});
+        }
+        segments.push(TemplateSegment::Placeholder { name, width });
+        rest = &after_open[close + 1..];
+    }
+    if !rest.is_empty() {
+        segments.push(TemplateSegment::Text(rest));
+    }
+    Ok(segments)
+}
+
+/// Fills the placeholders of a template. Templates are validated before they are stored,
+/// an invalid template is returned as is.
+pub fn render_template(template: &str, values: &TemplateValues) -> String {
+    match parse_template(template) {
+        Ok(segments) => segments
+            .into_iter()
+            .map(|segment| match segment {
+                TemplateSegment::Text(text) => text.to_string(),
+                TemplateSegment::Placeholder { name, width } => {
+                    format!("{:0>width$}", values.value(name), width = width)
+                }
+            })
+            .collect(),
+        Err(_) => template.to_string(),
+    }
+}
+
 #[cw_serde]
 pub struct Token {
     pub token_id: String,
@@ -100,3 +221,51 @@ pub struct NftData {
     pub creator_token_data: String,
     pub multi_mint_data: Option<MultiMintData>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_template() {
+        let values = TemplateValues {
+            id: "12".to_string(),
+            edition: "3".to_string(),
+            instance: Some("2".to_string()),
+            supply: Some(500),
+        };
+        assert_eq!(render_template("Token #{edition}/{supply}", &values), "Token #3/500");
+        assert_eq!(
+            render_template("ipfs://cid/{instance}/{id:4}.json", &values),
+            "ipfs://cid/2/0012.json"
+        );
+
+        // Missing values render empty
+        let values = TemplateValues {
+            id: "12".to_string(),
+            edition: "12".to_string(),
+            instance: None,
+            supply: None,
+        };
+        assert_eq!(render_template("{instance}#{id}{supply}", &values), "#12");
+
+        let template = MetadataTemplate {
+            name: Some("Token #{edition:3}".to_string()),
+            media_uri: Some("ipfs://cid/{id}.json".to_string()),
+            preview_uri: None,
+        };
+        assert_eq!(template.check_integrity(), Ok(()));
+
+        for invalid in ["{id", "id}", "{name}", "{id:x}", "{id:33}", "{{id}}"] {
+            let template = MetadataTemplate {
+                name: None,
+                media_uri: Some(invalid.to_string()),
+                preview_uri: None,
+            };
+            assert_eq!(
+                template.check_integrity(),
+                Err(TokenDetailsError::InvalidMetadataTemplate {})
+            );
+        }
+    }
+}
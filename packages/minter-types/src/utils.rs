@@ -1,6 +1,6 @@
 use crate::{
     collection_details::CollectionDetails,
-    token_details::{MultiMintData, NftData, TokenDetails},
+    token_details::{render_template, MultiMintData, NftData, TemplateValues, TokenDetails},
 };
 use cosmwasm_std::{Addr, Coin, CosmosMsg, Decimal, QuerierWrapper, StdError, Uint128};
 use omniflix_std::types::omniflix::onft::v1beta1::{
@@ -14,6 +14,7 @@ pub fn generate_minter_mint_message(
     token_id: String,
     minter_address: Addr,
     recipient: Addr,
+    supply: Option<u32>,
 ) -> Result<CosmosMsg, serde_json::Error> {
     let data = NftData {
         creator_token_data: token_details.data.clone().unwrap_or("".to_string()),
@@ -21,17 +22,36 @@ pub fn generate_minter_mint_message(
     };
     let json_data = serde_json::to_string(&data)?;
 
+    let template_values = TemplateValues {
+        id: token_id.clone(),
+        edition: token_id.clone(),
+        instance: None,
+        supply,
+    };
+    let metadata_template = token_details.metadata_template.clone().unwrap_or_default();
     let metadata = Metadata {
-        name: format!("{} #{}", token_details.token_name.clone(), token_id),
+        name: apply_template(
+            metadata_template.name.as_ref(),
+            &template_values,
+            format!("{} #{}", token_details.token_name.clone(), token_id),
+        ),
         description: token_details.description.clone().unwrap_or("".to_string()),
-        media_uri: format!("{}/{}", token_details.base_token_uri.clone(), token_id),
-        preview_uri: format!(
-            "{}/{}",
-            token_details
-                .preview_uri
-                .clone()
-                .unwrap_or(token_details.base_token_uri.clone()),
-            token_id,
+        media_uri: apply_template(
+            metadata_template.media_uri.as_ref(),
+            &template_values,
+            format!("{}/{}", token_details.base_token_uri.clone(), token_id),
+        ),
+        preview_uri: apply_template(
+            metadata_template.preview_uri.as_ref(),
+            &template_values,
+            format!(
+                "{}/{}",
+                token_details
+                    .preview_uri
+                    .clone()
+                    .unwrap_or(token_details.base_token_uri.clone()),
+                token_id,
+            ),
         ),
         uri_hash: collection.uri_hash.clone().unwrap_or("".to_string()),
     };
@@ -57,20 +77,40 @@ pub fn generate_oem_mint_message(
     token_id: String,
     minter_address: Addr,
     recipient: Addr,
+    supply: Option<u32>,
 ) -> Result<MsgMintOnft, serde_json::Error> {
     let data = NftData {
         creator_token_data: token_details.data.clone().unwrap_or("".to_string()),
         multi_mint_data: None,
     };
     let json_data = serde_json::to_string(&data)?;
+    let template_values = TemplateValues {
+        id: token_id.clone(),
+        edition: token_id.clone(),
+        instance: None,
+        supply,
+    };
+    let metadata_template = token_details.metadata_template.clone().unwrap_or_default();
     let metadata = Metadata {
-        name: format!("{} #{}", token_details.token_name.clone(), token_id),
+        name: apply_template(
+            metadata_template.name.as_ref(),
+            &template_values,
+            format!("{} #{}", token_details.token_name.clone(), token_id),
+        ),
         description: token_details.description.clone().unwrap_or("".to_string()),
-        media_uri: token_details.base_token_uri.clone(),
-        preview_uri: token_details
-            .preview_uri
-            .clone()
-            .unwrap_or(token_details.base_token_uri.clone()),
+        media_uri: apply_template(
+            metadata_template.media_uri.as_ref(),
+            &template_values,
+            token_details.base_token_uri.clone(),
+        ),
+        preview_uri: apply_template(
+            metadata_template.preview_uri.as_ref(),
+            &template_values,
+            token_details
+                .preview_uri
+                .clone()
+                .unwrap_or(token_details.base_token_uri.clone()),
+        ),
         uri_hash: collection.uri_hash.clone().unwrap_or("".to_string()),
     };
     Ok(MsgMintOnft {
@@ -87,6 +127,7 @@ pub fn generate_oem_mint_message(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn generate_multi_minter_mint_message(
     collection: &CollectionDetails,
     token_details: &TokenDetails,
@@ -95,7 +136,14 @@ pub fn generate_multi_minter_mint_message(
     recipient: Addr,
     mint_instance_id: String,
     mint_instance_token_id: String,
+    supply: Option<u32>,
 ) -> Result<MsgMintOnft, serde_json::Error> {
+    let template_values = TemplateValues {
+        id: token_id.clone(),
+        edition: mint_instance_token_id.clone(),
+        instance: Some(mint_instance_id.clone()),
+        supply,
+    };
     let data = NftData {
         creator_token_data: token_details.data.clone().unwrap_or("".to_string()),
         multi_mint_data: Some(MultiMintData {
@@ -105,14 +153,27 @@ pub fn generate_multi_minter_mint_message(
         }),
     };
     let json_data = serde_json::to_string(&data)?;
+    let metadata_template = token_details.metadata_template.clone().unwrap_or_default();
     let metadata = Metadata {
-        name: format!("{} #{}", token_details.token_name.clone(), mint_instance_token_id),
+        name: apply_template(
+            metadata_template.name.as_ref(),
+            &template_values,
+            format!("{} #{}", token_details.token_name.clone(), mint_instance_token_id),
+        ),
         description: token_details.description.clone().unwrap_or("".to_string()),
-        media_uri: token_details.base_token_uri.clone(),
-        preview_uri: token_details
-            .preview_uri
-            .clone()
-            .unwrap_or(token_details.base_token_uri.clone()),
+        media_uri: apply_template(
+            metadata_template.media_uri.as_ref(),
+            &template_values,
+            token_details.base_token_uri.clone(),
+        ),
+        preview_uri: apply_template(
+            metadata_template.preview_uri.as_ref(),
+            &template_values,
+            token_details
+                .preview_uri
+                .clone()
+                .unwrap_or(token_details.base_token_uri.clone()),
+        ),
         uri_hash: collection.uri_hash.clone().unwrap_or("".to_string()),
     };
     Ok(MsgMintOnft {
@@ -129,6 +190,15 @@ pub fn generate_multi_minter_mint_message(
     })
 }
 
+// Renders the template if set, otherwise falls back to the default value
+fn apply_template(
+    template: Option<&String>,
+    template_values: &TemplateValues,
+    default: String,
+) -> String {
+    template.map_or(default, |template| render_template(template, template_values))
+}
+
 pub fn generate_create_denom_msg(
     collection: &CollectionDetails,
     minter_address: Addr,
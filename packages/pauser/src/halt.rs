@@ -0,0 +1,203 @@
+use crate::{PauseError, PauseState};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{attr, Addr, Attribute, QuerierWrapper, StdError, StdResult, Storage};
+use cw_storage_plus::Item;
+use thiserror::Error;
+
+pub const GLOBAL_HALT_KEY: &str = "global_halt";
+pub const HALT_FACTORY_KEY: &str = "halt_factory";
+
+#[derive(Error, Debug, PartialEq)]
+pub enum HaltError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Pause(#[from] PauseError),
+
+    #[error("minters are halted by the factory ({factory})")]
+    Halted { factory: Addr },
+
+    #[error("minter was not created by the factory ({factory})")]
+    NotCreatedByFactory { factory: Addr },
+}
+
+/// Query the minters send to their factory, factories answer it with a bool
+#[cw_serde]
+pub enum HaltQueryMsg {
+    GlobalHalt {},
+}
+
+/// Layers that can stop a minter from minting
+#[cw_serde]
+pub struct HaltStatus {
+    // Paused by the pausers of the minter
+    pub paused: bool,
+    // Factory the minter checks, None if it was not created by a factory
+    pub factory: Option<Addr>,
+    // Halted by the pausers of the factory
+    pub global_halt: bool,
+}
+
+/// Halt set in a factory and checked by every minter it created
+pub struct GlobalHalt<'a> {
+    // Saved by the factory
+    pub halted: Item<'a, bool>,
+    // Saved by the minter
+    pub factory: Item<'a, Addr>,
+}
+
+impl<'a> Default for GlobalHalt<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> GlobalHalt<'a> {
+    pub fn new() -> Self {
+        GlobalHalt {
+            halted: Item::new(GLOBAL_HALT_KEY),
+            factory: Item::new(HALT_FACTORY_KEY),
+        }
+    }
+
+    /// Sets the halt of the factory, sender must be one of the pausers of the factory
+    pub fn set_halted(
+        &self,
+        storage: &mut dyn Storage,
+        sender: &Addr,
+        halted: bool,
+    ) -> Result<(), HaltError> {
+        PauseState::new()?.error_if_unauthorized(storage, sender)?;
+        self.halted.save(storage, &halted)?;
+        Ok(())
+    }
+
+    pub fn is_halted(&self, storage: &dyn Storage) -> StdResult<bool> {
+        Ok(self.halted.may_load(storage)?.unwrap_or(false))
+    }
+
+    /// Records the address as the factory of the minter if it answers the halt query
+    /// Minters instantiated by accounts or other contracts are not halted globally
+    /// Returns the recorded factory, None if the address did not answer the halt query
+    pub fn set_factory(
+        &self,
+        storage: &mut dyn Storage,
+        querier: QuerierWrapper,
+        address: &Addr,
+    ) -> StdResult<Option<Addr>> {
+        let halted: StdResult<bool> =
+            querier.query_wasm_smart(address, &HaltQueryMsg::GlobalHalt {});
+        if halted.is_err() {
+            return Ok(None);
+        }
+        self.factory.save(storage, address)?;
+        Ok(Some(address.clone()))
+    }
+
+    /// Returns the factory of the minter and whether it has halted its minters
+    pub fn factory_halt(
+        &self,
+        storage: &dyn Storage,
+        querier: QuerierWrapper,
+    ) -> StdResult<(Option<Addr>, bool)> {
+        let factory = self.factory.may_load(storage)?;
+        let halted = match &factory {
+            Some(factory) => querier.query_wasm_smart(factory, &HaltQueryMsg::GlobalHalt {})?,
+            None => false,
+        };
+        Ok((factory, halted))
+    }
+
+    /// Errors if the factory of the minter has halted its minters
+    pub fn error_if_halted(
+        &self,
+        storage: &dyn Storage,
+        querier: QuerierWrapper,
+    ) -> Result<(), HaltError> {
+        match self.factory_halt(storage, querier)? {
+            (Some(factory), true) => Err(HaltError::Halted { factory }),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn status(
+        &self,
+        storage: &dyn Storage,
+        querier: QuerierWrapper,
+    ) -> Result<HaltStatus, HaltError> {
+        let paused = PauseState::new()?.is_paused(storage)?;
+        let (factory, global_halt) = self.factory_halt(storage, querier)?;
+        Ok(HaltStatus {
+            paused,
+            factory,
+            global_halt,
+        })
+    }
+}
+
+/// Attribute of the factory returned by `set_factory`, so a minter that is not halted globally
+/// can be told apart from one whose factory failed the halt query
+pub fn halt_factory_attribute(factory: Option<Addr>) -> Attribute {
+    attr(
+        "halt_factory",
+        factory.map_or("none".to_string(), String::from),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn test_set_halted() {
+        let mut deps = mock_dependencies();
+        let pauser = Addr::unchecked("pauser");
+        let other = Addr::unchecked("other");
+        PauseState::new()
+            .unwrap()
+            .set_pausers(&mut deps.storage, pauser.clone(), vec![pauser.clone()])
+            .unwrap();
+
+        let halt = GlobalHalt::new();
+        assert!(!halt.is_halted(&deps.storage).unwrap());
+
+        // Only pausers of the factory can halt
+        assert_eq!(
+            halt.set_halted(&mut deps.storage, &other, true),
+            Err(HaltError::Pause(PauseError::Unauthorized { sender: other }))
+        );
+        halt.set_halted(&mut deps.storage, &pauser, true).unwrap();
+        assert!(halt.is_halted(&deps.storage).unwrap());
+        halt.set_halted(&mut deps.storage, &pauser, false).unwrap();
+        assert!(!halt.is_halted(&deps.storage).unwrap());
+    }
+
+    #[test]
+    fn test_minter_without_factory() {
+        let mut deps = mock_dependencies();
+        let halt = GlobalHalt::new();
+
+        // Accounts do not answer the halt query so they are not recorded
+        let querier = QuerierWrapper::new(&deps.querier);
+        let factory = halt
+            .set_factory(&mut deps.storage, querier, &Addr::unchecked("creator"))
+            .unwrap();
+        assert_eq!(factory, None);
+        assert_eq!(
+            halt_factory_attribute(factory),
+            attr("halt_factory", "none")
+        );
+        halt.error_if_halted(&deps.storage, querier).unwrap();
+        let status = halt.status(&deps.storage, querier).unwrap();
+        assert_eq!(
+            status,
+            HaltStatus {
+                paused: false,
+                factory: None,
+                global_halt: false,
+            }
+        );
+    }
+}
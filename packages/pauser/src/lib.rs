@@ -1,3 +1,6 @@
+pub mod halt;
+pub mod migration;
+
 use cosmwasm_std::{Addr, StdError, Storage};
 use cw_storage_plus::Item;
 use thiserror::Error;
@@ -42,14 +45,23 @@ impl<'a> PauseState<'a> {
         sender: Addr,
         pausers: Vec<Addr>,
     ) -> Result<(), PauseError> {
-        let mut current_pausers = self.pausers.load(storage).unwrap_or_default();
-        if current_pausers.is_empty() {
-            current_pausers = pausers;
-        } else {
+        self.set_pausers_as(storage, sender, pausers, false)
+    }
+
+    /// Sets pausers like `set_pausers`, skipping the pauser check if the caller already
+    /// authorized the sender through another mechanism, such as ownership.
+    pub fn set_pausers_as(
+        &self,
+        storage: &mut dyn Storage,
+        sender: Addr,
+        pausers: Vec<Addr>,
+        authorized: bool,
+    ) -> Result<(), PauseError> {
+        let current_pausers = self.pausers.load(storage).unwrap_or_default();
+        if !current_pausers.is_empty() && !authorized {
             self.error_if_unauthorized(storage, &sender)?;
-            current_pausers = pausers;
         }
-        self.pausers.save(storage, &current_pausers)?;
+        self.pausers.save(storage, &pausers)?;
         self.paused.save(storage, &false)?;
         Ok(())
     }
@@ -78,14 +90,40 @@ impl<'a> PauseState<'a> {
     }
 
     pub fn pause(&self, storage: &mut dyn Storage, sender: &Addr) -> Result<(), PauseError> {
+        self.pause_as(storage, sender, false)
+    }
+
+    pub fn unpause(&self, storage: &mut dyn Storage, sender: &Addr) -> Result<(), PauseError> {
+        self.unpause_as(storage, sender, false)
+    }
+
+    /// Pauses like `pause`, skipping the pauser check if the caller already authorized the sender
+    /// through another mechanism, such as a role.
+    pub fn pause_as(
+        &self,
+        storage: &mut dyn Storage,
+        sender: &Addr,
+        authorized: bool,
+    ) -> Result<(), PauseError> {
         self.error_if_paused(storage)?;
-        self.error_if_unauthorized(storage, sender)?;
+        if !authorized {
+            self.error_if_unauthorized(storage, sender)?;
+        }
         self.paused.save(storage, &true)?;
         Ok(())
     }
 
-    pub fn unpause(&self, storage: &mut dyn Storage, sender: &Addr) -> Result<(), PauseError> {
-        self.error_if_unauthorized(storage, sender)?;
+    /// Unpauses like `unpause`, skipping the pauser check if the caller already authorized the
+    /// sender through another mechanism, such as a role.
+    pub fn unpause_as(
+        &self,
+        storage: &mut dyn Storage,
+        sender: &Addr,
+        authorized: bool,
+    ) -> Result<(), PauseError> {
+        if !authorized {
+            self.error_if_unauthorized(storage, sender)?;
+        }
         self.paused.save(storage, &false)?;
         Ok(())
     }
@@ -170,7 +208,35 @@ mod tests {
         assert_eq!(state.unpause(&mut deps.storage, &pauser2), Ok(()));
         assert_eq!(
             state.unpause(&mut deps.storage, &pauser3),
-            Err(PauseError::Unauthorized { sender: pauser3 })
+            Err(PauseError::Unauthorized {
+                sender: pauser3.clone()
+            })
+        );
+
+        // authorized senders do not need to be pausers
+        assert_eq!(state.pause_as(&mut deps.storage, &pauser3, true), Ok(()));
+        assert_eq!(
+            state.pause_as(&mut deps.storage, &pauser3, true),
+            Err(PauseError::Paused {})
+        );
+        assert_eq!(
+            state.unpause_as(&mut deps.storage, &pauser3, false),
+            Err(PauseError::Unauthorized {
+                sender: pauser3.clone()
+            })
+        );
+        assert_eq!(state.unpause_as(&mut deps.storage, &pauser3, true), Ok(()));
+        assert!(!state.is_paused(&deps.storage).unwrap());
+
+        assert_eq!(
+            state.set_pausers_as(
+                &mut deps.storage,
+                pauser3.clone(),
+                vec![pauser3.clone()],
+                true
+            ),
+            Ok(())
         );
+        assert_eq!(state.pausers.load(&deps.storage).unwrap(), vec![pauser3]);
     }
 }
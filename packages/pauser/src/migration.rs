@@ -0,0 +1,92 @@
+use cosmwasm_std::{StdError, Storage};
+use cw2::{set_contract_version, CONTRACT};
+use semver::Version;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum MigrationError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error("Can not migrate {stored} to {expected}")]
+    InvalidContractName { stored: String, expected: String },
+
+    #[error("Can not migrate from version {stored} to the older version {new}")]
+    Downgrade { stored: String, new: String },
+
+    #[error("Invalid contract version {version}")]
+    InvalidVersion { version: String },
+}
+
+fn parse_version(version: &str) -> Result<Version, MigrationError> {
+    Version::parse(version).map_err(|_| MigrationError::InvalidVersion {
+        version: version.to_string(),
+    })
+}
+
+/// Checks the migrated contract is the same contract and not newer, then sets the new version
+/// Returns the version the contract is migrated from, None if it never stored one
+pub fn migrate_version(
+    storage: &mut dyn Storage,
+    name: &str,
+    version: &str,
+) -> Result<Option<String>, MigrationError> {
+    let stored = CONTRACT.may_load(storage)?;
+    if let Some(stored) = &stored {
+        if stored.contract != name {
+            return Err(MigrationError::InvalidContractName {
+                stored: stored.contract.clone(),
+                expected: name.to_string(),
+            });
+        }
+        if parse_version(&stored.version)? > parse_version(version)? {
+            return Err(MigrationError::Downgrade {
+                stored: stored.version.clone(),
+                new: version.to_string(),
+            });
+        }
+    }
+    set_contract_version(storage, name, version)?;
+    Ok(stored.map(|stored| stored.version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn test_migrate_version() {
+        let mut deps = mock_dependencies();
+
+        // Contracts that never stored a version can be migrated
+        let from = migrate_version(&mut deps.storage, "minter", "1.0.0").unwrap();
+        assert_eq!(from, None);
+        let from = migrate_version(&mut deps.storage, "minter", "1.1.0").unwrap();
+        assert_eq!(from, Some("1.0.0".to_string()));
+        // Migrating to the same version again is allowed
+        let from = migrate_version(&mut deps.storage, "minter", "1.1.0").unwrap();
+        assert_eq!(from, Some("1.1.0".to_string()));
+
+        assert_eq!(
+            migrate_version(&mut deps.storage, "minter", "1.0.1"),
+            Err(MigrationError::Downgrade {
+                stored: "1.1.0".to_string(),
+                new: "1.0.1".to_string(),
+            })
+        );
+        assert_eq!(
+            migrate_version(&mut deps.storage, "whitelist", "1.2.0"),
+            Err(MigrationError::InvalidContractName {
+                stored: "minter".to_string(),
+                expected: "whitelist".to_string(),
+            })
+        );
+        assert_eq!(
+            migrate_version(&mut deps.storage, "minter", "latest"),
+            Err(MigrationError::InvalidVersion {
+                version: "latest".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use cosmwasm_std::testing::MockApi;
+use cosmwasm_std::{
+    Addr, Api, CanonicalAddr, RecoverPubkeyError, StdError, StdResult, VerificationError,
+};
+
+// Instantiate2 addresses are the only canonical addresses of this length
+const INSTANTIATE2_ADDRESS_LENGTH: usize = 32;
+const INSTANTIATE2_ADDRESS_PREFIX: &str = "contract";
+
+/// MockApi that can also humanize the addresses of contracts instantiated with instantiate2
+/// Those addresses are rendered as "contract" followed by the hex encoded canonical address
+#[derive(Default)]
+pub struct OmniflixApi {
+    mock_api: MockApi,
+}
+
+impl OmniflixApi {
+    fn decode_instantiate2_address(&self, input: &str) -> Option<CanonicalAddr> {
+        let hex = input.strip_prefix(INSTANTIATE2_ADDRESS_PREFIX)?;
+        if hex.len() != INSTANTIATE2_ADDRESS_LENGTH * 2 {
+            return None;
+        }
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+            .collect::<Option<Vec<u8>>>()
+            .map(CanonicalAddr::from)
+    }
+}
+
+impl Api for OmniflixApi {
+    fn addr_validate(&self, input: &str) -> StdResult<Addr> {
+        let canonical = self.addr_canonicalize(input)?;
+        let normalized = self.addr_humanize(&canonical)?;
+        if normalized.as_str() != input {
+            return Err(StdError::generic_err("Invalid input: address not normalized"));
+        }
+        Ok(Addr::unchecked(input))
+    }
+
+    fn addr_canonicalize(&self, input: &str) -> StdResult<CanonicalAddr> {
+        match self.decode_instantiate2_address(input) {
+            Some(canonical) => Ok(canonical),
+            None => self.mock_api.addr_canonicalize(input),
+        }
+    }
+
+    fn addr_humanize(&self, canonical: &CanonicalAddr) -> StdResult<Addr> {
+        if canonical.len() != INSTANTIATE2_ADDRESS_LENGTH {
+            return self.mock_api.addr_humanize(canonical);
+        }
+        let hex: String = canonical
+            .as_slice()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        Ok(Addr::unchecked(format!("{}{}", INSTANTIATE2_ADDRESS_PREFIX, hex)))
+    }
+
+    fn secp256k1_verify(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        self.mock_api
+            .secp256k1_verify(message_hash, signature, public_key)
+    }
+
+    fn secp256k1_recover_pubkey(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        recovery_param: u8,
+    ) -> Result<Vec<u8>, RecoverPubkeyError> {
+        self.mock_api
+            .secp256k1_recover_pubkey(message_hash, signature, recovery_param)
+    }
+
+    fn ed25519_verify(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        self.mock_api.ed25519_verify(message, signature, public_key)
+    }
+
+    fn ed25519_batch_verify(
+        &self,
+        messages: &[&[u8]],
+        signatures: &[&[u8]],
+        public_keys: &[&[u8]],
+    ) -> Result<bool, VerificationError> {
+        self.mock_api
+            .ed25519_batch_verify(messages, signatures, public_keys)
+    }
+
+    fn debug(&self, message: &str) {
+        self.mock_api.debug(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instantiate2_address() {
+        let api = OmniflixApi::default();
+        let canonical = CanonicalAddr::from(vec![171u8; INSTANTIATE2_ADDRESS_LENGTH]);
+        let address = api.addr_humanize(&canonical).unwrap();
+        assert_eq!(address.as_str(), format!("contract{}", "ab".repeat(32)));
+        assert_eq!(api.addr_canonicalize(address.as_str()).unwrap(), canonical);
+        assert_eq!(api.addr_validate(address.as_str()).unwrap(), address);
+
+        // Other addresses are handled by the mock api
+        let address = api.addr_validate("creator").unwrap();
+        assert_eq!(address, Addr::unchecked("creator"));
+        assert!(api.addr_validate("CREATOR").is_err());
+    }
+}
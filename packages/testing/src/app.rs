@@ -1,7 +1,8 @@
 use std::ops::{Deref, DerefMut};
 
+use crate::api::OmniflixApi;
 use crate::stargate::StargateKeeper;
-use cosmwasm_std::{testing::MockApi, Empty, GovMsg, IbcMsg, IbcQuery, MemoryStorage};
+use cosmwasm_std::{Empty, GovMsg, IbcMsg, IbcQuery, MemoryStorage};
 use cw_multi_test::{
     no_init, App, AppBuilder, BankKeeper, DistributionKeeper, FailingModule, StakeKeeper,
     WasmKeeper,
@@ -10,7 +11,7 @@ use cw_multi_test::{
 pub struct OmniflixApp(
     App<
         BankKeeper,
-        MockApi,
+        OmniflixApi,
         MemoryStorage,
         FailingModule<Empty, Empty, Empty>,
         WasmKeeper<Empty, Empty>,
@@ -24,7 +25,7 @@ pub struct OmniflixApp(
 impl Deref for OmniflixApp {
     type Target = App<
         BankKeeper,
-        MockApi,
+        OmniflixApi,
         MemoryStorage,
         FailingModule<Empty, Empty, Empty>,
         WasmKeeper<Empty, Empty>,
@@ -55,7 +56,10 @@ impl OmniflixApp {
     pub fn new() -> Self {
         let app_builder = AppBuilder::default();
         let stargate = StargateKeeper {};
-        let app = app_builder.with_stargate(stargate).build(no_init);
+        let app = app_builder
+            .with_api(OmniflixApi::default())
+            .with_stargate(stargate)
+            .build(no_init);
         OmniflixApp(app)
     }
 }
@@ -1,6 +1,9 @@
+pub mod round;
+
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Coin, Deps, Timestamp};
+use cosmwasm_std::{Addr, Binary, Coin, Deps, Timestamp};
 use cosmwasm_std::{Empty, StdError};
+use cw_ownable::cw_ownable_query;
 use minter_types::config::Config as MinterConfig;
 use minter_types::msg::QueryMsg as MinterQueryMsg;
 
@@ -8,6 +11,8 @@ use minter_types::msg::QueryMsg as MinterQueryMsg;
 pub struct CreateWhitelistMsg {
     pub admin: String,
     pub rounds: Vec<RoundConfig>,
+    // Minter contracts allowed to mint from the whitelist
+    pub minters: Option<Vec<String>>,
 }
 #[cw_serde]
 pub struct RoundConfig {
@@ -15,6 +20,7 @@ pub struct RoundConfig {
     pub members: Vec<String>,
 }
 
+#[cw_ownable_query]
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum RoundWhitelistQueryMsgs {
@@ -36,15 +42,67 @@ pub enum RoundWhitelistQueryMsgs {
         start_after: Option<String>,
         limit: Option<u32>,
     },
-    // Returns price of the active round
+    // Returns price of the active round with the highest priority
     #[returns(Coin)]
     Price {},
 
+    // Returns price of the active round the address mints from
+    // Rounds where the address used its allocation with the minter are skipped
+    #[returns(Coin)]
+    CollectorPrice {
+        address: String,
+        minter: Option<String>,
+        proof: Option<MembershipProof>,
+    },
+
+    // Returns true if the address is a member of any active round
+    // Proof is required for rounds that do not store their members
     #[returns(bool)]
-    IsMember { address: String },
+    IsMember {
+        address: String,
+        proof: Option<MembershipProof>,
+    },
+
+    // Returns the number of tokens minted with the voucher nonce
+    #[returns(u32)]
+    VoucherNonceUsage { nonce: String },
+
+    // Returns the registered minters and their mints in each round
+    #[returns(Vec<MinterUsage>)]
+    Minters {},
+
+    // Returns the membership and mint usage of the address with the minter in every round
+    #[returns(Vec<UserRoundStatus>)]
+    UserRoundStatus { address: String, minter: String },
+
+    // Returns the mint counts of every address, minter and round
+    #[returns(Vec<UsageRecord>)]
+    UsageRecords {
+        // (address, minter, round_index) of the last record of the previous page
+        start_after: Option<(String, String, u8)>,
+        limit: Option<u32>,
+    },
+
+    // Returns the remaining supply of the round for the minter, None if the round has no cap
+    #[returns(Option<u32>)]
+    RemainingRoundSupply { round_index: u8, minter: String },
+
+    // Returns the remaining allowance of the address with the minter, rollovers included
+    #[returns(EffectiveAllowance)]
+    EffectiveAllowance {
+        address: String,
+        minter: String,
+        proof: Option<MembershipProof>,
+    },
 
     #[returns(String)]
     Admin {},
+
+    #[returns(bool)]
+    IsPaused {},
+
+    #[returns(Vec<Addr>)]
+    Pausers {},
 }
 
 #[cw_serde]
@@ -52,7 +110,110 @@ pub struct Round {
     pub start_time: Timestamp,
     pub end_time: Timestamp,
     pub mint_price: Coin,
-    pub round_per_address_limit: u8,
+    pub round_per_address_limit: u32,
+    // Hex encoded sha256 merkle root of the round members
+    // If set, members are not stored and collectors prove their membership with a merkle proof
+    pub merkle_root: Option<String>,
+    // Compressed or uncompressed secp256k1 public key of the voucher signer
+    // If set, members are not stored and collectors prove their membership with a signed voucher
+    pub voucher_signer: Option<Binary>,
+    // Overlapping active rounds are checked from the highest priority to the lowest
+    // Rounds with the same priority can not overlap
+    // Rounds stored before priorities were added default to the lowest priority
+    #[serde(default)]
+    pub priority: u8,
+    // Members mint for free up to their allocation
+    // Rounds stored before free claims were added are paid rounds
+    #[serde(default)]
+    pub free_claim: bool,
+    // Where the unused allocation of the members goes once the round ends
+    // Only rounds that store their members can roll over
+    pub rollover: Option<RolloverPolicy>,
+    // Maximum number of tokens minted in the round, None means no cap
+    pub round_supply: Option<RoundSupply>,
+}
+
+#[cw_serde]
+pub struct RoundSupply {
+    pub limit: u32,
+    // If true every linked minter can mint up to the limit, otherwise the limit is shared
+    pub per_minter: bool,
+}
+
+#[cw_serde]
+pub enum RolloverPolicy {
+    // Unused allocation is added to the allocation of the member in the next round by start time
+    // Allocation rolled into a round does not roll over again
+    NextRound {},
+    // Unused allocation is added to the public per address limit of the member
+    Public {},
+}
+
+#[cw_serde]
+pub struct MinterUsage {
+    pub minter: Addr,
+    // Number of tokens minted by the minter in each round
+    pub round_mints: Vec<(u8, u32)>,
+}
+
+#[cw_serde]
+pub struct UserRoundStatus {
+    pub round_index: u8,
+    // None for merkle and voucher rounds, membership is only known from the proof
+    pub is_member: Option<bool>,
+    pub minted: u32,
+    // None for merkle and voucher rounds, allocation is only known from the proof
+    pub remaining: Option<u32>,
+}
+
+#[cw_serde]
+pub struct EffectiveAllowance {
+    // Active round the address mints from, None if the address can not mint from any round
+    pub round_index: Option<u8>,
+    // Remaining allocation in the round, rollover from the previous round included
+    pub remaining: u32,
+    // Unused allocation of ended rounds added to the public per address limit
+    pub public_rollover: u32,
+}
+
+#[cw_serde]
+pub struct UsageRecord {
+    pub address: Addr,
+    pub minter: Addr,
+    pub round_index: u8,
+    pub minted: u32,
+}
+
+/// Proof of membership for rounds that do not store their members
+#[cw_serde]
+pub enum MembershipProof {
+    Merkle {
+        // Hex encoded sibling hashes from the leaf up to the root
+        proof: Vec<String>,
+        // Allocation committed in the leaf, overrides the round per address limit
+        allocation: Option<u32>,
+    },
+    Voucher {
+        // Maximum number of tokens the voucher can mint, overrides the round per address limit
+        max_quantity: u32,
+        expiry: Timestamp,
+        // Unique identifier of the voucher, a nonce can not mint more than max_quantity
+        nonce: String,
+        // secp256k1 signature of the sha256 hash of the voucher payload
+        signature: Binary,
+    },
+}
+
+/// Payload signed by the voucher signer of a round
+/// Signed message is the sha256 hash of its JSON encoding
+#[cw_serde]
+pub struct VoucherPayload {
+    pub whitelist_address: String,
+    pub round_index: u8,
+    pub address: String,
+    pub max_quantity: u32,
+    pub expiry: Timestamp,
+    pub nonce: String,
 }
 
 pub fn check_if_minter(address: &Addr, deps: Deps) -> Result<(), StdError> {
@@ -73,20 +234,76 @@ pub fn check_if_whitelist_is_active(address: &Addr, deps: Deps) -> Result<bool,
 pub fn check_if_address_is_member(
     address: &Addr,
     whitelist_address: &Addr,
+    proof: Option<MembershipProof>,
     deps: Deps,
 ) -> Result<bool, StdError> {
     let is_member_res: bool = deps.querier.query_wasm_smart(
         whitelist_address,
         &RoundWhitelistQueryMsgs::IsMember {
             address: address.to_string(),
+            proof,
         },
     )?;
     Ok(is_member_res)
 }
 
-pub fn check_whitelist_price(address: &Addr, deps: Deps) -> Result<Coin, StdError> {
-    let price_res: Coin = deps
-        .querier
-        .query_wasm_smart(address, &RoundWhitelistQueryMsgs::Price {})?;
+pub fn check_whitelist_price(
+    address: &Addr,
+    whitelist_address: &Addr,
+    minter_address: &Addr,
+    proof: Option<MembershipProof>,
+    deps: Deps,
+) -> Result<Coin, StdError> {
+    let price_res: Coin = deps.querier.query_wasm_smart(
+        whitelist_address,
+        &RoundWhitelistQueryMsgs::CollectorPrice {
+            address: address.to_string(),
+            minter: Some(minter_address.to_string()),
+            proof,
+        },
+    )?;
     Ok(price_res)
 }
+
+pub fn check_public_rollover(
+    address: &Addr,
+    whitelist_address: &Addr,
+    minter_address: &Addr,
+    deps: Deps,
+) -> Result<u32, StdError> {
+    let allowance_res: EffectiveAllowance = deps.querier.query_wasm_smart(
+        whitelist_address,
+        &RoundWhitelistQueryMsgs::EffectiveAllowance {
+            address: address.to_string(),
+            minter: minter_address.to_string(),
+            proof: None,
+        },
+    )?;
+    Ok(allowance_res.public_rollover)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::from_json;
+
+    #[test]
+    fn test_deserialize_baseline_round() {
+        // Rounds stored by whitelists created before the round options were added
+        let round: Round = from_json(
+            br#"{
+                "start_time": "1000",
+                "end_time": "2000",
+                "mint_price": { "denom": "uflix", "amount": "1000000" },
+                "round_per_address_limit": 1
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(round.priority, 0);
+        assert!(!round.free_claim);
+        assert_eq!(round.merkle_root, None);
+        assert_eq!(round.voucher_signer, None);
+        assert_eq!(round.rollover, None);
+        assert_eq!(round.round_supply, None);
+    }
+}
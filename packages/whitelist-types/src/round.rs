@@ -0,0 +1,139 @@
+use crate::Round;
+use cosmwasm_std::{Binary, Timestamp};
+use thiserror::Error;
+
+// Maximum number of members that can be added to or removed from a round at once
+pub const MAX_MEMBERS_PER_BATCH: usize = 5000;
+
+/// Problems a round can have before it is saved by the whitelist
+#[derive(Error, Debug, PartialEq)]
+pub enum RoundError {
+    #[error("Invalid end time")]
+    InvalidEndTime {},
+
+    #[error("Round has already started")]
+    RoundAlreadyStarted {},
+
+    #[error("Invalid per address limit")]
+    InvalidPerAddressLimit {},
+
+    #[error("Invalid round supply")]
+    InvalidRoundSupply {},
+
+    #[error("Invalid merkle root")]
+    InvalidMerkleRoot {},
+
+    #[error("Invalid voucher signer")]
+    InvalidVoucherSigner {},
+
+    #[error("Round can not have both a merkle root and a voucher signer")]
+    ConflictingMembershipProofs {},
+
+    #[error("Only rounds that store their members can roll over")]
+    RolloverNotAllowed {},
+
+    #[error("Rounds overlapped")]
+    RoundsOverlapped {},
+
+    #[error("Members can not be added to a round with a merkle root or a voucher signer")]
+    MembersNotAllowed {},
+
+    #[error("Empty address list")]
+    EmptyAddressList {},
+
+    #[error("Whitelist member limit exceeded")]
+    WhitelistMemberLimitExceeded {},
+}
+
+// Merkle and voucher rounds prove membership at mint time
+pub fn stores_members(round: &Round) -> bool {
+    round.merkle_root.is_none() && round.voucher_signer.is_none()
+}
+
+// Merkle roots are hex encoded sha256 hashes
+pub fn check_merkle_root(merkle_root: &str) -> Result<(), RoundError> {
+    let mut decoded = [0u8; 32];
+    hex::decode_to_slice(merkle_root, &mut decoded)
+        .map_err(|_| RoundError::InvalidMerkleRoot {})
+}
+
+pub fn check_voucher_signer(voucher_signer: &Binary) -> Result<(), RoundError> {
+    // Compressed keys are 33 bytes, uncompressed keys are 65 bytes
+    match voucher_signer.len() {
+        33 | 65 => Ok(()),
+        _ => Err(RoundError::InvalidVoucherSigner {}),
+    }
+}
+
+pub fn check_round_integrity(round: &Round, now: Timestamp) -> Result<(), RoundError> {
+    if round.start_time > round.end_time {
+        return Err(RoundError::InvalidEndTime {});
+    }
+    if round.start_time < now {
+        return Err(RoundError::RoundAlreadyStarted {});
+    }
+    if round.round_per_address_limit == 0 {
+        return Err(RoundError::InvalidPerAddressLimit {});
+    }
+    if let Some(round_supply) = &round.round_supply {
+        if round_supply.limit == 0 {
+            return Err(RoundError::InvalidRoundSupply {});
+        }
+    }
+    if let Some(merkle_root) = &round.merkle_root {
+        check_merkle_root(merkle_root)?;
+    }
+    if let Some(voucher_signer) = &round.voucher_signer {
+        if round.merkle_root.is_some() {
+            return Err(RoundError::ConflictingMembershipProofs {});
+        }
+        check_voucher_signer(voucher_signer)?;
+    }
+    // Unused allocation is only known for the stored members
+    if round.rollover.is_some() && !stores_members(round) {
+        return Err(RoundError::RolloverNotAllowed {});
+    }
+
+    Ok(())
+}
+
+// Members of the round config are saved with the round, merkle and voucher rounds take none
+pub fn check_round_members(round: &Round, members: &[String]) -> Result<(), RoundError> {
+    if !stores_members(round) {
+        if !members.is_empty() {
+            return Err(RoundError::MembersNotAllowed {});
+        }
+        return Ok(());
+    }
+    check_batch_size(members)
+}
+
+// Validates the size of a batch of members
+pub fn check_batch_size<T>(members: &[T]) -> Result<(), RoundError> {
+    if members.len() > MAX_MEMBERS_PER_BATCH {
+        return Err(RoundError::WhitelistMemberLimitExceeded {});
+    }
+    if members.is_empty() {
+        return Err(RoundError::EmptyAddressList {});
+    }
+    Ok(())
+}
+
+// Rounds with the same priority can not overlap
+pub fn check_rounds_overlap(mut rounds: Vec<&Round>) -> Result<(), RoundError> {
+    // Sort the rounds by priority and start time
+    rounds.sort_by(|a, b| (a.priority, a.start_time).cmp(&(b.priority, b.start_time)));
+
+    // Check if any round overlaps with a round of the same priority
+    for pair in rounds.windows(2) {
+        let current_round = pair[0];
+        let next_round = pair[1];
+
+        if current_round.priority == next_round.priority
+            && current_round.end_time > next_round.start_time
+        {
+            return Err(RoundError::RoundsOverlapped {});
+        }
+    }
+    Ok(())
+}
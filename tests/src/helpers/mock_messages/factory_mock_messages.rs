@@ -18,6 +18,7 @@ pub fn return_minter_factory_inst_message(code_id: u64) -> MinterFactoryInstanti
             fee_collector_address: Addr::unchecked("admin".to_string()),
             admin: Addr::unchecked("admin".to_string()),
             product_label: "label".to_string(),
+            whitelist_factory_address: None,
         },
     }
 }
@@ -39,6 +40,7 @@ pub fn return_open_edition_minter_factory_inst_message(
                     multi_minter_product_label: "mm_oem_label".to_string(),
                 }),
                 oem_product_label: "oem_label".to_string(),
+                whitelist_factory_address: None,
             },
         },
         None => OpenEditionMinterFactoryInstantiateMsg {
@@ -49,6 +51,7 @@ pub fn return_open_edition_minter_factory_inst_message(
                 admin: Addr::unchecked("admin".to_string()),
                 multi_minter_params: None,
                 oem_product_label: "oem_label".to_string(),
+                whitelist_factory_address: None,
             },
         },
     }
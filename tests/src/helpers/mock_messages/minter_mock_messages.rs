@@ -22,6 +22,7 @@ pub fn return_minter_instantiate_msg() -> CreateMinterMsg {
         description: Some("description".to_string()),
         preview_uri: Some("preview_uri".to_string()),
         base_token_uri: "base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
@@ -44,5 +45,6 @@ pub fn return_minter_instantiate_msg() -> CreateMinterMsg {
             admin: Addr::unchecked("creator".to_string()),
             payment_collector: Addr::unchecked("creator".to_string()),
         },
+        timelock_duration: None,
     }
 }
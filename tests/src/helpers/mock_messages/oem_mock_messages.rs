@@ -32,6 +32,7 @@ pub fn return_open_edition_minter_inst_msg() -> OpenEditionMinterCreateMsg {
         description: Some("description".to_string()),
         preview_uri: Some("preview_uri".to_string()),
         base_token_uri: "base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
@@ -47,5 +48,6 @@ pub fn return_open_edition_minter_inst_msg() -> OpenEditionMinterCreateMsg {
             admin: Addr::unchecked("creator".to_string()),
             payment_collector: Addr::unchecked("creator".to_string()),
         },
+        timelock_duration: None,
     }
 }
@@ -8,12 +8,24 @@ pub fn return_round_configs() -> Vec<RoundConfig> {
         end_time: Timestamp::from_nanos(3000),
         mint_price: Coin::new(1000000, "diffirent_denom"),
         round_per_address_limit: 1,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
     };
     let round_2 = whitelist_types::Round {
         start_time: Timestamp::from_nanos(4000),
         end_time: Timestamp::from_nanos(5000),
         mint_price: Coin::new(1000000, "uflix"),
         round_per_address_limit: 1,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
     };
     let round_config_1 = whitelist_types::RoundConfig {
         round: round_1,
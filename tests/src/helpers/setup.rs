@@ -1,34 +1,39 @@
 use cosmwasm_std::{coins, Addr, BlockInfo, Timestamp};
 use cw_multi_test::ContractWrapper;
 use omniflix_minter::contract::{
-    execute as minter_execute, instantiate as minter_instantiate, query as minter_query,
+    execute as minter_execute, instantiate as minter_instantiate, migrate as minter_migrate,
+    query as minter_query,
 };
 use omniflix_minter_factory::contract::{
-    execute as factory_execute, instantiate as factory_instantiate, query as factory_query,
+    execute as factory_execute, instantiate as factory_instantiate, migrate as factory_migrate,
+    query as factory_query, reply as factory_reply,
 };
 use omniflix_open_edition_minter::contract::{
     execute as open_edition_minter_execute, instantiate as open_edition_minter_instantiate,
-    query as open_edition_minter_query,
+    migrate as open_edition_minter_migrate, query as open_edition_minter_query,
 };
 
 use crate::helpers::utils::mint_to_address;
 use omniflix_open_edition_minter_factory::contract::{
     execute as open_edition_minter_factory_execute,
     instantiate as open_edition_minter_factory_instantiate,
-    query as open_edition_minter_factory_query,
+    migrate as open_edition_minter_factory_migrate, query as open_edition_minter_factory_query,
+    reply as open_edition_minter_factory_reply,
 };
 use omniflix_round_whitelist::contract::{
     execute as round_whitelist_execute, instantiate as round_whitelist_instantiate,
-    query as round_whitelist_query,
+    migrate as round_whitelist_migrate, query as round_whitelist_query,
 };
 use omniflix_round_whitelist_factory::contract::{
     execute as round_whitelist_factory_execute, instantiate as round_whitelist_factory_instantiate,
-    query as round_whitelist_factory_query,
+    migrate as round_whitelist_factory_migrate, query as round_whitelist_factory_query,
+    reply as round_whitelist_factory_reply,
 };
 
 use omniflix_multi_mint_open_edition_minter::contract::{
     execute as multi_mint_open_edition_minter_execute,
     instantiate as multi_mint_open_edition_minter_instantiate,
+    migrate as multi_mint_open_edition_minter_migrate,
     query as multi_mint_open_edition_minter_query,
 };
 
@@ -69,42 +74,58 @@ pub fn setup() -> SetupResponse {
         coins(1000000000000, "diffirent_denom"),
     );
 
-    let minter_factory_contract = Box::new(ContractWrapper::new(
-        factory_execute,
-        factory_instantiate,
-        factory_query,
-    ));
-    let minter_contract = Box::new(ContractWrapper::new(
-        minter_execute,
-        minter_instantiate,
-        minter_query,
-    ));
-
-    let round_whitelist_factory_contract = Box::new(ContractWrapper::new(
-        round_whitelist_factory_execute,
-        round_whitelist_factory_instantiate,
-        round_whitelist_factory_query,
-    ));
-    let round_whitelist_contract = Box::new(ContractWrapper::new(
-        round_whitelist_execute,
-        round_whitelist_instantiate,
-        round_whitelist_query,
-    ));
-    let open_edition_minter_factory_contract = Box::new(ContractWrapper::new(
-        open_edition_minter_factory_execute,
-        open_edition_minter_factory_instantiate,
-        open_edition_minter_factory_query,
-    ));
-    let open_edition_minter_contract = Box::new(ContractWrapper::new(
-        open_edition_minter_execute,
-        open_edition_minter_instantiate,
-        open_edition_minter_query,
-    ));
-    let multi_mint_open_edition_minter_contract = Box::new(ContractWrapper::new(
-        multi_mint_open_edition_minter_execute,
-        multi_mint_open_edition_minter_instantiate,
-        multi_mint_open_edition_minter_query,
-    ));
+    let minter_factory_contract = Box::new(
+        ContractWrapper::new(factory_execute, factory_instantiate, factory_query)
+            .with_reply(factory_reply)
+            .with_migrate(factory_migrate),
+    );
+    let minter_contract = Box::new(
+        ContractWrapper::new(minter_execute, minter_instantiate, minter_query)
+            .with_migrate(minter_migrate),
+    );
+
+    let round_whitelist_factory_contract = Box::new(
+        ContractWrapper::new(
+            round_whitelist_factory_execute,
+            round_whitelist_factory_instantiate,
+            round_whitelist_factory_query,
+        )
+        .with_reply(round_whitelist_factory_reply)
+        .with_migrate(round_whitelist_factory_migrate),
+    );
+    let round_whitelist_contract = Box::new(
+        ContractWrapper::new(
+            round_whitelist_execute,
+            round_whitelist_instantiate,
+            round_whitelist_query,
+        )
+        .with_migrate(round_whitelist_migrate),
+    );
+    let open_edition_minter_factory_contract = Box::new(
+        ContractWrapper::new(
+            open_edition_minter_factory_execute,
+            open_edition_minter_factory_instantiate,
+            open_edition_minter_factory_query,
+        )
+        .with_reply(open_edition_minter_factory_reply)
+        .with_migrate(open_edition_minter_factory_migrate),
+    );
+    let open_edition_minter_contract = Box::new(
+        ContractWrapper::new(
+            open_edition_minter_execute,
+            open_edition_minter_instantiate,
+            open_edition_minter_query,
+        )
+        .with_migrate(open_edition_minter_migrate),
+    );
+    let multi_mint_open_edition_minter_contract = Box::new(
+        ContractWrapper::new(
+            multi_mint_open_edition_minter_execute,
+            multi_mint_open_edition_minter_instantiate,
+            multi_mint_open_edition_minter_query,
+        )
+        .with_migrate(multi_mint_open_edition_minter_migrate),
+    );
 
     let minter_code_id = app.store_code(minter_contract);
 
@@ -1,5 +1,6 @@
-use cosmwasm_std::{from_json, Coin, MemoryStorage, Storage};
-use cw_multi_test::{AppResponse, BankSudo, SudoMsg};
+use cosmwasm_std::{from_json, Addr, Coin, MemoryStorage, Storage};
+use cw_multi_test::{AppResponse, BankSudo, Executor, SudoMsg};
+use omniflix_round_whitelist::msg::ExecuteMsg as RoundWhitelistExecuteMsg;
 use omniflix_std::types::omniflix::onft::v1beta1::Collection;
 use omniflix_testing::app::OmniflixApp;
 
@@ -26,3 +27,20 @@ pub fn mint_to_address(app: &mut OmniflixApp, to_address: String, amount: Vec<Co
     app.sudo(SudoMsg::Bank(BankSudo::Mint { to_address, amount }))
         .unwrap();
 }
+// Registers the minter so it can mint from the round whitelist
+pub fn add_whitelist_minter(
+    app: &mut OmniflixApp,
+    whitelist_admin: &Addr,
+    whitelist_address: &str,
+    minter_address: &str,
+) {
+    app.execute_contract(
+        whitelist_admin.clone(),
+        Addr::unchecked(whitelist_address),
+        &RoundWhitelistExecuteMsg::AddMinters {
+            minters: vec![minter_address.to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+}
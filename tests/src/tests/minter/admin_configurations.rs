@@ -3,6 +3,7 @@ use std::str::FromStr;
 use cosmwasm_std::{coin, BlockInfo, Decimal, Timestamp};
 use cosmwasm_std::{Addr, StdError};
 use cw_multi_test::Executor;
+use cw_ownable::{Action, Ownership, OwnershipError};
 
 use minter_types::config::Config;
 use minter_types::token_details::{Token, TokenDetails, TokenDetailsError};
@@ -68,6 +69,7 @@ fn update_whitelist() {
     let round_whitelist_inst_msg = CreateWhitelistMsg {
         admin: admin.to_string(),
         rounds: rounds.clone(),
+        minters: None,
     };
     let create_round_whitelist_msg =
         omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
@@ -152,6 +154,7 @@ fn update_whitelist() {
     let round_whitelist_inst_msg = CreateWhitelistMsg {
         admin: admin.to_string(),
         rounds: rounds.clone(),
+        minters: None,
     };
     let create_round_whitelist_msg =
         omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
@@ -189,6 +192,7 @@ fn update_whitelist() {
     let round_whitelist_inst_msg = CreateWhitelistMsg {
         admin: admin.to_string(),
         rounds: rounds.clone(),
+        minters: None,
     };
     let create_round_whitelist_msg =
         omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
@@ -312,7 +316,7 @@ fn burn_remaining_tokens() {
         .execute_contract(
             creator.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[public_minting_price.clone()],
         )
         .unwrap();
@@ -391,7 +395,7 @@ fn burn_remaining_tokens() {
         .execute_contract(
             creator.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[public_minting_price.clone()],
         )
         .unwrap_err();
@@ -706,29 +710,61 @@ fn update_admin() {
         .unwrap();
     let minter_address = get_contract_address_from_res(res.clone());
 
-    // Non admin can not update admin
+    // Non admin can not transfer ownership
     let res = app
         .execute_contract(
             collector.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::UpdateAdmin {
-                admin: collector.clone().into_string(),
-            },
+            &MinterExecuteMsg::UpdateOwnership(Action::TransferOwnership {
+                new_owner: collector.to_string(),
+                expiry: None,
+            }),
             &[],
         )
         .unwrap_err();
     let error = res.source().unwrap();
     let error = error.downcast_ref::<MinterContractError>().unwrap();
-    assert_eq!(error, &MinterContractError::Unauthorized {});
+    assert_eq!(error, &MinterContractError::Ownership(OwnershipError::NotOwner));
 
-    // Update admin
+    // Creator proposes collector as the new admin
     let _res = app
         .execute_contract(
             creator.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::UpdateAdmin {
-                admin: collector.clone().into_string(),
-            },
+            &MinterExecuteMsg::UpdateOwnership(Action::TransferOwnership {
+                new_owner: collector.to_string(),
+                expiry: None,
+            }),
+            &[],
+        )
+        .unwrap();
+
+    // Admin is not updated until the transfer is accepted
+    let auth_details: AuthDetails = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::AuthDetails {})
+        .unwrap();
+    assert_eq!(auth_details.admin, creator.clone());
+
+    // Only the pending owner can accept the transfer
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateOwnership(Action::AcceptOwnership),
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::Ownership(OwnershipError::NotPendingOwner));
+
+    // Collector accepts the transfer
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateOwnership(Action::AcceptOwnership),
             &[],
         )
         .unwrap();
@@ -738,6 +774,14 @@ fn update_admin() {
         .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::AuthDetails {})
         .unwrap();
     assert_eq!(auth_details.admin, collector.clone());
+
+    let ownership: Ownership<String> = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::Ownership {})
+        .unwrap();
+    assert_eq!(ownership.owner, Some(collector.to_string()));
+    assert_eq!(ownership.pending_owner, None);
+}
 }
 
 #[test]
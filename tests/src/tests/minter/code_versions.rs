@@ -0,0 +1,321 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::return_minter_factory_inst_message;
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::setup::setup;
+use crate::helpers::utils::get_contract_address_from_res;
+use cosmwasm_std::{coin, to_json_binary, Addr};
+use cw_multi_test::{ContractWrapper, Executor};
+use factory_types::catalog::{CatalogError, CodeVersion};
+use factory_types::registry::{ContractType, CreatedContract};
+use omniflix_minter::contract::{
+    execute as minter_execute, instantiate as minter_instantiate, migrate as minter_migrate,
+    query as minter_query,
+};
+use omniflix_minter::msg::MigrateMsg;
+use omniflix_minter_factory::error::ContractError as MinterFactoryError;
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use omniflix_minter_factory::msg::QueryMsg as MinterFactoryQueryMsg;
+
+#[test]
+fn code_versions() {
+    let res = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let mut app = res.app;
+
+    // Second minter code to upgrade to
+    let minter_v2_code_id = app.store_code(Box::new(
+        ContractWrapper::new(minter_execute, minter_instantiate, minter_query)
+            .with_migrate(minter_migrate),
+    ));
+
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+
+    // Non admin can not add a version
+    let add_version_msg = FactoryExecuteMsg::AddCodeVersion {
+        contract_type: ContractType::Minter,
+        version: "v2".to_string(),
+        code_id: minter_v2_code_id,
+        label: "Minter v2".to_string(),
+    };
+    let error = app
+        .execute_contract(creator.clone(), factory_addr.clone(), &add_version_msg, &[])
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(error, &MinterFactoryError::Unauthorized {});
+
+    // Minter factory does not create whitelists
+    let error = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::AddCodeVersion {
+                contract_type: ContractType::RoundWhitelist,
+                version: "v2".to_string(),
+                code_id: minter_v2_code_id,
+                label: "Minter v2".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterFactoryError::Catalog(CatalogError::UnsupportedContractType {
+            contract_type: "round_whitelist".to_string(),
+        })
+    );
+
+    let _res = app
+        .execute_contract(admin.clone(), factory_addr.clone(), &add_version_msg, &[])
+        .unwrap();
+    let code_versions: Vec<CodeVersion> = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::CodeVersions {
+                contract_type: ContractType::Minter,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        code_versions,
+        vec![CodeVersion {
+            contract_type: ContractType::Minter,
+            version: "v2".to_string(),
+            code_id: minter_v2_code_id,
+            label: "Minter v2".to_string(),
+            deprecated: false,
+        }]
+    );
+
+    // Minter without a version uses the default code id
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter {
+                msg: return_minter_instantiate_msg(),
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res);
+    let contract_info = app
+        .wrap()
+        .query_wasm_contract_info(&minter_address)
+        .unwrap();
+    assert_eq!(contract_info.code_id, minter_code_id);
+    // Admin of the minter is its wasm admin by default
+    assert_eq!(contract_info.admin, Some(creator.to_string()));
+
+    let mut msg = return_minter_instantiate_msg();
+    msg.collection_details.id = "id2".to_string();
+    // Predicted address of a versioned minter uses the code id of the version
+    let predicted_address: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::PredictMinterAddress {
+                creator: creator.to_string(),
+                collection_id: "id2".to_string(),
+                version: Some("v2".to_string()),
+            },
+        )
+        .unwrap();
+    let default_predicted_address: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::PredictMinterAddress {
+                creator: creator.to_string(),
+                collection_id: "id2".to_string(),
+                version: None,
+            },
+        )
+        .unwrap();
+    assert_ne!(predicted_address, default_predicted_address);
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateVersionedMinter {
+                msg: msg.clone(),
+                version: "v2".to_string(),
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let versioned_minter_address = get_contract_address_from_res(res);
+    assert_eq!(versioned_minter_address, predicted_address.to_string());
+    let created_contract: Option<CreatedContract> = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::CreatedContract {
+                address: versioned_minter_address,
+            },
+        )
+        .unwrap();
+    assert_eq!(created_contract.unwrap().code_id, minter_v2_code_id);
+
+    // Non admin can not migrate minters
+    let migrate_msg = FactoryExecuteMsg::MigrateContracts {
+        contract_type: ContractType::Minter,
+        version: "v2".to_string(),
+        contracts: vec![minter_address.clone()],
+        msg: to_json_binary(&MigrateMsg { factory: None }).unwrap(),
+    };
+    let error = app
+        .execute_contract(creator.clone(), factory_addr.clone(), &migrate_msg, &[])
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(error, &MinterFactoryError::Unauthorized {});
+
+    // Factory can not migrate minters of creators who did not opt in
+    let error = app
+        .execute_contract(admin.clone(), factory_addr.clone(), &migrate_msg, &[])
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterFactoryError::Catalog(CatalogError::NotFactoryAdmin {
+            address: minter_address.clone(),
+        })
+    );
+
+    // Creator lets the factory admin migrate its next minters
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::SetManagedUpgrades { enabled: true },
+            &[],
+        )
+        .unwrap();
+    let managed_upgrades: bool = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::ManagedUpgrades {
+                creator: creator.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(managed_upgrades);
+    let mut managed_msg = return_minter_instantiate_msg();
+    managed_msg.collection_details.id = "id4".to_string();
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter { msg: managed_msg },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let managed_minter_address = get_contract_address_from_res(res);
+    let contract_info = app
+        .wrap()
+        .query_wasm_contract_info(&managed_minter_address)
+        .unwrap();
+    assert_eq!(contract_info.admin, Some(factory_addr.to_string()));
+
+    let migrate_msg = FactoryExecuteMsg::MigrateContracts {
+        contract_type: ContractType::Minter,
+        version: "v2".to_string(),
+        contracts: vec![managed_minter_address.clone()],
+        msg: to_json_binary(&MigrateMsg { factory: None }).unwrap(),
+    };
+    let _res = app
+        .execute_contract(admin.clone(), factory_addr.clone(), &migrate_msg, &[])
+        .unwrap();
+    let contract_info = app
+        .wrap()
+        .query_wasm_contract_info(&managed_minter_address)
+        .unwrap();
+    assert_eq!(contract_info.code_id, minter_v2_code_id);
+    let created_contract: Option<CreatedContract> = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::CreatedContract {
+                address: managed_minter_address,
+            },
+        )
+        .unwrap();
+    let created_contract = created_contract.unwrap();
+    assert_eq!(created_contract.code_id, minter_v2_code_id);
+    assert!(created_contract.factory_admin);
+
+    // Deprecated version can not be used for new minters
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::SetCodeVersionDeprecated {
+                contract_type: ContractType::Minter,
+                version: "v2".to_string(),
+                deprecated: true,
+            },
+            &[],
+        )
+        .unwrap();
+    msg.collection_details.id = "id3".to_string();
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateVersionedMinter {
+                msg: msg.clone(),
+                version: "v2".to_string(),
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterFactoryError::Catalog(CatalogError::VersionDeprecated {
+            version: "v2".to_string(),
+        })
+    );
+
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateVersionedMinter {
+                msg,
+                version: "v3".to_string(),
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterFactoryError::Catalog(CatalogError::VersionNotFound {
+            version: "v3".to_string(),
+        })
+    );
+}
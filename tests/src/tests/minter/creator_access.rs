@@ -0,0 +1,193 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::return_minter_factory_inst_message;
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::setup::setup;
+use cosmwasm_std::{coin, BlockInfo, Timestamp};
+use cw_multi_test::Executor;
+use factory_types::access::{AccessError, CreatorAccessMode, CreatorStatus};
+use omniflix_minter_factory::error::ContractError as MinterFactoryError;
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use omniflix_minter_factory::msg::QueryMsg as MinterFactoryQueryMsg;
+
+#[test]
+fn creator_access() {
+    let res = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let mut app = res.app;
+
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+
+    // Non admin can not change the access mode
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::UpdateCreatorAccessMode {
+                mode: CreatorAccessMode::ApprovedOnly,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(error, &MinterFactoryError::Unauthorized {});
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::UpdateCreatorAccessMode {
+                mode: CreatorAccessMode::ApprovedOnly,
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Unapproved creator can not create a minter
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter {
+                msg: return_minter_instantiate_msg(),
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterFactoryError::Access(AccessError::CreatorNotApproved {})
+    );
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::ApproveCreators {
+                creators: vec![creator.to_string()],
+            },
+            &[],
+        )
+        .unwrap();
+    let status: CreatorStatus = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::CreatorStatus {
+                creator: creator.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(status.approved);
+    assert!(status.can_create);
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter {
+                msg: return_minter_instantiate_msg(),
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+
+    // Unapproved creators can create one minter in every 100 seconds
+    let mode = CreatorAccessMode::RateLimited {
+        max_contracts: 1,
+        window_seconds: 100,
+    };
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::UpdateCreatorAccessMode { mode },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter {
+                msg: return_minter_instantiate_msg(),
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+
+    let mut msg = return_minter_instantiate_msg();
+    msg.collection_details.id = "id2".to_string();
+    let error = app
+        .execute_contract(
+            collector.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter { msg: msg.clone() },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterFactoryError::Access(AccessError::RateLimited {
+            max_contracts: 1,
+            window_seconds: 100,
+        })
+    );
+    let status: CreatorStatus = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::CreatorStatus {
+                creator: collector.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(!status.approved);
+    assert!(!status.can_create);
+    assert_eq!(status.remaining_contracts, Some(0));
+
+    // Approved creator is not limited
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter { msg: msg.clone() },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+
+    // Collector can create another minter once the window has passed
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_seconds(101),
+    });
+    let init = msg.init.as_mut().unwrap();
+    init.start_time = Timestamp::from_seconds(200);
+    init.end_time = Some(Timestamp::from_seconds(300));
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter { msg },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+}
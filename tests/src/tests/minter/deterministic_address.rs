@@ -0,0 +1,106 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::return_minter_factory_inst_message;
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::setup::setup;
+use crate::helpers::utils::get_contract_address_from_res;
+use cosmwasm_std::{coin, Addr};
+use cw_multi_test::Executor;
+use omniflix_minter_factory::error::ContractError as MinterFactoryError;
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use omniflix_minter_factory::msg::QueryMsg as MinterFactoryQueryMsg;
+
+#[test]
+fn deterministic_minter_address() {
+    let res = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let mut app = res.app;
+
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+
+    // Minter address is known before the minter exists
+    let predicted_address: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::PredictMinterAddress {
+                creator: creator.to_string(),
+                collection_id: "id".to_string(),
+                version: None,
+            },
+        )
+        .unwrap();
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter {
+                msg: return_minter_instantiate_msg(),
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res);
+    assert_eq!(minter_address, predicted_address.to_string());
+
+    // Creator can not create another minter with the same collection id
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter {
+                msg: return_minter_instantiate_msg(),
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterFactoryError::SaltCollision {
+            address: minter_address.clone(),
+        }
+    );
+
+    // Same collection id of another creator resolves to another address
+    let predicted_address: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::PredictMinterAddress {
+                creator: collector.to_string(),
+                collection_id: "id".to_string(),
+                version: None,
+            },
+        )
+        .unwrap();
+    assert_ne!(predicted_address.to_string(), minter_address);
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter {
+                msg: return_minter_instantiate_msg(),
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    assert_eq!(
+        get_contract_address_from_res(res),
+        predicted_address.to_string()
+    );
+}
@@ -0,0 +1,153 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::return_minter_factory_inst_message;
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::setup::setup;
+use crate::helpers::utils::get_contract_address_from_res;
+use cosmwasm_std::{coin, Addr};
+use cw_multi_test::Executor;
+use factory_types::registry::{ContractType, CreatedContract};
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use omniflix_minter_factory::msg::QueryMsg as MinterFactoryQueryMsg;
+
+#[test]
+fn factory_registry() {
+    let res = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let mut app = res.app;
+
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+
+    // Creator creates two collections
+    let mut minter_addresses = vec![];
+    for collection_id in ["id1", "id2"] {
+        let mut minter_inst_msg = return_minter_instantiate_msg();
+        minter_inst_msg.collection_details.id = collection_id.to_string();
+        let res = app
+            .execute_contract(
+                creator.clone(),
+                factory_addr.clone(),
+                &FactoryExecuteMsg::CreateMinter {
+                    msg: minter_inst_msg,
+                },
+                &[coin(2000000, "uflix")],
+            )
+            .unwrap();
+        minter_addresses.push(get_contract_address_from_res(res));
+    }
+
+    // Minter is recorded with its creator and collection
+    let created_contract: Option<CreatedContract> = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::CreatedContract {
+                address: minter_addresses[0].clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        created_contract,
+        Some(CreatedContract {
+            address: Addr::unchecked(minter_addresses[0].clone()),
+            creator: creator.clone(),
+            code_id: minter_code_id,
+            contract_type: ContractType::Minter,
+            collection_id: Some("id1".to_string()),
+            created_at: app.block_info().time,
+            factory_admin: false,
+        })
+    );
+
+    // Contracts not created by the factory are not found
+    let created_contract: Option<CreatedContract> = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::CreatedContract {
+                address: collector.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(created_contract, None);
+
+    let contracts: Vec<CreatedContract> = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::ContractsByCreator {
+                creator: creator.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(contracts.len(), 2);
+
+    // Paginate after the first contract
+    let contracts: Vec<CreatedContract> = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::ContractsByCreator {
+                creator: creator.to_string(),
+                start_after: Some(contracts[0].address.to_string()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(contracts.len(), 1);
+
+    let contracts: Vec<CreatedContract> = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::ContractsByCreator {
+                creator: collector.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(contracts.is_empty());
+
+    let contracts: Vec<CreatedContract> = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::ContractsByType {
+                contract_type: ContractType::Minter,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(contracts.len(), 2);
+
+    let contracts: Vec<CreatedContract> = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::ContractsByCollectionId {
+                collection_id: "id2".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(contracts.len(), 1);
+    assert_eq!(contracts[0].address, Addr::unchecked(minter_addresses[1].clone()));
+}
@@ -0,0 +1,151 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::return_minter_factory_inst_message;
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::setup::setup;
+use cosmwasm_std::{coin, Coin, Decimal, Uint128};
+use cw_multi_test::Executor;
+use factory_types::discount::FeeDiscount;
+use factory_types::CustomPaymentError;
+use omniflix_minter_factory::error::ContractError as MinterFactoryError;
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use omniflix_minter_factory::msg::QueryMsg as MinterFactoryQueryMsg;
+
+#[test]
+fn creator_fee_discount() {
+    let res = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let mut app = res.app;
+
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+
+    // Non admin can not set discounts
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::SetCreatorFeeDiscount {
+                creator: creator.to_string(),
+                discount: Some(FeeDiscount::Percentage(Decimal::percent(50))),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(error, &MinterFactoryError::Unauthorized {});
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::SetCreatorFeeDiscount {
+                creator: creator.to_string(),
+                discount: Some(FeeDiscount::Percentage(Decimal::percent(50))),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Discount only applies to the minter creation fee
+    let creator_fee: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::CreatorMinterCreationFee {
+                creator: creator.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(creator_fee, vec![coin(1500000, "uflix")]);
+
+    // Full minter creation fee is rejected
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter {
+                msg: return_minter_instantiate_msg(),
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterFactoryError::PaymentError(CustomPaymentError::InsufficientFunds {
+            expected: [coin(1000000, "uflix"), coin(500000, "uflix")].to_vec(),
+            actual: [coin(2000000, "uflix")].to_vec()
+        })
+    );
+
+    let uflix_before = app
+        .wrap()
+        .query_balance(admin.clone(), "uflix".to_string())
+        .unwrap()
+        .amount;
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter {
+                msg: return_minter_instantiate_msg(),
+            },
+            &[coin(1500000, "uflix")],
+        )
+        .unwrap();
+    let uflix_after = app
+        .wrap()
+        .query_balance(admin.clone(), "uflix".to_string())
+        .unwrap()
+        .amount;
+    assert_eq!(uflix_after - uflix_before, Uint128::from(500000u128));
+
+    // Waived minter creation fee leaves only the collection creation fee
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::SetCreatorFeeDiscount {
+                creator: collector.to_string(),
+                discount: Some(FeeDiscount::Fixed(Uint128::zero())),
+            },
+            &[],
+        )
+        .unwrap();
+    let uflix_before = app
+        .wrap()
+        .query_balance(admin.clone(), "uflix".to_string())
+        .unwrap()
+        .amount;
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter {
+                msg: return_minter_instantiate_msg(),
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+    let uflix_after = app
+        .wrap()
+        .query_balance(admin.clone(), "uflix".to_string())
+        .unwrap()
+        .amount;
+    assert_eq!(uflix_after, uflix_before);
+}
@@ -0,0 +1,301 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::return_minter_factory_inst_message;
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::setup::setup;
+use crate::helpers::utils::get_contract_address_from_res;
+use cosmwasm_std::{coin, to_json_binary, Addr, Binary, BlockInfo, Deps, Env, StdError, StdResult};
+use cw_multi_test::{ContractWrapper, Executor};
+use factory_types::registry::ContractType;
+use minter_types::msg::QueryMsg;
+use omniflix_minter::error::ContractError as MinterContractError;
+use omniflix_minter::msg::{ExecuteMsg as MinterExecuteMsg, MigrateMsg, MinterExtensionQueryMsg};
+use omniflix_minter_factory::contract::{
+    execute as factory_execute, instantiate as factory_instantiate, query as factory_query,
+    reply as factory_reply,
+};
+use omniflix_minter_factory::error::ContractError as MinterFactoryError;
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use omniflix_minter_factory::msg::MigrateMsg as FactoryMigrateMsg;
+use omniflix_minter_factory::msg::QueryMsg as MinterFactoryQueryMsg;
+use pauser::halt::{HaltError, HaltStatus};
+use pauser::PauseError;
+
+type MinterQueryMsg = QueryMsg<MinterExtensionQueryMsg>;
+
+#[test]
+fn global_halt() {
+    let res = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let mut app = res.app;
+
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+
+    let minter_inst_msg = return_minter_instantiate_msg();
+    let init = minter_inst_msg.init.clone().unwrap();
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter {
+                msg: minter_inst_msg,
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = Addr::unchecked(get_contract_address_from_res(res));
+
+    // Minter records the factory that created it
+    let halt_status: HaltStatus = app
+        .wrap()
+        .query_wasm_smart(&minter_address, &MinterQueryMsg::HaltStatus {})
+        .unwrap();
+    assert_eq!(
+        halt_status,
+        HaltStatus {
+            paused: false,
+            factory: Some(factory_addr.clone()),
+            global_halt: false,
+        }
+    );
+
+    // Only factory pausers can halt the minters
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::SetGlobalHalt { halted: true },
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterFactoryError::Halt(HaltError::Pause(PauseError::Unauthorized {
+            sender: creator.clone(),
+        }))
+    );
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::SetGlobalHalt { halted: true },
+            &[],
+        )
+        .unwrap();
+    let global_halt: bool = app
+        .wrap()
+        .query_wasm_smart(&factory_addr, &MinterFactoryQueryMsg::GlobalHalt {})
+        .unwrap();
+    assert!(global_halt);
+    let halt_status: HaltStatus = app
+        .wrap()
+        .query_wasm_smart(&minter_address, &MinterQueryMsg::HaltStatus {})
+        .unwrap();
+    assert!(!halt_status.paused);
+    assert!(halt_status.global_halt);
+
+    // Minting is halted even though the minter is not paused
+    app.set_block(BlockInfo {
+        time: init.start_time,
+        height: 1,
+        chain_id: "test".to_string(),
+    });
+    let error = app
+        .execute_contract(
+            collector.clone(),
+            minter_address.clone(),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[init.mint_price.clone()],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterContractError::Halt(HaltError::Halted {
+            factory: factory_addr.clone(),
+        })
+    );
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::SetGlobalHalt { halted: false },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            minter_address.clone(),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[init.mint_price],
+        )
+        .unwrap();
+}
+
+// Factories from before the global halt do not answer the halt query
+fn legacy_factory_query(deps: Deps, env: Env, msg: MinterFactoryQueryMsg) -> StdResult<Binary> {
+    match msg {
+        MinterFactoryQueryMsg::GlobalHalt {} => Err(StdError::generic_err("unknown variant")),
+        msg => factory_query(deps, env, msg),
+    }
+}
+
+#[test]
+fn global_halt_migrated_minter() {
+    let res = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let mut app = res.app;
+
+    let legacy_factory_code_id = app.store_code(Box::new(
+        ContractWrapper::new(factory_execute, factory_instantiate, legacy_factory_query)
+            .with_reply(factory_reply),
+    ));
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            legacy_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            Some(admin.to_string()),
+        )
+        .unwrap();
+
+    // Creator lets the factory admin migrate its minters
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::SetManagedUpgrades { enabled: true },
+            &[],
+        )
+        .unwrap();
+    let minter_inst_msg = return_minter_instantiate_msg();
+    let init = minter_inst_msg.init.clone().unwrap();
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter {
+                msg: minter_inst_msg,
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let halt_factory = res
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attribute| attribute.key == "halt_factory")
+        .unwrap()
+        .value
+        .clone();
+    assert_eq!(halt_factory, "none");
+    let minter_address = Addr::unchecked(get_contract_address_from_res(res));
+
+    // Minter created before the global halt has no factory to check
+    let halt_status: HaltStatus = app
+        .wrap()
+        .query_wasm_smart(&minter_address, &MinterQueryMsg::HaltStatus {})
+        .unwrap();
+    assert_eq!(halt_status.factory, None);
+
+    // Factory is upgraded, then migrates the minter and passes itself as its factory
+    let _res = app
+        .migrate_contract(
+            admin.clone(),
+            factory_addr.clone(),
+            &FactoryMigrateMsg {},
+            minter_factory_code_id,
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::AddCodeVersion {
+                contract_type: ContractType::Minter,
+                version: "v1".to_string(),
+                code_id: minter_code_id,
+                label: "minter".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::MigrateContracts {
+                contract_type: ContractType::Minter,
+                version: "v1".to_string(),
+                contracts: vec![minter_address.to_string()],
+                msg: to_json_binary(&MigrateMsg {
+                    factory: Some(factory_addr.to_string()),
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+    let halt_status: HaltStatus = app
+        .wrap()
+        .query_wasm_smart(&minter_address, &MinterQueryMsg::HaltStatus {})
+        .unwrap();
+    assert_eq!(halt_status.factory, Some(factory_addr.clone()));
+
+    // Migrated minter is halted with the other minters of the factory
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::SetGlobalHalt { halted: true },
+            &[],
+        )
+        .unwrap();
+    app.set_block(BlockInfo {
+        time: init.start_time,
+        height: 1,
+        chain_id: "test".to_string(),
+    });
+    let error = app
+        .execute_contract(
+            collector.clone(),
+            minter_address.clone(),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[init.mint_price],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterContractError::Halt(HaltError::Halted {
+            factory: factory_addr,
+        })
+    );
+}
@@ -0,0 +1,352 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::{
+    return_minter_factory_inst_message, return_round_whitelist_factory_inst_message,
+};
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::mock_messages::whitelist_mock_messages::return_round_configs;
+use crate::helpers::setup::setup;
+use cosmwasm_std::{coin, Addr, BlockInfo, Coin, Decimal, Empty, Timestamp, Uint128};
+use cw_multi_test::{AppResponse, Executor};
+use factory_types::catalog::CatalogError;
+use factory_types::discount::FeeDiscount;
+use factory_types::registry::CreatedContract;
+use factory_types::CustomPaymentError;
+use minter_types::collection_details::CollectionDetailsError;
+use minter_types::config::Config as MinterConfig;
+use minter_types::msg::QueryMsg as MinterQueryMsg;
+use omniflix_minter::msg::ExecuteMsg as MinterExecuteMsg;
+use omniflix_minter_factory::error::ContractError as MinterFactoryError;
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use omniflix_minter_factory::msg::QueryMsg as MinterFactoryQueryMsg;
+use omniflix_round_whitelist_factory::error::ContractError as WhitelistFactoryError;
+use omniflix_round_whitelist_factory::msg::{
+    ExecuteMsg as WhitelistFactoryExecuteMsg, QueryMsg as WhitelistFactoryQueryMsg,
+};
+use whitelist_types::CreateWhitelistMsg;
+
+fn attribute_value(res: &AppResponse, key: &str) -> String {
+    res.events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attribute| attribute.key == key)
+        .unwrap()
+        .value
+        .clone()
+}
+
+#[test]
+fn create_launch() {
+    let res = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let round_whitelist_factory_code_id = res.round_whitelist_factory_code_id;
+    let round_whitelist_code_id = res.round_whitelist_code_id;
+    let mut app = res.app;
+
+    let round_whitelist_factory_inst_msg =
+        return_round_whitelist_factory_inst_message(round_whitelist_code_id);
+    let round_whitelist_factory_addr = app
+        .instantiate_contract(
+            round_whitelist_factory_code_id,
+            admin.clone(),
+            &round_whitelist_factory_inst_msg,
+            &[],
+            "round_whitelist_factory",
+            None,
+        )
+        .unwrap();
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+
+    let create_launch_msg = FactoryExecuteMsg::CreateLaunch {
+        whitelist: CreateWhitelistMsg {
+            admin: creator.to_string(),
+            rounds: return_round_configs(),
+            minters: None,
+        },
+        minter: return_minter_instantiate_msg(),
+        version: None,
+    };
+
+    // Launches can not be created until the whitelist factory is set
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &create_launch_msg,
+            &[coin(3000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(error, &MinterFactoryError::WhitelistFactoryNotSet {});
+
+    // Non admin can not set the whitelist factory
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::UpdateWhitelistFactoryAddress {
+                whitelist_factory_address: Some(round_whitelist_factory_addr.to_string()),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(error, &MinterFactoryError::Unauthorized {});
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::UpdateWhitelistFactoryAddress {
+                whitelist_factory_address: Some(round_whitelist_factory_addr.to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Launch fee covers the collection, minter and whitelist creation fees
+    let launch_creation_fee: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::LaunchCreationFee {
+                creator: creator.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(launch_creation_fee, vec![coin(3000000, "uflix")]);
+
+    // Whitelist creation fee is missing
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &create_launch_msg,
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterFactoryError::PaymentError(CustomPaymentError::InsufficientFunds {
+            expected: [
+                coin(1000000, "uflix"),
+                coin(1000000, "uflix"),
+                coin(1000000, "uflix"),
+            ]
+            .to_vec(),
+            actual: [coin(2000000, "uflix")].to_vec()
+        })
+    );
+
+    // Whitelist factory only creates whitelists for others for allowed factories
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &create_launch_msg,
+            &[coin(3000000, "uflix")],
+        )
+        .unwrap_err();
+    let error = error.root_cause().downcast_ref::<WhitelistFactoryError>().unwrap();
+    assert_eq!(error, &WhitelistFactoryError::FactoryNotAllowed {});
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            round_whitelist_factory_addr.clone(),
+            &WhitelistFactoryExecuteMsg::UpdateAllowedFactories {
+                add: vec![factory_addr.to_string()],
+                remove: vec![],
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Minter without init is rejected before the whitelist is created
+    let mut minter_msg = return_minter_instantiate_msg();
+    minter_msg.init = None;
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateLaunch {
+                whitelist: CreateWhitelistMsg {
+                    admin: creator.to_string(),
+                    rounds: return_round_configs(),
+                    minters: None,
+                },
+                minter: minter_msg,
+                version: None,
+            },
+            &[coin(3000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(error, &MinterFactoryError::InitMissing {});
+
+    // Minter is checked like a minter created on its own
+    let mut minter_msg = return_minter_instantiate_msg();
+    minter_msg.collection_details.collection_name = "a".repeat(257);
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateLaunch {
+                whitelist: CreateWhitelistMsg {
+                    admin: creator.to_string(),
+                    rounds: return_round_configs(),
+                    minters: None,
+                },
+                minter: minter_msg,
+                version: None,
+            },
+            &[coin(3000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterFactoryError::CollectionDetails(CollectionDetailsError::InvalidCollectionName {})
+    );
+
+    // Versions are resolved from the code catalog
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateLaunch {
+                whitelist: CreateWhitelistMsg {
+                    admin: creator.to_string(),
+                    rounds: return_round_configs(),
+                    minters: None,
+                },
+                minter: return_minter_instantiate_msg(),
+                version: Some("v2".to_string()),
+            },
+            &[coin(3000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterFactoryError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterFactoryError::Catalog(CatalogError::VersionNotFound {
+            version: "v2".to_string(),
+        })
+    );
+
+    let uflix_before = app
+        .wrap()
+        .query_balance(admin.clone(), "uflix".to_string())
+        .unwrap()
+        .amount;
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &create_launch_msg,
+            &[coin(3000000, "uflix")],
+        )
+        .unwrap();
+    let whitelist_address = attribute_value(&res, "whitelist_address");
+    let minter_address = attribute_value(&res, "minter_address");
+
+    // Minter and whitelist creation fees are collected
+    let uflix_after = app
+        .wrap()
+        .query_balance(admin.clone(), "uflix".to_string())
+        .unwrap()
+        .amount;
+    assert_eq!(uflix_after - uflix_before, Uint128::from(2000000u128));
+
+    // Minter is wired to the whitelist
+    let config: MinterConfig = app
+        .wrap()
+        .query_wasm_smart(&minter_address, &MinterQueryMsg::<Empty>::Config {})
+        .unwrap();
+    assert_eq!(
+        config.whitelist_address,
+        Some(Addr::unchecked(&whitelist_address))
+    );
+
+    // Minter is recorded for the creator
+    let created_contract: Option<CreatedContract> = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::CreatedContract {
+                address: minter_address.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(created_contract.unwrap().creator, creator);
+
+    // Whitelist is recorded for the creator, not the minter factory
+    let created_contract: Option<CreatedContract> = app
+        .wrap()
+        .query_wasm_smart(
+            &round_whitelist_factory_addr,
+            &WhitelistFactoryQueryMsg::CreatedContract {
+                address: whitelist_address.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(created_contract.unwrap().creator, creator);
+
+    // Whitelist fee discount of the creator applies to launches
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            round_whitelist_factory_addr.clone(),
+            &WhitelistFactoryExecuteMsg::SetCreatorFeeDiscount {
+                creator: creator.to_string(),
+                discount: Some(FeeDiscount::Percentage(Decimal::one())),
+            },
+            &[],
+        )
+        .unwrap();
+    let launch_creation_fee: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::LaunchCreationFee {
+                creator: creator.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(launch_creation_fee, vec![coin(2000000, "uflix")]);
+
+    // Whitelist accepts the minter without adding it afterwards
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_nanos(2_000 + 1),
+    });
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(1000000, "diffirent_denom")],
+        )
+        .unwrap();
+}
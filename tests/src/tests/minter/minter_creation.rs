@@ -343,6 +343,7 @@ fn test_minter_creation_with_whitelist() {
     let round_whitelist_inst_msg = CreateWhitelistMsg {
         admin: admin.to_string(),
         rounds: rounds.clone(),
+        minters: None,
     };
     let create_round_whitelist_msg =
         omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
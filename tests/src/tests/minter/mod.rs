@@ -1,4 +1,18 @@
 #[cfg(test)]
+mod code_versions;
+#[cfg(test)]
+mod creator_access;
+#[cfg(test)]
+mod deterministic_address;
+#[cfg(test)]
+mod fee_discount;
+#[cfg(test)]
+mod global_halt;
+#[cfg(test)]
+mod launch;
+#[cfg(test)]
+mod factory_registry;
+#[cfg(test)]
 mod minter_creation;
 #[cfg(test)]
 mod pause_minter;
@@ -8,6 +22,14 @@ mod paused_factory;
 mod private_minting;
 #[cfg(test)]
 mod public_minting;
+#[cfg(test)]
+mod validate_create;
 
 #[cfg(test)]
 mod admin_configurations;
+#[cfg(test)]
+mod roles;
+#[cfg(test)]
+mod timelock;
+#[cfg(test)]
+mod sale_config;
@@ -72,7 +72,7 @@ fn pause_minter() {
     });
 
     // Mint a token
-    let mint_msg = MinterExecuteMsg::Mint {};
+    let mint_msg = MinterExecuteMsg::Mint { proof: None };
 
     let _res = app
         .execute_contract(
@@ -141,7 +141,7 @@ fn pause_minter() {
     let error = err.downcast_ref::<MinterContractError>().unwrap();
     assert_eq!(error, &MinterContractError::Pause(PauseError::Paused {}));
 
-    let mint_msg = MinterExecuteMsg::Mint {};
+    let mint_msg = MinterExecuteMsg::Mint { proof: None };
     let res = app
         .execute_contract(
             creator.clone(),
@@ -14,7 +14,7 @@ use crate::helpers::mock_messages::factory_mock_messages::{
 };
 use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
 use crate::helpers::mock_messages::whitelist_mock_messages::return_round_configs;
-use crate::helpers::utils::get_contract_address_from_res;
+use crate::helpers::utils::{add_whitelist_minter, get_contract_address_from_res};
 
 use crate::{helpers::setup::setup, helpers::utils::query_onft_collection};
 use omniflix_minter::error::ContractError as MinterContractError;
@@ -68,6 +68,7 @@ fn minter_private_minting() {
     let round_whitelist_inst_msg = CreateWhitelistMsg {
         admin: admin.to_string(),
         rounds: rounds.clone(),
+        minters: None,
     };
     let create_round_whitelist_msg =
         omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
@@ -103,13 +104,14 @@ fn minter_private_minting() {
         )
         .unwrap();
     let minter_address = get_contract_address_from_res(res.clone());
+    add_whitelist_minter(&mut app, &admin, &round_whitelist_address, &minter_address);
 
     // Try minting when whitelist is not active
     let error = app
         .execute_contract(
             creator.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(1000000, "uflix")],
         )
         .unwrap_err();
@@ -128,7 +130,7 @@ fn minter_private_minting() {
         .execute_contract(
             creator.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(1000000, "uflix")],
         )
         .unwrap_err();
@@ -141,7 +143,7 @@ fn minter_private_minting() {
         .execute_contract(
             collector.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(1000000, "uflix")],
         )
         .unwrap_err();
@@ -156,7 +158,7 @@ fn minter_private_minting() {
         .execute_contract(
             collector.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(100000 + 1, "diffirent_denom")],
         )
         .unwrap_err();
@@ -175,7 +177,7 @@ fn minter_private_minting() {
         .execute_contract(
             collector.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[round_1_price.clone()],
         )
         .unwrap();
@@ -208,7 +210,7 @@ fn minter_private_minting() {
         .execute_contract(
             collector.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[round_1_price.clone()],
         )
         .unwrap_err();
@@ -231,7 +233,7 @@ fn minter_private_minting() {
         .execute_contract(
             collector.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(1000000, "diffirent_denom")],
         )
         .unwrap_err();
@@ -251,7 +253,7 @@ fn minter_private_minting() {
         .execute_contract(
             collector.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[round_2_price.clone()],
         )
         .unwrap_err();
@@ -264,7 +266,7 @@ fn minter_private_minting() {
         .execute_contract(
             creator.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[round_2_price.clone()],
         )
         .unwrap();
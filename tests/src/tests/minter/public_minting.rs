@@ -104,7 +104,7 @@ fn minter_public_minting() {
         .execute_contract(
             creator.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[public_minting_price.clone()],
         )
         .unwrap_err();
@@ -130,7 +130,7 @@ fn minter_public_minting() {
         .execute_contract(
             creator.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(1000000, "incorrect_denom")],
         )
         .unwrap_err();
@@ -148,7 +148,7 @@ fn minter_public_minting() {
         .execute_contract(
             creator.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(100000, "uflix")],
         )
         .unwrap_err();
@@ -174,7 +174,7 @@ fn minter_public_minting() {
         .execute_contract(
             creator.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[public_minting_price.clone()],
         )
         .unwrap_err();
@@ -200,7 +200,7 @@ fn minter_public_minting() {
         .execute_contract(
             collector.clone(),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[public_minting_price.clone()],
         )
         .unwrap();
@@ -262,7 +262,7 @@ fn minter_public_minting() {
         .execute_contract(
             Addr::unchecked(collector.clone()),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[public_minting_price.clone()],
         )
         .unwrap_err();
@@ -285,7 +285,7 @@ fn minter_public_minting() {
             .execute_contract(
                 collector.clone(),
                 Addr::unchecked(minter_address.clone()),
-                &MinterExecuteMsg::Mint {},
+                &MinterExecuteMsg::Mint { proof: None },
                 &[public_minting_price.clone()],
             )
             .unwrap();
@@ -353,7 +353,7 @@ fn minter_public_minting() {
         .execute_contract(
             Addr::unchecked("collector1001".to_string()),
             Addr::unchecked(minter_address.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[public_minting_price.clone()],
         )
         .unwrap_err();
@@ -0,0 +1,346 @@
+use cosmwasm_std::{coin, Addr};
+use cw_multi_test::Executor;
+use cw_ownable::Action;
+
+use minter_types::msg::QueryMsg;
+use minter_types::roles::Role;
+use minter_types::types::AuthDetails;
+
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+
+use crate::helpers::mock_messages::factory_mock_messages::return_minter_factory_inst_message;
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::utils::get_contract_address_from_res;
+
+use crate::helpers::setup::setup;
+use omniflix_minter::error::ContractError as MinterContractError;
+use omniflix_minter::msg::ExecuteMsg as MinterExecuteMsg;
+use omniflix_minter::msg::MinterExtensionQueryMsg;
+type MinterQueryMsg = QueryMsg<MinterExtensionQueryMsg>;
+
+#[test]
+fn roles() {
+    let res = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let mut app = res.app;
+
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+
+    let minter_inst_msg = return_minter_instantiate_msg();
+    let create_minter_msg = FactoryExecuteMsg::CreateMinter {
+        msg: minter_inst_msg.clone(),
+    };
+
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr,
+            &create_minter_msg,
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res.clone());
+
+    // Non admin can not grant roles
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::GrantRole {
+                role: Role::Finance,
+                address: collector.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::Unauthorized {});
+
+    // Collector can not update mint price without finance role
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateMintPrice {
+                mint_price: coin(5_000_000, "uflix"),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::Unauthorized {});
+
+    // Admin grants finance role to collector
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::GrantRole {
+                role: Role::Finance,
+                address: collector.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let members: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            minter_address.clone(),
+            &MinterQueryMsg::RoleMembers {
+                role: Role::Finance,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(members, vec![collector.clone()]);
+
+    let roles: Vec<Role> = app
+        .wrap()
+        .query_wasm_smart(
+            minter_address.clone(),
+            &MinterQueryMsg::AddressRoles {
+                address: collector.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(roles, vec![Role::Finance]);
+
+    // Finance role can update mint price and payment collector
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateMintPrice {
+                mint_price: coin(5_000_000, "uflix"),
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdatePaymentCollector {
+                payment_collector: collector.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    let auth_details: AuthDetails = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::AuthDetails {})
+        .unwrap();
+    assert_eq!(auth_details.payment_collector, collector);
+    assert_eq!(auth_details.admin, creator);
+
+    // Finance role can not act as ops or airdrop
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateDenom {
+                collection_name: Some("new_name".to_string()),
+                description: None,
+                preview_uri: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::Unauthorized {});
+
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::MintAdmin {
+                recipient: collector.to_string(),
+                token_id: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::Unauthorized {});
+
+    // Ops role can pause and unpause without being a pauser
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::GrantRole {
+                role: Role::Ops,
+                address: admin.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Pause {},
+            &[],
+        )
+        .unwrap();
+    let is_paused: bool = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::IsPaused {})
+        .unwrap();
+    assert!(is_paused);
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Unpause {},
+            &[],
+        )
+        .unwrap();
+    let is_paused: bool = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::IsPaused {})
+        .unwrap();
+    assert!(!is_paused);
+
+    // Airdrop role can mint with MintAdmin
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::GrantRole {
+                role: Role::Airdrop,
+                address: admin.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::MintAdmin {
+                recipient: collector.to_string(),
+                token_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Revoke finance role
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::RevokeRole {
+                role: Role::Finance,
+                address: collector.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateMintPrice {
+                mint_price: coin(1_000_000, "uflix"),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::Unauthorized {});
+
+    // Can not revoke a role that is not granted
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::RevokeRole {
+                role: Role::Finance,
+                address: collector.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::RoleNotFound {});
+
+    // Roles granted by the previous owner are revoked when ownership is accepted
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::GrantRole {
+                role: Role::Airdrop,
+                address: collector.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateOwnership(Action::TransferOwnership {
+                new_owner: admin.to_string(),
+                expiry: None,
+            }),
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateOwnership(Action::AcceptOwnership),
+            &[],
+        )
+        .unwrap();
+    let roles: Vec<Role> = app
+        .wrap()
+        .query_wasm_smart(
+            minter_address.clone(),
+            &MinterQueryMsg::AddressRoles {
+                address: collector.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(roles.is_empty());
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::MintAdmin {
+                recipient: collector.to_string(),
+                token_id: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::Unauthorized {});
+}
@@ -0,0 +1,300 @@
+use cosmwasm_std::{coin, Addr, BlockInfo, Timestamp};
+use cw_multi_test::Executor;
+
+use minter_types::config::{Config, ConfigurationError};
+use minter_types::msg::QueryMsg;
+
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+
+use crate::helpers::mock_messages::factory_mock_messages::return_minter_factory_inst_message;
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::utils::get_contract_address_from_res;
+
+use crate::helpers::setup::setup;
+use omniflix_minter::error::ContractError as MinterContractError;
+use omniflix_minter::msg::ExecuteMsg as MinterExecuteMsg;
+use omniflix_minter::msg::MinterExtensionQueryMsg;
+type MinterQueryMsg = QueryMsg<MinterExtensionQueryMsg>;
+
+#[test]
+fn update_sale_config() {
+    let res = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let mut app = res.app;
+
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+
+    let minter_inst_msg = return_minter_instantiate_msg();
+    let create_minter_msg = FactoryExecuteMsg::CreateMinter {
+        msg: minter_inst_msg.clone(),
+    };
+
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr,
+            &create_minter_msg,
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res.clone());
+
+    // Non admin can not update sale config
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateSaleConfig {
+                start_time: Some(Timestamp::from_seconds(5)),
+                end_time: None,
+                per_address_limit: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::Unauthorized {});
+
+    // Start time can not be in the past
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateSaleConfig {
+                start_time: Some(Timestamp::from_nanos(0)),
+                end_time: None,
+                per_address_limit: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterContractError::ConfigurationError(ConfigurationError::InvalidStartTime {})
+    );
+
+    // End time can not be before start time
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateSaleConfig {
+                start_time: Some(Timestamp::from_seconds(5)),
+                end_time: Some(Some(Timestamp::from_seconds(4))),
+                per_address_limit: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterContractError::ConfigurationError(ConfigurationError::InvalidEndTime {})
+    );
+
+    // Before minting starts every field can be updated
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateSaleConfig {
+                start_time: Some(Timestamp::from_seconds(5)),
+                end_time: Some(Some(Timestamp::from_seconds(20))),
+                per_address_limit: Some(Some(2)),
+            },
+            &[],
+        )
+        .unwrap();
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.start_time, Timestamp::from_seconds(5));
+    assert_eq!(config.end_time, Some(Timestamp::from_seconds(20)));
+    assert_eq!(config.per_address_limit, Some(2));
+
+    // End time and per address limit can be cleared
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateSaleConfig {
+                start_time: None,
+                end_time: Some(None),
+                per_address_limit: Some(None),
+            },
+            &[],
+        )
+        .unwrap();
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.end_time, None);
+    assert_eq!(config.per_address_limit, None);
+
+    // Missing fields keep the current values
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateSaleConfig {
+                start_time: None,
+                end_time: Some(Some(Timestamp::from_seconds(20))),
+                per_address_limit: Some(Some(2)),
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateSaleConfig {
+                start_time: Some(Timestamp::from_seconds(5)),
+                end_time: None,
+                per_address_limit: None,
+            },
+            &[],
+        )
+        .unwrap();
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.end_time, Some(Timestamp::from_seconds(20)));
+    assert_eq!(config.per_address_limit, Some(2));
+
+    // Minting starts
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_seconds(10),
+    });
+
+    // Start time is locked
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateSaleConfig {
+                start_time: Some(Timestamp::from_seconds(15)),
+                end_time: None,
+                per_address_limit: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterContractError::ConfigurationError(ConfigurationError::StartTimeLocked {})
+    );
+
+    // End time can not be shortened
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateSaleConfig {
+                start_time: None,
+                end_time: Some(Some(Timestamp::from_seconds(15))),
+                per_address_limit: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterContractError::ConfigurationError(ConfigurationError::EndTimeNotExtended {})
+    );
+
+    // Per address limit can not be decreased
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateSaleConfig {
+                start_time: None,
+                end_time: None,
+                per_address_limit: Some(Some(1)),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterContractError::ConfigurationError(ConfigurationError::PerAddressLimitNotIncreased {})
+    );
+
+    // End time can be extended and per address limit increased
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateSaleConfig {
+                start_time: Some(Timestamp::from_seconds(5)),
+                end_time: Some(Some(Timestamp::from_seconds(30))),
+                per_address_limit: Some(Some(5)),
+            },
+            &[],
+        )
+        .unwrap();
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.start_time, Timestamp::from_seconds(5));
+    assert_eq!(config.end_time, Some(Timestamp::from_seconds(30)));
+    assert_eq!(config.per_address_limit, Some(5));
+
+    // Sale ends
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_seconds(31),
+    });
+
+    // Nothing can be changed after the sale has ended
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateSaleConfig {
+                start_time: None,
+                end_time: Some(None),
+                per_address_limit: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterContractError::ConfigurationError(ConfigurationError::SaleEnded {})
+    );
+}
@@ -0,0 +1,358 @@
+use cosmwasm_std::{coin, Addr, BlockInfo, Timestamp};
+use cw_multi_test::Executor;
+
+use minter_types::config::Config;
+use minter_types::msg::QueryMsg;
+use minter_types::timelock::{ConfigChange, PendingChange, TimelockError, DEFAULT_TIMELOCK_DURATION};
+use minter_types::types::AuthDetails;
+
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+
+use crate::helpers::mock_messages::factory_mock_messages::return_minter_factory_inst_message;
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::utils::get_contract_address_from_res;
+
+use crate::helpers::setup::setup;
+use omniflix_minter::error::ContractError as MinterContractError;
+use omniflix_minter::msg::ExecuteMsg as MinterExecuteMsg;
+use omniflix_minter::msg::MinterExtensionQueryMsg;
+type MinterQueryMsg = QueryMsg<MinterExtensionQueryMsg>;
+
+#[test]
+fn timelocked_config_changes() {
+    let res = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let mut app = res.app;
+
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+
+    let minter_inst_msg = return_minter_instantiate_msg();
+    let create_minter_msg = FactoryExecuteMsg::CreateMinter {
+        msg: minter_inst_msg.clone(),
+    };
+
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr,
+            &create_minter_msg,
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res.clone());
+
+    // Non admin can not set the timelock duration
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateTimelockDuration { duration: 100 },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::Unauthorized {});
+
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateTimelockDuration { duration: 100 },
+            &[],
+        )
+        .unwrap();
+    let duration: u64 = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::TimelockDuration {})
+        .unwrap();
+    assert_eq!(duration, 100);
+
+    // Before minting starts changes are applied immediately
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateMintPrice {
+                mint_price: coin(2_000_000, "uflix"),
+            },
+            &[],
+        )
+        .unwrap();
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.mint_price, coin(2_000_000, "uflix"));
+
+    // Minting starts
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_seconds(10),
+    });
+
+    // Timelock duration can not be decreased after minting has started
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateTimelockDuration { duration: 10 },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterContractError::Timelock(TimelockError::TimelockDurationDecreased {})
+    );
+
+    // Mint price change is queued
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateMintPrice {
+                mint_price: coin(5_000_000, "uflix"),
+            },
+            &[],
+        )
+        .unwrap();
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.mint_price, coin(2_000_000, "uflix"));
+
+    let pending_changes: Vec<PendingChange> = app
+        .wrap()
+        .query_wasm_smart(
+            minter_address.clone(),
+            &MinterQueryMsg::PendingChanges {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        pending_changes,
+        vec![PendingChange {
+            id: 1,
+            change: ConfigChange::MintPrice {
+                mint_price: coin(5_000_000, "uflix"),
+            },
+            mint_instance_id: None,
+            queued_at: Timestamp::from_seconds(10),
+            effective_time: Timestamp::from_seconds(110),
+        }]
+    );
+
+    // Can not be executed before the effective time
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::ExecutePendingChange { change_id: 1 },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterContractError::Timelock(TimelockError::PendingChangeNotReady {
+            effective_time: Timestamp::from_seconds(110)
+        })
+    );
+
+    // Payment collector change is queued and then cancelled
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdatePaymentCollector {
+                payment_collector: collector.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    // Non admin can not cancel
+    let res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::CancelPendingChange { change_id: 2 },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::Unauthorized {});
+
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::CancelPendingChange { change_id: 2 },
+            &[],
+        )
+        .unwrap();
+
+    // Timelock passes
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_seconds(110),
+    });
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::ExecutePendingChange { change_id: 1 },
+            &[],
+        )
+        .unwrap();
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.mint_price, coin(5_000_000, "uflix"));
+
+    // Cancelled change can not be executed
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::ExecutePendingChange { change_id: 2 },
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(
+        error,
+        &MinterContractError::Timelock(TimelockError::PendingChangeNotFound {})
+    );
+    let auth_details: AuthDetails = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::AuthDetails {})
+        .unwrap();
+    assert_eq!(auth_details.payment_collector, creator);
+
+    let pending_changes: Vec<PendingChange> = app
+        .wrap()
+        .query_wasm_smart(
+            minter_address.clone(),
+            &MinterQueryMsg::PendingChanges {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(pending_changes.is_empty());
+}
+
+#[test]
+fn timelock_enabled_by_default() {
+    let res = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let mut app = res.app;
+
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+
+    // Creator does not set a timelock duration
+    let minter_inst_msg = return_minter_instantiate_msg();
+    let create_minter_msg = FactoryExecuteMsg::CreateMinter {
+        msg: minter_inst_msg.clone(),
+    };
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr,
+            &create_minter_msg,
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res.clone());
+
+    let duration: u64 = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::TimelockDuration {})
+        .unwrap();
+    assert_eq!(duration, DEFAULT_TIMELOCK_DURATION);
+
+    // Minting starts
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_seconds(10),
+    });
+
+    // Mint price change is queued
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::UpdateMintPrice {
+                mint_price: coin(5_000_000, "uflix"),
+            },
+            &[],
+        )
+        .unwrap();
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &MinterQueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.mint_price, minter_inst_msg.init.unwrap().mint_price);
+
+    let pending_changes: Vec<PendingChange> = app
+        .wrap()
+        .query_wasm_smart(
+            minter_address.clone(),
+            &MinterQueryMsg::PendingChanges {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        pending_changes,
+        vec![PendingChange {
+            id: 1,
+            change: ConfigChange::MintPrice {
+                mint_price: coin(5_000_000, "uflix"),
+            },
+            mint_instance_id: None,
+            queued_at: Timestamp::from_seconds(10),
+            effective_time: Timestamp::from_seconds(10 + DEFAULT_TIMELOCK_DURATION),
+        }]
+    );
+}
@@ -0,0 +1,147 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::return_minter_factory_inst_message;
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::setup::setup;
+use crate::helpers::utils::get_contract_address_from_res;
+use cosmwasm_std::coin;
+use cw_multi_test::{ContractWrapper, Executor};
+use factory_types::registry::ContractType;
+use factory_types::validation::ValidationResponse;
+use omniflix_minter::contract::{
+    execute as minter_execute, instantiate as minter_instantiate, query as minter_query,
+};
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use omniflix_minter_factory::msg::QueryMsg as MinterFactoryQueryMsg;
+
+#[test]
+fn validate_create_minter() {
+    let res = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let mut app = res.app;
+
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+
+    // Valid message returns the funds CreateMinter must be sent with
+    let minter_inst_msg = return_minter_instantiate_msg();
+    let validation: ValidationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::ValidateCreateMinter {
+                creator: creator.to_string(),
+                msg: minter_inst_msg.clone(),
+                version: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        validation,
+        ValidationResponse {
+            valid: true,
+            errors: vec![],
+            required_funds: vec![coin(2000000, "uflix")],
+        }
+    );
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::CreateMinter {
+                msg: minter_inst_msg.clone(),
+            },
+            &validation.required_funds,
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res);
+
+    // Versioned minters are checked with the code id of the version
+    let validation: ValidationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::ValidateCreateMinter {
+                creator: creator.to_string(),
+                msg: minter_inst_msg.clone(),
+                version: Some("v2".to_string()),
+            },
+        )
+        .unwrap();
+    assert_eq!(validation.errors, vec!["Version v2 not found".to_string()]);
+    let minter_v2_code_id = app.store_code(Box::new(ContractWrapper::new(
+        minter_execute,
+        minter_instantiate,
+        minter_query,
+    )));
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr.clone(),
+            &FactoryExecuteMsg::AddCodeVersion {
+                contract_type: ContractType::Minter,
+                version: "v2".to_string(),
+                code_id: minter_v2_code_id,
+                label: "Minter v2".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    // Collection id is only used by the minter of the default code id
+    let validation: ValidationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::ValidateCreateMinter {
+                creator: creator.to_string(),
+                msg: minter_inst_msg.clone(),
+                version: Some("v2".to_string()),
+            },
+        )
+        .unwrap();
+    assert!(validation.valid);
+
+    // Every problem of the message is returned at once
+    let mut msg = minter_inst_msg;
+    msg.collection_details.collection_name = "a".repeat(257);
+    msg.token_details = None;
+    let mut init = msg.init.clone().unwrap();
+    init.per_address_limit = Some(0);
+    msg.init = Some(init);
+    let validation: ValidationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &factory_addr,
+            &MinterFactoryQueryMsg::ValidateCreateMinter {
+                creator: creator.to_string(),
+                msg,
+                version: None,
+            },
+        )
+        .unwrap();
+    assert!(!validation.valid);
+    assert_eq!(
+        validation.errors,
+        vec![
+            format!(
+                "Collection id is already used by the creator, minter exists at {}",
+                minter_address
+            ),
+            "Invalid collection name".to_string(),
+            "Invalid token details".to_string(),
+            "Invalid per address limit".to_string(),
+        ]
+    );
+    assert_eq!(validation.required_funds, vec![coin(2000000, "uflix")]);
+}
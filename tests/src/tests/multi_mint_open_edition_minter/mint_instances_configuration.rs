@@ -74,6 +74,7 @@ fn remove_mint_instance() {
             payment_collector: Addr::unchecked("creator".to_string()),
         },
         init: Default::default(),
+        timelock_duration: None,
     };
 
     let res = app
@@ -103,6 +104,7 @@ fn remove_mint_instance() {
         description: Some("MintInstance number 1 description".to_string()),
         preview_uri: Some("MintInstance number 1 prev uri".to_string()),
         base_token_uri: "MintInstance number 1 base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
@@ -164,6 +166,7 @@ fn remove_mint_instance() {
         Addr::unchecked(multi_minter_addr.clone()),
         &MultiMintOpenEditionMinterExecuteMsg::Mint {
             mint_instance_id: None,
+            proof: None,
         },
         &[coin(5_000_000, "uflix")],
     )
@@ -215,6 +218,7 @@ fn remove_mint_instance() {
         description: Some("MintInstance number 2 description".to_string()),
         preview_uri: Some("MintInstance number 2 prev uri".to_string()),
         base_token_uri: "MintInstance number 2 base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
@@ -258,6 +262,7 @@ fn remove_mint_instance() {
         description: Some("MintInstance number 3 description".to_string()),
         preview_uri: Some("MintInstance number 3 prev uri".to_string()),
         base_token_uri: "MintInstance number 3 base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
@@ -321,6 +326,7 @@ fn remove_mint_instance() {
         description: Some("MintInstance number 4 description".to_string()),
         preview_uri: Some("MintInstance number 4 prev uri".to_string()),
         base_token_uri: "MintInstance number 4 base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
@@ -444,6 +450,7 @@ fn remove_non_active_mint_instance() {
             payment_collector: Addr::unchecked("creator".to_string()),
         },
         init: Default::default(),
+        timelock_duration: None,
     };
 
     let res = app
@@ -473,6 +480,7 @@ fn remove_non_active_mint_instance() {
         description: Some("MintInstance number 1 description".to_string()),
         preview_uri: Some("MintInstance number 1 prev uri".to_string()),
         base_token_uri: "MintInstance number 1 base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
@@ -505,6 +513,7 @@ fn remove_non_active_mint_instance() {
         description: Some("MintInstance number 2 description".to_string()),
         preview_uri: Some("MintInstance number 2 prev uri".to_string()),
         base_token_uri: "MintInstance number 2 base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
@@ -539,6 +548,7 @@ fn remove_non_active_mint_instance() {
         description: Some("MintInstance number 3 description".to_string()),
         preview_uri: Some("MintInstance number 3 prev uri".to_string()),
         base_token_uri: "MintInstance number 3 base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
@@ -577,6 +587,7 @@ fn remove_non_active_mint_instance() {
         Addr::unchecked(multi_minter_addr.clone()),
         &MultiMintOpenEditionMinterExecuteMsg::Mint {
             mint_instance_id: Some(3),
+            proof: None,
         },
         &[coin(5_000_000, "uflix")],
     )
@@ -734,6 +745,7 @@ fn remove_first_mint_instance() {
             payment_collector: Addr::unchecked("creator".to_string()),
         },
         init: Default::default(),
+        timelock_duration: None,
     };
 
     let res = app
@@ -795,6 +807,7 @@ fn remove_first_mint_instance() {
         description: Some("MintInstance number 1 description".to_string()),
         preview_uri: Some("MintInstance number 1 prev uri".to_string()),
         base_token_uri: "MintInstance number 1 base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
@@ -879,6 +892,7 @@ fn remove_first_mint_instance() {
             Addr::unchecked(multi_minter_addr.clone()),
             &MultiMintOpenEditionMinterExecuteMsg::Mint {
                 mint_instance_id: None,
+                proof: None,
             },
             &[coin(5_000_000, "uflix")],
         )
@@ -940,6 +954,7 @@ fn add_mint_instance() {
             payment_collector: Addr::unchecked("creator".to_string()),
         },
         init: Default::default(),
+        timelock_duration: None,
     };
 
     let res = app
@@ -960,6 +975,7 @@ fn add_mint_instance() {
             description: Some("MintInstance number 1 description".to_string()),
             preview_uri: Some("MintInstance number 1 prev uri".to_string()),
             base_token_uri: "MintInstance number 1 base_token_uri".to_string(),
+            metadata_template: None,
             transferable: true,
             royalty_ratio: Decimal::percent(10),
             extensible: true,
@@ -1392,6 +1408,7 @@ fn update_mint_price() {
         },
         init: Default::default(),
         token_details: None,
+        timelock_duration: None,
     };
 
     let res = app
@@ -1433,6 +1450,7 @@ fn update_mint_price() {
             description: Some("MintInstance number 1 description".to_string()),
             preview_uri: Some("MintInstance number 1 prev uri".to_string()),
             base_token_uri: "MintInstance number 1 base_token_uri".to_string(),
+            metadata_template: None,
             transferable: true,
             royalty_ratio: Decimal::percent(10),
             extensible: true,
@@ -1579,6 +1597,7 @@ fn update_royalty_ratio() {
             payment_collector: Addr::unchecked("creator".to_string()),
         },
         init: Default::default(),
+        timelock_duration: None,
     };
 
     let res = app
@@ -1620,6 +1639,7 @@ fn update_royalty_ratio() {
             description: Some("MintInstance number 1 description".to_string()),
             preview_uri: Some("MintInstance number 1 prev uri".to_string()),
             base_token_uri: "MintInstance number 1 base_token_uri".to_string(),
+            metadata_template: None,
             transferable: true,
             royalty_ratio: Decimal::percent(10),
             extensible: true,
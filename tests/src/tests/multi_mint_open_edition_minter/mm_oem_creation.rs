@@ -67,6 +67,7 @@ fn multi_mint_oem_creation() {
             payment_collector: Addr::unchecked("creator".to_string()),
         },
         init: Default::default(),
+        timelock_duration: None,
     };
     // Send no funds
     let res = app
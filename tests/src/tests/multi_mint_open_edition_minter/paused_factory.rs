@@ -71,6 +71,7 @@ fn paused_mm_oem_factory() {
             payment_collector: Addr::unchecked("creator".to_string()),
         },
         init: Default::default(),
+        timelock_duration: None,
     };
 
     let _res = app
@@ -72,6 +72,7 @@ fn paused_mm_oem() {
             payment_collector: Addr::unchecked("creator".to_string()),
         },
         init: Default::default(),
+        timelock_duration: None,
     };
 
     let res = app
@@ -92,6 +93,7 @@ fn paused_mm_oem() {
         description: Some("MintInstance number 1 description".to_string()),
         preview_uri: Some("MintInstance number 1 prev uri".to_string()),
         base_token_uri: "MintInstance number 1 base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
@@ -178,6 +180,7 @@ fn paused_mm_oem() {
     // Ensure that the minter can not mint
     let mint_msg = MultiMintOpenEditionMinterExecuteMsg::Mint {
         mint_instance_id: None,
+        proof: None,
     };
 
     let error = app
@@ -27,7 +27,9 @@ use crate::helpers::mock_messages::factory_mock_messages::{
     return_open_edition_minter_factory_inst_message, return_round_whitelist_factory_inst_message,
 };
 use crate::helpers::mock_messages::whitelist_mock_messages::return_round_configs;
-use crate::helpers::utils::{get_contract_address_from_res, query_onft_collection};
+use crate::helpers::utils::{
+    add_whitelist_minter, get_contract_address_from_res, query_onft_collection,
+};
 
 use crate::helpers::setup::setup;
 
@@ -93,6 +95,7 @@ fn multi_mint_oem_private_minting() {
             payment_collector: Addr::unchecked("creator".to_string()),
         },
         init: Default::default(),
+        timelock_duration: None,
     };
     // Create a mm oem minter
     let res = app
@@ -116,6 +119,7 @@ fn multi_mint_oem_private_minting() {
     let round_whitelist_inst_msg = CreateWhitelistMsg {
         admin: admin.to_string(),
         rounds: rounds.clone(),
+        minters: None,
     };
 
     // Create a whitelist
@@ -130,6 +134,7 @@ fn multi_mint_oem_private_minting() {
         )
         .unwrap();
     let round_whitelist_addr = get_contract_address_from_res(res);
+    add_whitelist_minter(&mut app, &admin, &round_whitelist_addr, &minter_addr);
 
     // Create a mint_instance
     // Create first mint_instance
@@ -138,6 +143,7 @@ fn multi_mint_oem_private_minting() {
         description: Some("MintInstance number 1 description".to_string()),
         preview_uri: Some("MintInstance number 1 prev uri".to_string()),
         base_token_uri: "MintInstance number 1 base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
@@ -168,7 +174,10 @@ fn multi_mint_oem_private_minting() {
 
     // Private minting havent started yet
     // Try to mint
-    let mint_msg = MultiMintOpenEditionMinterExecuteMsg::Mint { mint_instance_id: None };
+    let mint_msg = MultiMintOpenEditionMinterExecuteMsg::Mint {
+        mint_instance_id: None,
+        proof: None,
+    };
     let res = app
         .execute_contract(
             creator.clone(),
@@ -195,7 +204,10 @@ fn multi_mint_oem_private_minting() {
     app.set_block(block);
 
     // Try to mint creator is not whitelisted for the first round
-    let mint_msg = MultiMintOpenEditionMinterExecuteMsg::Mint { mint_instance_id: None };
+    let mint_msg = MultiMintOpenEditionMinterExecuteMsg::Mint {
+        mint_instance_id: None,
+        proof: None,
+    };
     let res = app
         .execute_contract(
             creator.clone(),
@@ -214,7 +226,10 @@ fn multi_mint_oem_private_minting() {
     );
 
     // Collector can mint but first send wrong payment
-    let mint_msg = MultiMintOpenEditionMinterExecuteMsg::Mint { mint_instance_id: None };
+    let mint_msg = MultiMintOpenEditionMinterExecuteMsg::Mint {
+        mint_instance_id: None,
+        proof: None,
+    };
     let res = app
         .execute_contract(
             collector.clone(),
@@ -235,7 +250,10 @@ fn multi_mint_oem_private_minting() {
     );
 
     // Collector can mint
-    let mint_msg = MultiMintOpenEditionMinterExecuteMsg::Mint { mint_instance_id: None };
+    let mint_msg = MultiMintOpenEditionMinterExecuteMsg::Mint {
+        mint_instance_id: None,
+        proof: None,
+    };
     let _res = app
         .execute_contract(
             collector.clone(),
@@ -307,7 +325,10 @@ fn multi_mint_oem_private_minting() {
 
     // Try minting again with the same collector
     // Should fail
-    let mint_msg = MultiMintOpenEditionMinterExecuteMsg::Mint { mint_instance_id: None };
+    let mint_msg = MultiMintOpenEditionMinterExecuteMsg::Mint {
+        mint_instance_id: None,
+        proof: None,
+    };
     let res = app
         .execute_contract(
             collector.clone(),
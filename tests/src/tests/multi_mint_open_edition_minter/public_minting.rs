@@ -71,6 +71,7 @@ fn multi_mint_oem_public_minting() {
             payment_collector: Addr::unchecked("creator".to_string()),
         },
         init: Default::default(),
+        timelock_duration: None,
     };
 
     let res = app
@@ -101,6 +102,7 @@ fn multi_mint_oem_public_minting() {
             Addr::unchecked(multi_minter_addr.clone()),
             &MultiMintOpenEditionMinterExecuteMsg::Mint {
                 mint_instance_id: None,
+                proof: None,
             },
             &[coin(2000000, "uflix")],
         )
@@ -120,6 +122,7 @@ fn multi_mint_oem_public_minting() {
         description: Some("MintInstance number 1 description".to_string()),
         preview_uri: Some("MintInstance number 1 prev uri".to_string()),
         base_token_uri: "MintInstance number 1 base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
@@ -164,6 +167,7 @@ fn multi_mint_oem_public_minting() {
             Addr::unchecked(multi_minter_addr.clone()),
             &MultiMintOpenEditionMinterExecuteMsg::Mint {
                 mint_instance_id: Some(1),
+                proof: None,
             },
             &[coin(2000000, "uflix")],
         )
@@ -251,6 +255,7 @@ fn multi_mint_oem_public_minting() {
             Addr::unchecked(multi_minter_addr.clone()),
             &MultiMintOpenEditionMinterExecuteMsg::Mint {
                 mint_instance_id: Some(1),
+                proof: None,
             },
             &[coin(2000000, "uflix")],
         )
@@ -276,6 +281,7 @@ fn multi_mint_oem_public_minting() {
             Addr::unchecked(multi_minter_addr.clone()),
             &MultiMintOpenEditionMinterExecuteMsg::Mint {
                 mint_instance_id: Some(1),
+                proof: None,
             },
             &[coin(2000000, "uflix")],
         )
@@ -305,6 +311,7 @@ fn multi_mint_oem_public_minting() {
             Addr::unchecked(multi_minter_addr.clone()),
             &MultiMintOpenEditionMinterExecuteMsg::Mint {
                 mint_instance_id: Some(1),
+                proof: None,
             },
             &[coin(5000000, "uflix")],
         )
@@ -352,6 +359,7 @@ fn multi_mint_oem_public_minting() {
             Addr::unchecked(multi_minter_addr.clone()),
             &MultiMintOpenEditionMinterExecuteMsg::Mint {
                 mint_instance_id: Some(1),
+                proof: None,
             },
             &[coin(5000000, "uflix")],
         )
@@ -379,6 +387,7 @@ fn multi_mint_oem_public_minting() {
                 Addr::unchecked(multi_minter_addr.clone()),
                 &MultiMintOpenEditionMinterExecuteMsg::Mint {
                     mint_instance_id: Some(1),
+                    proof: None,
                 },
                 &[coin(5000000, "uflix")],
             )
@@ -402,6 +411,7 @@ fn multi_mint_oem_public_minting() {
             Addr::unchecked(multi_minter_addr.clone()),
             &MultiMintOpenEditionMinterExecuteMsg::Mint {
                 mint_instance_id: Some(1),
+                proof: None,
             },
             &[coin(5000000, "uflix")],
         )
@@ -484,6 +494,7 @@ fn multi_mint_oem_admin_mint() {
             payment_collector: Addr::unchecked("creator".to_string()),
         },
         init: Default::default(),
+        timelock_duration: None,
     };
 
     let res = app
@@ -504,6 +515,7 @@ fn multi_mint_oem_admin_mint() {
         description: Some("MintInstance number 1 description".to_string()),
         preview_uri: Some("MintInstance number 1 prev uri".to_string()),
         base_token_uri: "MintInstance number 1 base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
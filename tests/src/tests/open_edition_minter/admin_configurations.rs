@@ -2,6 +2,7 @@
 use cosmwasm_std::{coin, Addr, BlockInfo, Coin, StdError, Timestamp};
 
 use cw_multi_test::Executor;
+use cw_ownable::{Action, Ownership, OwnershipError};
 use minter_types::types::AuthDetails;
 
 use minter_types::config::Config;
@@ -78,6 +79,7 @@ fn update_whitelist() {
     let round_whitelist_inst_msg = CreateWhitelistMsg {
         admin: admin.to_string(),
         rounds: rounds.clone(),
+        minters: None,
     };
     let create_round_whitelist_msg =
         omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
@@ -162,6 +164,7 @@ fn update_whitelist() {
     let round_whitelist_inst_msg = CreateWhitelistMsg {
         admin: admin.to_string(),
         rounds: rounds.clone(),
+        minters: None,
     };
     let create_round_whitelist_msg =
         omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
@@ -198,6 +201,7 @@ fn update_whitelist() {
     let round_whitelist_inst_msg = CreateWhitelistMsg {
         admin: admin.to_string(),
         rounds: rounds.clone(),
+        minters: None,
     };
     let create_round_whitelist_msg =
         omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
@@ -316,7 +320,7 @@ fn burn_remaining_tokens() {
         .execute_contract(
             creator.clone(),
             Addr::unchecked(minter_address.clone()),
-            &OpenEditionMinterExecuteMsg::Mint {},
+            &OpenEditionMinterExecuteMsg::Mint { proof: None },
             &[public_minting_price.clone()],
         )
         .unwrap();
@@ -363,7 +367,7 @@ fn burn_remaining_tokens() {
         .execute_contract(
             creator.clone(),
             Addr::unchecked(minter_address.clone()),
-            &OpenEditionMinterExecuteMsg::Mint {},
+            &OpenEditionMinterExecuteMsg::Mint { proof: None },
             &[public_minting_price.clone()],
         )
         .unwrap_err();
@@ -633,34 +637,68 @@ fn update_admin() {
         .unwrap();
     assert_eq!(auth_details.admin, creator);
 
-    // Non creator can not update admin
+    // Non admin can not transfer ownership
     let res = app
         .execute_contract(
             collector.clone(),
             Addr::unchecked(minter_address.clone()),
-            &OpenEditionMinterExecuteMsg::UpdateAdmin {
-                admin: collector.to_string(),
-            },
+            &OpenEditionMinterExecuteMsg::UpdateOwnership(Action::TransferOwnership {
+                new_owner: collector.to_string(),
+                expiry: None,
+            }),
             &[],
         )
         .unwrap_err();
     let error = res.source().unwrap();
     let error = error.downcast_ref::<OpenEditionMinterError>().unwrap();
-    assert_eq!(error, &OpenEditionMinterError::Unauthorized {});
+    assert_eq!(error, &OpenEditionMinterError::Ownership(OwnershipError::NotOwner));
 
-    // Creator can update admin
+    // Creator proposes collector as the new admin
     let _res = app
         .execute_contract(
             creator.clone(),
             Addr::unchecked(minter_address.clone()),
-            &OpenEditionMinterExecuteMsg::UpdateAdmin {
-                admin: collector.to_string(),
-            },
+            &OpenEditionMinterExecuteMsg::UpdateOwnership(Action::TransferOwnership {
+                new_owner: collector.to_string(),
+                expiry: None,
+            }),
+            &[],
+        )
+        .unwrap();
+
+    // Admin is not updated until the transfer is accepted
+    let auth_details: AuthDetails = app
+        .wrap()
+        .query_wasm_smart(
+            minter_address.clone(),
+            &OpenEditionMinterQueryMsg::AuthDetails {},
+        )
+        .unwrap();
+    assert_eq!(auth_details.admin, creator);
+
+    // Only the pending owner can accept the transfer
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &OpenEditionMinterExecuteMsg::UpdateOwnership(Action::AcceptOwnership),
+            &[],
+        )
+        .unwrap_err();
+    let error = res.source().unwrap();
+    let error = error.downcast_ref::<OpenEditionMinterError>().unwrap();
+    assert_eq!(error, &OpenEditionMinterError::Ownership(OwnershipError::NotPendingOwner));
+
+    // Collector accepts the transfer
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &OpenEditionMinterExecuteMsg::UpdateOwnership(Action::AcceptOwnership),
             &[],
         )
         .unwrap();
 
-    // Query AuthDetails
     let auth_details: AuthDetails = app
         .wrap()
         .query_wasm_smart(
@@ -669,6 +707,14 @@ fn update_admin() {
         )
         .unwrap();
     assert_eq!(auth_details.admin, collector);
+
+    let ownership: Ownership<String> = app
+        .wrap()
+        .query_wasm_smart(minter_address.clone(), &OpenEditionMinterQueryMsg::Ownership {})
+        .unwrap();
+    assert_eq!(ownership.owner, Some(collector.to_string()));
+    assert_eq!(ownership.pending_owner, None);
+}
 }
 #[test]
 fn update_payment_collector() {
@@ -0,0 +1,116 @@
+#![cfg(test)]
+use cosmwasm_std::{coin, Addr};
+
+use crate::helpers::utils::get_contract_address_from_res;
+use cw_multi_test::Executor;
+use minter_types::token_details::{MetadataTemplate, TokenDetailsError};
+use omniflix_open_edition_minter_factory::msg::ExecuteMsg as OpenEditionMinterFactoryExecuteMsg;
+
+use crate::helpers::mock_messages::factory_mock_messages::return_open_edition_minter_factory_inst_message;
+
+use crate::helpers::mock_messages::oem_mock_messages::return_open_edition_minter_inst_msg;
+
+use crate::helpers::utils::query_onft_collection;
+
+use crate::helpers::setup::setup;
+
+use omniflix_open_edition_minter::msg::ExecuteMsg as OpenEditionMinterExecuteMsg;
+
+use omniflix_open_edition_minter::error::ContractError as OpenEditionMinterError;
+
+#[test]
+fn oem_metadata_template() {
+    let res = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let open_edition_minter_factory_code_id = res.open_edition_minter_factory_code_id;
+    let open_edition_minter_code_id = res.open_edition_minter_code_id;
+    let mut app = res.app;
+
+    // Instantiate the oem minter factory
+    let open_edition_minter_factory_instantiate_msg =
+        return_open_edition_minter_factory_inst_message(open_edition_minter_code_id, None);
+
+    let open_edition_minter_factory_address = app
+        .instantiate_contract(
+            open_edition_minter_factory_code_id,
+            admin.clone(),
+            &open_edition_minter_factory_instantiate_msg,
+            &[],
+            "Open Edition Minter Factory",
+            None,
+        )
+        .unwrap();
+
+    // Unknown placeholder
+    let mut open_edition_minter_instantiate_msg = return_open_edition_minter_inst_msg();
+    open_edition_minter_instantiate_msg
+        .token_details
+        .as_mut()
+        .unwrap()
+        .metadata_template = Some(MetadataTemplate {
+        name: Some("Token #{serial}".to_string()),
+        media_uri: None,
+        preview_uri: None,
+    });
+    let create_minter_msg = OpenEditionMinterFactoryExecuteMsg::CreateOpenEditionMinter {
+        msg: open_edition_minter_instantiate_msg,
+    };
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            open_edition_minter_factory_address.clone(),
+            &create_minter_msg,
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap_err();
+    let err = res.source().unwrap().source().unwrap();
+    let error = err.downcast_ref::<OpenEditionMinterError>().unwrap();
+    assert_eq!(
+        OpenEditionMinterError::TokenDetailsError(TokenDetailsError::InvalidMetadataTemplate {}),
+        *error
+    );
+
+    // Valid template
+    let mut open_edition_minter_instantiate_msg = return_open_edition_minter_inst_msg();
+    open_edition_minter_instantiate_msg
+        .token_details
+        .as_mut()
+        .unwrap()
+        .metadata_template = Some(MetadataTemplate {
+        name: Some("Token #{edition}/{supply}".to_string()),
+        media_uri: Some("ipfs://cid/{id:4}.json".to_string()),
+        preview_uri: None,
+    });
+    let create_minter_msg = OpenEditionMinterFactoryExecuteMsg::CreateOpenEditionMinter {
+        msg: open_edition_minter_instantiate_msg,
+    };
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            open_edition_minter_factory_address.clone(),
+            &create_minter_msg,
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res);
+
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &OpenEditionMinterExecuteMsg::MintAdmin {
+                recipient: collector.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let collection = query_onft_collection(app.storage(), minter_address.clone());
+    let metadata = collection.onfts[0].metadata.clone().unwrap();
+    assert_eq!(metadata.name, "Token #1/1000");
+    assert_eq!(metadata.media_uri, "ipfs://cid/0001.json");
+    // Preview uri falls back to the default value
+    assert_eq!(metadata.preview_uri, "preview_uri");
+}
@@ -1,4 +1,5 @@
 mod admin_configurations;
+mod metadata_template;
 mod open_edition_minter_creation;
 mod pause_oem;
 mod paused_factory;
@@ -249,6 +249,7 @@ fn open_edition_minter_creation() {
     let mut init = open_edition_minter_instantiate_msg.init.unwrap();
     init.mint_price.amount = Uint128::zero();
     open_edition_minter_instantiate_msg.init = Some(init);
+    open_edition_minter_instantiate_msg.collection_details.id = "id2".to_string();
     let create_minter_msg = OpenEditionMinterFactoryExecuteMsg::CreateOpenEditionMinter {
         msg: open_edition_minter_instantiate_msg,
     };
@@ -92,7 +92,7 @@ fn pause_oem() {
         .execute_contract(
             creator.clone(),
             Addr::unchecked(oem_contract_address.clone()),
-            &OpenEditionMinterExecuteMsg::Mint {},
+            &OpenEditionMinterExecuteMsg::Mint { proof: None },
             &[coin(1000000, "uflix")],
         )
         .unwrap();
@@ -137,7 +137,7 @@ fn pause_oem() {
         .execute_contract(
             creator.clone(),
             Addr::unchecked(oem_contract_address.clone()),
-            &OpenEditionMinterExecuteMsg::Mint {},
+            &OpenEditionMinterExecuteMsg::Mint { proof: None },
             &[coin(1000000, "uflix")],
         )
         .unwrap_err();
@@ -11,7 +11,7 @@ use omniflix_open_edition_minter_factory::msg::ExecuteMsg as OpenEditionMinterFa
 use whitelist_types::CreateWhitelistMsg;
 
 use crate::helpers::mock_messages::whitelist_mock_messages::return_round_configs;
-use crate::helpers::utils::get_contract_address_from_res;
+use crate::helpers::utils::{add_whitelist_minter, get_contract_address_from_res};
 
 use crate::helpers::mock_messages::factory_mock_messages::{
     return_open_edition_minter_factory_inst_message, return_round_whitelist_factory_inst_message,
@@ -80,6 +80,7 @@ fn private_minting() {
     let round_whitelist_inst_msg = CreateWhitelistMsg {
         admin: admin.to_string(),
         rounds: rounds.clone(),
+        minters: None,
     };
     let create_round_whitelist_msg =
         omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
@@ -151,9 +152,10 @@ fn private_minting() {
         )
         .unwrap();
     let minter_address = get_contract_address_from_res(res);
+    add_whitelist_minter(&mut app, &admin, &whitelist_address, &minter_address);
 
     // Try minting should fail because the whitelist no rounds are active
-    let mint_msg = OpenEditionMinterExecuteMsg::Mint {};
+    let mint_msg = OpenEditionMinterExecuteMsg::Mint { proof: None };
     let res = app
         .execute_contract(
             creator.clone(),
@@ -174,7 +176,7 @@ fn private_minting() {
     });
     // Mint for creator should fail because the creator is not whitelisted for first round
     // Creator is also an admin for this minter but this does not matter since executed msg is not MintAdmin{}
-    let mint_msg = OpenEditionMinterExecuteMsg::Mint {};
+    let mint_msg = OpenEditionMinterExecuteMsg::Mint { proof: None };
     let res = app
         .execute_contract(
             creator.clone(),
@@ -190,7 +192,7 @@ fn private_minting() {
     let round_1_mint_price = &rounds[0].round.mint_price;
 
     // Mint for collector
-    let mint_msg = OpenEditionMinterExecuteMsg::Mint {};
+    let mint_msg = OpenEditionMinterExecuteMsg::Mint { proof: None };
     let _res = app
         .execute_contract(
             collector.clone(),
@@ -265,7 +267,7 @@ fn private_minting() {
     // Mint for creator
     // Send round 1's mint price
     // Should fail because wrong mint price
-    let mint_msg = OpenEditionMinterExecuteMsg::Mint {};
+    let mint_msg = OpenEditionMinterExecuteMsg::Mint { proof: None };
     let res = app
         .execute_contract(
             creator.clone(),
@@ -288,7 +290,7 @@ fn private_minting() {
     // Should not fail because the creator is whitelisted for round 2
     // Price is correct
     // Round limit is not reached
-    let mint_msg = OpenEditionMinterExecuteMsg::Mint {};
+    let mint_msg = OpenEditionMinterExecuteMsg::Mint { proof: None };
     let _res = app
         .execute_contract(
             creator.clone(),
@@ -317,7 +319,7 @@ fn private_minting() {
         .mint_price;
 
     // Mint for collector
-    let mint_msg = OpenEditionMinterExecuteMsg::Mint {};
+    let mint_msg = OpenEditionMinterExecuteMsg::Mint { proof: None };
     let _res = app
         .execute_contract(
             collector.clone(),
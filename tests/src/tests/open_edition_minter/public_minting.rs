@@ -111,7 +111,7 @@ fn oem_public_minting() {
         height: 1,
         chain_id: "".to_string(),
     });
-    let mint_msg = OpenEditionMinterExecuteMsg::Mint {};
+    let mint_msg = OpenEditionMinterExecuteMsg::Mint { proof: None };
     let res = app
         .execute_contract(
             collector.clone(),
@@ -220,7 +220,7 @@ fn oem_public_minting() {
     });
 
     // Try minting with incorrect payment amount
-    let mint_msg = OpenEditionMinterExecuteMsg::Mint {};
+    let mint_msg = OpenEditionMinterExecuteMsg::Mint { proof: None };
     let res = app
         .execute_contract(
             collector.clone(),
@@ -243,7 +243,7 @@ fn oem_public_minting() {
     );
 
     // Try minting with incorrect payment denom
-    let mint_msg = OpenEditionMinterExecuteMsg::Mint {};
+    let mint_msg = OpenEditionMinterExecuteMsg::Mint { proof: None };
     let res = app
         .execute_contract(
             collector.clone(),
@@ -268,7 +268,7 @@ fn oem_public_minting() {
         .amount;
 
     // Mint with collector
-    let mint_msg = OpenEditionMinterExecuteMsg::Mint {};
+    let mint_msg = OpenEditionMinterExecuteMsg::Mint { proof: None };
     let _res = app
         .execute_contract(
             collector.clone(),
@@ -326,7 +326,7 @@ fn oem_public_minting() {
     assert_eq!(res, 2);
 
     // Now mint once more with collector, Should fail as per address limit is 1
-    let mint_msg = OpenEditionMinterExecuteMsg::Mint {};
+    let mint_msg = OpenEditionMinterExecuteMsg::Mint { proof: None };
     let res = app
         .execute_contract(
             collector.clone(),
@@ -349,7 +349,7 @@ fn oem_public_minting() {
 
     // Try minting after public minting end time
     // Nor admin or collector should be able to mint
-    let mint_msg = OpenEditionMinterExecuteMsg::Mint {};
+    let mint_msg = OpenEditionMinterExecuteMsg::Mint { proof: None };
     let res = app
         .execute_contract(
             collector.clone(),
@@ -394,7 +394,7 @@ fn oem_public_minting() {
             [public_minting_price.clone()].to_vec(),
         );
         // Mint
-        let mint_msg = OpenEditionMinterExecuteMsg::Mint {};
+        let mint_msg = OpenEditionMinterExecuteMsg::Mint { proof: None };
         let _res = app
             .execute_contract(
                 collector.clone(),
@@ -421,7 +421,7 @@ fn oem_public_minting() {
     assert_eq!(res, 1000);
 
     // Try minting after all tokens are minted
-    let mint_msg = OpenEditionMinterExecuteMsg::Mint {};
+    let mint_msg = OpenEditionMinterExecuteMsg::Mint { proof: None };
     let res = app
         .execute_contract(
             collector.clone(),
@@ -17,7 +17,9 @@ use whitelist_types::{RoundConfig, RoundWhitelistQueryMsgs};
 use crate::helpers::mock_messages::factory_mock_messages::{
     return_minter_factory_inst_message, return_round_whitelist_factory_inst_message,
 };
-use crate::helpers::utils::{get_contract_address_from_res, mint_to_address};
+use crate::helpers::utils::{
+    add_whitelist_minter, get_contract_address_from_res, mint_to_address,
+};
 
 use crate::{helpers::setup::setup, helpers::utils::query_onft_collection};
 use omniflix_minter::error::ContractError as MinterContractError;
@@ -136,18 +138,36 @@ fn test_scenario_1() {
             start_time: Timestamp::from_nanos(1_000_000),
             end_time: Timestamp::from_nanos(2_000_000),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
             mint_price: coin(1_000_000, "uflix"),
         },
         Round {
             start_time: Timestamp::from_nanos(2_000_000),
             end_time: Timestamp::from_nanos(3_000_000),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
             mint_price: coin(2_000_000, "ibc_atom"),
         },
         Round {
             start_time: Timestamp::from_nanos(3_000_000),
             end_time: Timestamp::from_nanos(4_000_000),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
             mint_price: coin(3_000_000, "ibc_atom"),
         },
     ]
@@ -181,6 +201,7 @@ fn test_scenario_1() {
     let round_whitelist_inst_msg = CreateWhitelistMsg {
         admin: admin.to_string(),
         rounds: round_configs.clone(),
+        minters: None,
     };
     let create_round_whitelist_msg =
         omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
@@ -214,6 +235,7 @@ fn test_scenario_1() {
             token_name: "token_name".to_string(),
             description: Some("description".to_string()),
             base_token_uri: "base_token_uri".to_string(),
+            metadata_template: None,
             preview_uri: Some("preview_uri".to_string()),
             extensible: true,
             nsfw: false,
@@ -232,6 +254,7 @@ fn test_scenario_1() {
             admin: Addr::unchecked("creator".to_string()),
             payment_collector: Addr::unchecked("creator".to_string()),
         },
+        timelock_duration: None,
     };
     let mut minter_2_inst_msg = minter_1_inst_message.clone();
     let mut init = minter_2_inst_msg.init.clone().unwrap();
@@ -269,6 +292,8 @@ fn test_scenario_1() {
         )
         .unwrap();
     let minter_2_addr = get_contract_address_from_res(res);
+    add_whitelist_minter(&mut app, &admin, &round_whitelist_addr, &minter_1_addr);
+    add_whitelist_minter(&mut app, &admin, &round_whitelist_addr, &minter_2_addr);
 
     // Collector_1 buys 1 NFT from Minter_1 during round 1
     // Price is 1_000_000 uflix
@@ -288,7 +313,7 @@ fn test_scenario_1() {
         .execute_contract(
             collector_1.clone(),
             Addr::unchecked(minter_1_addr.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(1000000, "uflix")],
         )
         .unwrap();
@@ -299,7 +324,7 @@ fn test_scenario_1() {
         .execute_contract(
             collector_1.clone(),
             Addr::unchecked(minter_2_addr.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(1000000, "uflix")],
         )
         .unwrap();
@@ -325,7 +350,7 @@ fn test_scenario_1() {
         .execute_contract(
             collector_1.clone(),
             Addr::unchecked(minter_1_addr.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(1000000, "uflix")],
         )
         .unwrap_err();
@@ -353,7 +378,7 @@ fn test_scenario_1() {
         .execute_contract(
             collector_1.clone(),
             Addr::unchecked(minter_1_addr.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(2000000, "ibc_atom")],
         )
         .unwrap();
@@ -388,7 +413,7 @@ fn test_scenario_1() {
         .execute_contract(
             collector_1.clone(),
             Addr::unchecked(minter_1_addr.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(3000000, "ibc_atom")],
         )
         .unwrap_err();
@@ -418,7 +443,7 @@ fn test_scenario_1() {
             admin.clone(),
             Addr::unchecked(round_whitelist_addr.clone()),
             &RoundWhitelistExecuteMsg::AddMembers {
-                members: vec![collector_1.clone().to_string()],
+                members: vec![(collector_1.clone().to_string(), 1)],
                 round_index: index as u8,
             },
             &[],
@@ -430,7 +455,7 @@ fn test_scenario_1() {
         .execute_contract(
             collector_1.clone(),
             Addr::unchecked(minter_1_addr.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(3000000, "ibc_atom")],
         )
         .unwrap();
@@ -444,6 +469,12 @@ fn test_scenario_1() {
         start_time: Timestamp::from_nanos(4_000_000),
         end_time: Timestamp::from_nanos(5_000_000),
         round_per_address_limit: 1,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
         mint_price: coin(200_000, "uflix"),
     };
     let round_4_addresses = (1..=100)
@@ -487,7 +518,7 @@ fn test_scenario_1() {
         .execute_contract(
             collector_1.clone(),
             Addr::unchecked(minter_1_addr.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(200000, "uflix")],
         )
         .unwrap();
@@ -511,7 +542,7 @@ fn test_scenario_1() {
         .execute_contract(
             creator.clone(),
             Addr::unchecked(minter_1_addr.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(5000000, "uflix")],
         )
         .unwrap();
@@ -2,6 +2,7 @@
 use cosmwasm_std::{coin, coins, Addr, BlockInfo, Timestamp, Uint128};
 use cosmwasm_std::{Decimal, StdError};
 use cw_multi_test::Executor;
+use cw_ownable::Action;
 use minter_types::collection_details::CollectionDetails;
 use minter_types::config::Config;
 use minter_types::msg::QueryMsg as CommonMinterQueryMsg;
@@ -26,7 +27,9 @@ type MultiMintOpenEditionMinterQueryMsg =
 use crate::helpers::mock_messages::factory_mock_messages::{
     return_open_edition_minter_factory_inst_message, return_round_whitelist_factory_inst_message,
 };
-use crate::helpers::utils::{get_contract_address_from_res, mint_to_address};
+use crate::helpers::utils::{
+    add_whitelist_minter, get_contract_address_from_res, mint_to_address,
+};
 
 use crate::{helpers::setup::setup, helpers::utils::query_onft_collection};
 
@@ -141,18 +144,36 @@ fn test_scenario_2() {
             start_time: Timestamp::from_nanos(1_000_000),
             end_time: Timestamp::from_nanos(2_000_000),
             round_per_address_limit: 100,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
             mint_price: coin(1_000_000, "uflix"),
         },
         Round {
             start_time: Timestamp::from_nanos(2_000_000),
             end_time: Timestamp::from_nanos(3_000_000),
             round_per_address_limit: 100,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
             mint_price: coin(2_000_000, "ibc_atom"),
         },
         Round {
             start_time: Timestamp::from_nanos(3_000_000),
             end_time: Timestamp::from_nanos(4_000_000),
             round_per_address_limit: 1,
+            merkle_root: None,
+            voucher_signer: None,
+            priority: 0,
+            free_claim: false,
+            rollover: None,
+            round_supply: None,
             mint_price: coin(3_000_000, "ibc_atom"),
         },
     ]
@@ -188,6 +209,7 @@ fn test_scenario_2() {
     let round_whitelist_inst_msg = CreateWhitelistMsg {
         admin: admin.to_string(),
         rounds: round_configs.clone(),
+        minters: None,
     };
     let create_round_whitelist_msg =
         omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
@@ -223,6 +245,8 @@ fn test_scenario_2() {
             payment_collector: Addr::unchecked("creator".to_string()),
         },
         init: Default::default(),
+        // Creator opts out of the timelock to fix prices while minting
+        timelock_duration: Some(0),
     };
 
     let res = app
@@ -236,6 +260,7 @@ fn test_scenario_2() {
         )
         .unwrap();
     let multi_minter_addr = get_contract_address_from_res(res);
+    add_whitelist_minter(&mut app, &admin, &round_whitelist_addr, &multi_minter_addr);
 
     // Query all mint_instances
     let res: Result<Vec<(u32, MintInstance)>, _> = app.wrap().query_wasm_smart(
@@ -273,6 +298,7 @@ fn test_scenario_2() {
         description: Some("MintInstance number 1 description".to_string()),
         preview_uri: Some("MintInstance number 1 prev uri".to_string()),
         base_token_uri: "MintInstance number 1 base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
@@ -317,7 +343,7 @@ fn test_scenario_2() {
             .execute_contract(
                 collector.clone(),
                 Addr::unchecked(multi_minter_addr.clone()),
-                &MinterExecuteMsg::Mint {},
+                &MinterExecuteMsg::Mint { proof: None },
                 &[coin(1000000, "uflix")],
             )
             .unwrap();
@@ -344,7 +370,7 @@ fn test_scenario_2() {
             .execute_contract(
                 collector.clone(),
                 Addr::unchecked(multi_minter_addr.clone()),
-                &MinterExecuteMsg::Mint {},
+                &MinterExecuteMsg::Mint { proof: None },
                 &[coin(2000000, "ibc_atom")],
             )
             .unwrap();
@@ -366,7 +392,7 @@ fn test_scenario_2() {
             .execute_contract(
                 collector.clone(),
                 Addr::unchecked(multi_minter_addr.clone()),
-                &MinterExecuteMsg::Mint {},
+                &MinterExecuteMsg::Mint { proof: None },
                 &[coin(3000000, "ibc_atom")],
             )
             .unwrap();
@@ -385,7 +411,7 @@ fn test_scenario_2() {
         .execute_contract(
             creator.clone(),
             Addr::unchecked(multi_minter_addr.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(3000000, "ibc_atom")],
         )
         .unwrap_err();
@@ -403,6 +429,12 @@ fn test_scenario_2() {
         start_time: Timestamp::from_nanos(4_500_000),
         end_time: Timestamp::from_nanos(8_000_000),
         round_per_address_limit: 100,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
         mint_price: coin(200_000, "uflix"),
     };
     let round_4_addresses = (1..=100)
@@ -437,7 +469,7 @@ fn test_scenario_2() {
             .execute_contract(
                 collector.clone(),
                 Addr::unchecked(multi_minter_addr.clone()),
-                &MinterExecuteMsg::Mint {},
+                &MinterExecuteMsg::Mint { proof: None },
                 &[coin(200000, "uflix")],
             )
             .unwrap();
@@ -450,6 +482,12 @@ fn test_scenario_2() {
         start_time: Timestamp::from_nanos(8_000_000 + 2),
         end_time: Timestamp::from_nanos(11_000_000),
         round_per_address_limit: 100,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
         mint_price: coin(200_000, "uflix"),
     }];
 
@@ -467,6 +505,7 @@ fn test_scenario_2() {
     let round_whitelist_inst_msg = CreateWhitelistMsg {
         admin: admin.to_string(),
         rounds: round_configs.clone(),
+        minters: Some(vec![multi_minter_addr.clone()]),
     };
     let create_round_whitelist_msg =
         omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
@@ -533,7 +572,7 @@ fn test_scenario_2() {
             .execute_contract(
                 collector.clone(),
                 Addr::unchecked(multi_minter_addr.clone()),
-                &MinterExecuteMsg::Mint {},
+                &MinterExecuteMsg::Mint { proof: None },
                 &[coin(200000, "uflix")],
             )
             .unwrap();
@@ -556,7 +595,7 @@ fn test_scenario_2() {
             .execute_contract(
                 collector.clone(),
                 Addr::unchecked(multi_minter_addr.clone()),
-                &MinterExecuteMsg::Mint {},
+                &MinterExecuteMsg::Mint { proof: None },
                 &[coin(5000000, "uflix")],
             )
             .unwrap();
@@ -598,7 +637,7 @@ fn test_scenario_2() {
         .execute_contract(
             Addr::unchecked("collector"),
             Addr::unchecked(multi_minter_addr.clone()),
-            &MinterExecuteMsg::Mint {},
+            &MinterExecuteMsg::Mint { proof: None },
             &[coin(1, "uflix")],
         )
         .unwrap_err();
@@ -662,9 +701,18 @@ fn test_scenario_2() {
         .execute_contract(
             creator.clone(),
             Addr::unchecked(multi_minter_addr.clone()),
-            &MinterExecuteMsg::UpdateAdmin {
-                admin: new_admin.clone().into_string(),
-            },
+            &MinterExecuteMsg::UpdateOwnership(Action::TransferOwnership {
+                new_owner: new_admin.clone().into_string(),
+                expiry: None,
+            }),
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            new_admin.clone(),
+            Addr::unchecked(multi_minter_addr.clone()),
+            &MinterExecuteMsg::UpdateOwnership(Action::AcceptOwnership),
             &[],
         )
         .unwrap();
@@ -674,6 +722,7 @@ fn test_scenario_2() {
         description: Some("MintInstance number 2 description".to_string()),
         preview_uri: Some("MintInstance number 2 prev uri".to_string()),
         base_token_uri: "MintInstance number 2 base_token_uri".to_string(),
+        metadata_template: None,
         transferable: true,
         royalty_ratio: Decimal::percent(10),
         extensible: true,
@@ -716,7 +765,10 @@ fn test_scenario_2() {
         .execute_contract(
             collector.clone(),
             Addr::unchecked(multi_minter_addr.clone()),
-            &MultiMintOpenEditionMinterExecuteMsg::Mint { mint_instance_id: Some(1) },
+            &MultiMintOpenEditionMinterExecuteMsg::Mint {
+                mint_instance_id: Some(1),
+                proof: None,
+            },
             &[coin(1000000, "uflix")],
         )
         .unwrap();
@@ -735,7 +787,10 @@ fn test_scenario_2() {
         .execute_contract(
             collector.clone(),
             Addr::unchecked(multi_minter_addr.clone()),
-            &MultiMintOpenEditionMinterExecuteMsg::Mint { mint_instance_id: Some(2) },
+            &MultiMintOpenEditionMinterExecuteMsg::Mint {
+                mint_instance_id: Some(2),
+                proof: None,
+            },
             &[coin(1000000, "uflix")],
         )
         .unwrap_err();
@@ -761,7 +816,10 @@ fn test_scenario_2() {
         .execute_contract(
             collector.clone(),
             Addr::unchecked(multi_minter_addr.clone()),
-            &MultiMintOpenEditionMinterExecuteMsg::Mint { mint_instance_id: Some(2) },
+            &MultiMintOpenEditionMinterExecuteMsg::Mint {
+                mint_instance_id: Some(2),
+                proof: None,
+            },
             &[coin(5000000, "uflix")],
         )
         .unwrap();
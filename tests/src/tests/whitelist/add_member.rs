@@ -42,6 +42,7 @@ fn add_member() {
                 msg: CreateWhitelistMsg {
                     admin: creator.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000000, "uflix")],
@@ -55,7 +56,7 @@ fn add_member() {
             admin.clone(),
             Addr::unchecked(round_whitelist_address.clone()),
             &ExecuteMsg::AddMembers {
-                members: ["address".to_string()].to_vec(),
+                members: [("address".to_string(), 1)].to_vec(),
                 round_index: 1,
             },
             &[coin(1000000, "uflix")],
@@ -71,7 +72,7 @@ fn add_member() {
             creator.clone(),
             Addr::unchecked(round_whitelist_address.clone()),
             &ExecuteMsg::AddMembers {
-                members: ["address".to_string()].to_vec(),
+                members: [("address".to_string(), 1)].to_vec(),
                 round_index: 100,
             },
             &[coin(1000000, "uflix")],
@@ -90,7 +91,7 @@ fn add_member() {
             creator.clone(),
             Addr::unchecked(round_whitelist_address.clone()),
             &ExecuteMsg::AddMembers {
-                members: ["collector".to_string()].to_vec(),
+                members: [("collector".to_string(), 1)].to_vec(),
                 round_index: 1,
             },
             &[coin(1000000, "uflix")],
@@ -116,7 +117,7 @@ fn add_member() {
             creator.clone(),
             Addr::unchecked(round_whitelist_address.clone()),
             &ExecuteMsg::AddMembers {
-                members: vec!["collector".to_string(); 500],
+                members: vec![("collector".to_string(), 1); 500],
                 round_index: 1,
             },
             &[coin(1000000, "uflix")],
@@ -137,10 +138,10 @@ fn add_member() {
     assert_eq!(members, vec![("collector".to_string())]);
 
     // Try adding 500 different addresses to first round
-    let mut addresses: Vec<String> = Vec::new();
+    let mut addresses: Vec<(String, u32)> = Vec::new();
     for i in 0..500 {
         let address = format!("collector{}", i);
-        addresses.push(address.clone());
+        addresses.push((address.clone(), 1));
     }
     let _res = app
         .execute_contract(
@@ -230,10 +231,10 @@ fn add_member() {
 
     // Member limit for an execution is 5000
     // Try adding more than 5000 members
-    let mut addresses: Vec<String> = Vec::new();
+    let mut addresses: Vec<(String, u32)> = Vec::new();
     for i in 0..4999 + 2 {
         let address = format!("collector{}", i);
-        addresses.push(address.clone());
+        addresses.push((address.clone(), 1));
     }
     let res = app
         .execute_contract(
@@ -40,6 +40,7 @@ fn add_round() {
                 msg: CreateWhitelistMsg {
                     admin: admin.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000000, "uflix")],
@@ -51,6 +52,12 @@ fn add_round() {
         start_time: Timestamp::from_nanos(1000),
         end_time: Timestamp::from_nanos(2000),
         round_per_address_limit: 1,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
         mint_price: coin(1000000, "uflix"),
     };
     let round_members = vec!["collector".to_string()];
@@ -76,6 +83,12 @@ fn add_round() {
         start_time: Timestamp::from_nanos(500),
         end_time: Timestamp::from_nanos(1800),
         round_per_address_limit: 1,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
         mint_price: coin(1000000, "uflix"),
     };
     let round_members = vec!["collector".to_string()];
@@ -101,6 +114,12 @@ fn add_round() {
         start_time: Timestamp::from_nanos(2500),
         end_time: Timestamp::from_nanos(3500),
         round_per_address_limit: 1,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
         mint_price: coin(1000000, "uflix"),
     };
     let round_members = vec!["collector".to_string()];
@@ -128,6 +147,12 @@ fn add_round() {
         start_time: Timestamp::from_nanos(4000),
         end_time: Timestamp::from_nanos(3000),
         round_per_address_limit: 1,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
         mint_price: coin(1000000, "uflix"),
     };
     let round_members = vec!["collector".to_string()];
@@ -155,6 +180,12 @@ fn add_round() {
         start_time: Timestamp::from_nanos(5000),
         end_time: Timestamp::from_nanos(6000),
         round_per_address_limit: 1,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
         mint_price: coin(1000000, "uflix"),
     };
     let empty_addresses = vec![];
@@ -181,6 +212,12 @@ fn add_round() {
         start_time: Timestamp::from_nanos(4000),
         end_time: Timestamp::from_nanos(5000),
         round_per_address_limit: 0,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
         mint_price: coin(1000000, "uflix"),
     };
     let round_members = vec!["collector".to_string()];
@@ -0,0 +1,129 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::return_round_whitelist_factory_inst_message;
+use crate::helpers::setup::{setup, SetupResponse};
+use crate::helpers::utils::get_contract_address_from_res;
+use cosmwasm_std::{coin, Addr, Timestamp};
+
+use cw_multi_test::Executor;
+use omniflix_round_whitelist::msg::ExecuteMsg;
+use omniflix_testing::app::OmniflixApp;
+use whitelist_types::{CreateWhitelistMsg, Round, RoundConfig, RoundWhitelistQueryMsgs};
+
+fn query_members(
+    app: &OmniflixApp,
+    round_whitelist_address: &str,
+    round_index: u8,
+    start_after: Option<&str>,
+    limit: Option<u32>,
+) -> Vec<String> {
+    app.wrap()
+        .query_wasm_smart(
+            round_whitelist_address,
+            &RoundWhitelistQueryMsgs::Members {
+                round_index,
+                start_after: start_after.map(|start_after| start_after.to_string()),
+                limit,
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn many_rounds() {
+    let res: SetupResponse = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let round_whitelist_factory_code_id = res.round_whitelist_factory_code_id;
+    let round_whitelist_code_id = res.round_whitelist_code_id;
+    let mut app = res.app;
+
+    let round_whitelist_factory_inst_msg =
+        return_round_whitelist_factory_inst_message(round_whitelist_code_id);
+    let round_whitelist_factory_addr = app
+        .instantiate_contract(
+            round_whitelist_factory_code_id,
+            admin.clone(),
+            &round_whitelist_factory_inst_msg,
+            &[],
+            "round_whitelist_factory",
+            None,
+        )
+        .unwrap();
+
+    // 12 rounds with 3 members each
+    let rounds: Vec<RoundConfig> = (1..=12)
+        .map(|round_index: u64| RoundConfig {
+            round: Round {
+                start_time: Timestamp::from_nanos(2000 + round_index * 100),
+                end_time: Timestamp::from_nanos(2050 + round_index * 100),
+                mint_price: coin(1000000, "uflix"),
+                round_per_address_limit: 1,
+                merkle_root: None,
+                voucher_signer: None,
+                priority: 0,
+                free_claim: false,
+                rollover: None,
+                round_supply: None,
+            },
+            members: (1..=3)
+                .map(|member| format!("member{}_{}", round_index, member))
+                .collect(),
+        })
+        .collect();
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: creator.to_string(),
+                    rounds,
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+    let round_whitelist_address = get_contract_address_from_res(res);
+
+    // Pagination stays within the round
+    let members = query_members(&app, &round_whitelist_address, 1, None, Some(2));
+    assert_eq!(members, vec!["member1_1", "member1_2"]);
+    let members = query_members(&app, &round_whitelist_address, 1, Some("member1_2"), None);
+    assert_eq!(members, vec!["member1_3"]);
+    let members = query_members(&app, &round_whitelist_address, 10, None, None);
+    assert_eq!(members, vec!["member10_1", "member10_2", "member10_3"]);
+    let members = query_members(&app, &round_whitelist_address, 10, Some("member10_3"), None);
+    assert!(members.is_empty());
+
+    // Removing round 1 keeps the members of rounds 10 to 12
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::RemoveRound { round_index: 1 },
+            &[],
+        )
+        .unwrap();
+    let members = query_members(&app, &round_whitelist_address, 1, None, None);
+    assert!(members.is_empty());
+    for round_index in 10..=12 {
+        let members = query_members(&app, &round_whitelist_address, round_index, None, None);
+        assert_eq!(members.len(), 3);
+    }
+
+    // Members are only removed from the given round
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::RemoveMembers {
+                members: vec!["member11_1".to_string()],
+                round_index: 2,
+            },
+            &[],
+        )
+        .unwrap();
+    let members = query_members(&app, &round_whitelist_address, 11, None, None);
+    assert_eq!(members.len(), 3);
+}
@@ -0,0 +1,187 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::{
+    return_minter_factory_inst_message, return_round_whitelist_factory_inst_message,
+};
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::setup::{setup, SetupResponse};
+use crate::helpers::utils::{add_whitelist_minter, get_contract_address_from_res};
+use cosmwasm_std::{coin, Addr, BlockInfo, Timestamp};
+
+use cw_multi_test::Executor;
+use omniflix_minter::msg::ExecuteMsg as MinterExecuteMsg;
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use omniflix_round_whitelist::error::ContractError as RoundWhitelistContractError;
+use omniflix_round_whitelist::msg::ExecuteMsg;
+use whitelist_types::{CreateWhitelistMsg, Round, RoundConfig};
+
+#[test]
+fn member_allocation() {
+    let res: SetupResponse = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let round_whitelist_factory_code_id = res.round_whitelist_factory_code_id;
+    let round_whitelist_code_id = res.round_whitelist_code_id;
+    let mut app = res.app;
+
+    let round_whitelist_factory_inst_msg =
+        return_round_whitelist_factory_inst_message(round_whitelist_code_id);
+    let round_whitelist_factory_addr = app
+        .instantiate_contract(
+            round_whitelist_factory_code_id,
+            admin.clone(),
+            &round_whitelist_factory_inst_msg,
+            &[],
+            "round_whitelist_factory",
+            None,
+        )
+        .unwrap();
+
+    // Admin uses the round per address limit
+    let round = Round {
+        start_time: Timestamp::from_nanos(2000),
+        end_time: Timestamp::from_nanos(3000),
+        mint_price: coin(1000000, "uflix"),
+        round_per_address_limit: 1,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
+    };
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: creator.to_string(),
+                    rounds: vec![RoundConfig {
+                        round,
+                        members: vec![admin.to_string()],
+                    }],
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+    let round_whitelist_address = get_contract_address_from_res(res);
+
+    // Allocation can not be zero
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::AddMembers {
+                members: vec![(collector.to_string(), 0)],
+                round_index: 1,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let err = res.source().unwrap();
+    let error = err.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::InvalidMemberAllocation {});
+
+    // Collector gets 3 mints
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::AddMembers {
+                members: vec![(collector.to_string(), 3)],
+                round_index: 1,
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Create a minter linked to the whitelist
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+    let mut minter_inst_msg = return_minter_instantiate_msg();
+    let mut init = minter_inst_msg.init.unwrap();
+    init.whitelist_address = Some(round_whitelist_address.clone());
+    minter_inst_msg.init = Some(init);
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr,
+            &FactoryExecuteMsg::CreateMinter {
+                msg: minter_inst_msg,
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res);
+    add_whitelist_minter(
+        &mut app,
+        &creator,
+        &round_whitelist_address,
+        &minter_address,
+    );
+
+    // Round starts
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_nanos(2_000 + 1),
+    });
+
+    // Collector can mint up to its allocation
+    for _ in 0..3 {
+        let _res = app
+            .execute_contract(
+                collector.clone(),
+                Addr::unchecked(minter_address.clone()),
+                &MinterExecuteMsg::Mint { proof: None },
+                &[coin(1000000, "uflix")],
+            )
+            .unwrap();
+    }
+    let error = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap().source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::RoundReachedMintLimit {});
+
+    // Admin is limited by the round per address limit
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+    let error = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap().source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::RoundReachedMintLimit {});
+}
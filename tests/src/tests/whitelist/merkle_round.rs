@@ -0,0 +1,330 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::{
+    return_minter_factory_inst_message, return_round_whitelist_factory_inst_message,
+};
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::setup::{setup, SetupResponse};
+use crate::helpers::utils::{add_whitelist_minter, get_contract_address_from_res};
+use cosmwasm_std::{coin, Addr, BlockInfo, Timestamp};
+use sha2::{Digest, Sha256};
+
+use cw_multi_test::Executor;
+use omniflix_minter::error::ContractError as MinterContractError;
+use omniflix_minter::msg::ExecuteMsg as MinterExecuteMsg;
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use omniflix_round_whitelist::error::ContractError as RoundWhitelistContractError;
+use omniflix_round_whitelist::merkle::leaf_hash;
+use whitelist_types::{
+    CreateWhitelistMsg, MembershipProof, Round, RoundConfig, RoundWhitelistQueryMsgs,
+};
+
+fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let mut pair = [a, b];
+    pair.sort_unstable();
+    Sha256::digest(pair.concat()).into()
+}
+
+#[test]
+fn merkle_round() {
+    let res: SetupResponse = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let round_whitelist_factory_code_id = res.round_whitelist_factory_code_id;
+    let round_whitelist_code_id = res.round_whitelist_code_id;
+    let mut app = res.app;
+
+    let round_whitelist_factory_inst_msg =
+        return_round_whitelist_factory_inst_message(round_whitelist_code_id);
+    let round_whitelist_factory_addr = app
+        .instantiate_contract(
+            round_whitelist_factory_code_id,
+            admin.clone(),
+            &round_whitelist_factory_inst_msg,
+            &[],
+            "round_whitelist_factory",
+            None,
+        )
+        .unwrap();
+
+    // Collector has an allocation of 2, creator uses the round limit
+    let collector_leaf = leaf_hash(&collector, Some(2));
+    let creator_leaf = leaf_hash(&creator, None);
+    let merkle_root = hex::encode(hash_pair(collector_leaf, creator_leaf));
+    let collector_proof = MembershipProof::Merkle {
+        proof: vec![hex::encode(creator_leaf)],
+        allocation: Some(2),
+    };
+
+    let round = Round {
+        start_time: Timestamp::from_nanos(2000),
+        end_time: Timestamp::from_nanos(3000),
+        mint_price: coin(1000000, "uflix"),
+        round_per_address_limit: 1,
+        merkle_root: Some("invalid_root".to_string()),
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
+    };
+
+    // Merkle root must be a hex encoded hash
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: admin.to_string(),
+                    rounds: vec![RoundConfig {
+                        round: round.clone(),
+                        members: vec![],
+                    }],
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap().source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::InvalidMerkleRoot {});
+
+    // Merkle rounds can not store members
+    let round = Round {
+        merkle_root: Some(hex::encode(creator_leaf)),
+        ..round
+    };
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: admin.to_string(),
+                    rounds: vec![RoundConfig {
+                        round: round.clone(),
+                        members: vec![collector.to_string()],
+                    }],
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap().source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::MembersNotAllowed {});
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: admin.to_string(),
+                    rounds: vec![RoundConfig {
+                        round: round.clone(),
+                        members: vec![],
+                    }],
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+    let round_whitelist_address = get_contract_address_from_res(res);
+
+    // Non admin can not update the root
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &omniflix_round_whitelist::msg::ExecuteMsg::UpdateMerkleRoot {
+                merkle_root: merkle_root.clone(),
+                round_index: 1,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::Unauthorized {});
+
+    // Admin updates the root before the round starts
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &omniflix_round_whitelist::msg::ExecuteMsg::UpdateMerkleRoot {
+                merkle_root: merkle_root.clone(),
+                round_index: 1,
+            },
+            &[],
+        )
+        .unwrap();
+    let round: Round = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::Round { round_index: 1 },
+        )
+        .unwrap();
+    assert_eq!(round.merkle_root, Some(merkle_root.clone()));
+
+    // Members can not be added to a merkle round
+    let error = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &omniflix_round_whitelist::msg::ExecuteMsg::AddMembers {
+                members: vec![(collector.to_string(), 1)],
+                round_index: 1,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::MembersNotAllowed {});
+
+    // Create a minter linked to the whitelist
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+    let mut minter_inst_msg = return_minter_instantiate_msg();
+    let mut init = minter_inst_msg.init.unwrap();
+    init.whitelist_address = Some(round_whitelist_address.clone());
+    minter_inst_msg.init = Some(init);
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr,
+            &FactoryExecuteMsg::CreateMinter {
+                msg: minter_inst_msg,
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res);
+    add_whitelist_minter(&mut app, &admin, &round_whitelist_address, &minter_address);
+
+    // Round starts
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_nanos(2_000 + 1),
+    });
+
+    // Root can not be updated after the round has started
+    let error = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &omniflix_round_whitelist::msg::ExecuteMsg::UpdateMerkleRoot {
+                merkle_root: merkle_root.clone(),
+                round_index: 1,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::RoundAlreadyStarted {});
+
+    let is_member: bool = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::IsMember {
+                address: collector.to_string(),
+                proof: Some(collector_proof.clone()),
+            },
+        )
+        .unwrap();
+    assert!(is_member);
+
+    // Minting without a proof fails
+    let error = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::AddressNotWhitelisted {});
+
+    // Proof claiming a different allocation fails
+    let error = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint {
+                proof: Some(MembershipProof::Merkle {
+                    proof: vec![hex::encode(creator_leaf)],
+                    allocation: Some(5),
+                }),
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::AddressNotWhitelisted {});
+
+    // Collector can mint up to its allocation
+    for _ in 0..2 {
+        let _res = app
+            .execute_contract(
+                collector.clone(),
+                Addr::unchecked(minter_address.clone()),
+                &MinterExecuteMsg::Mint {
+                    proof: Some(collector_proof.clone()),
+                },
+                &[coin(1000000, "uflix")],
+            )
+            .unwrap();
+    }
+    let error = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint {
+                proof: Some(collector_proof.clone()),
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap().source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::RoundReachedMintLimit {});
+
+    // Creator uses the round limit
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint {
+                proof: Some(MembershipProof::Merkle {
+                    proof: vec![hex::encode(collector_leaf)],
+                    allocation: None,
+                }),
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+}
@@ -0,0 +1,188 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::{
+    return_minter_factory_inst_message, return_round_whitelist_factory_inst_message,
+};
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::mock_messages::whitelist_mock_messages::return_round_configs;
+use crate::helpers::setup::{setup, SetupResponse};
+use crate::helpers::utils::get_contract_address_from_res;
+use cosmwasm_std::{coin, Addr, BlockInfo, Timestamp};
+
+use cw_multi_test::Executor;
+use omniflix_minter::msg::ExecuteMsg as MinterExecuteMsg;
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use omniflix_round_whitelist::error::ContractError as RoundWhitelistContractError;
+use omniflix_round_whitelist::msg::ExecuteMsg;
+use whitelist_types::{CreateWhitelistMsg, MinterUsage, RoundWhitelistQueryMsgs};
+
+#[test]
+fn registered_minters() {
+    let res: SetupResponse = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let round_whitelist_factory_code_id = res.round_whitelist_factory_code_id;
+    let round_whitelist_code_id = res.round_whitelist_code_id;
+    let mut app = res.app;
+
+    let round_whitelist_factory_inst_msg =
+        return_round_whitelist_factory_inst_message(round_whitelist_code_id);
+    let round_whitelist_factory_addr = app
+        .instantiate_contract(
+            round_whitelist_factory_code_id,
+            admin.clone(),
+            &round_whitelist_factory_inst_msg,
+            &[],
+            "round_whitelist_factory",
+            None,
+        )
+        .unwrap();
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: admin.to_string(),
+                    rounds: return_round_configs(),
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+    let round_whitelist_address = get_contract_address_from_res(res);
+
+    // Create a minter linked to the whitelist
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+    let mut minter_inst_msg = return_minter_instantiate_msg();
+    let mut init = minter_inst_msg.init.unwrap();
+    init.whitelist_address = Some(round_whitelist_address.clone());
+    minter_inst_msg.init = Some(init);
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr,
+            &FactoryExecuteMsg::CreateMinter {
+                msg: minter_inst_msg,
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res);
+
+    // Round 1 starts
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_nanos(2_000 + 1),
+    });
+
+    // Unregistered minter can not mint from the whitelist
+    let error = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(1000000, "diffirent_denom")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap().source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::MinterNotRegistered {});
+
+    // Non admin can not register minters
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::AddMinters {
+                minters: vec![minter_address.clone()],
+            },
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::Unauthorized {});
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::AddMinters {
+                minters: vec![minter_address.clone()],
+            },
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(1000000, "diffirent_denom")],
+        )
+        .unwrap();
+
+    let minters: Vec<MinterUsage> = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::Minters {},
+        )
+        .unwrap();
+    assert_eq!(
+        minters,
+        vec![MinterUsage {
+            minter: Addr::unchecked(minter_address.clone()),
+            round_mints: vec![(1, 1)],
+        }]
+    );
+
+    // Admin unregisters the minter
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::RemoveMinters {
+                minters: vec![minter_address.clone()],
+            },
+            &[],
+        )
+        .unwrap();
+    let minters: Vec<MinterUsage> = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::Minters {},
+        )
+        .unwrap();
+    assert!(minters.is_empty());
+
+    let error = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::RemoveMinters {
+                minters: vec![minter_address.clone()],
+            },
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::MinterNotRegistered {});
+}
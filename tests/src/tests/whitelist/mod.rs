@@ -5,3 +5,16 @@ mod remove_round;
 mod round_whitelist_creation;
 
 mod add_member;
+mod free_claim_rollover;
+mod remove_member;
+mod many_rounds;
+mod member_allocation;
+mod merkle_round;
+mod minters;
+mod overlapping_rounds;
+mod paused_whitelist;
+mod round_supply;
+mod update_admin;
+mod update_round;
+mod user_round_status;
+mod voucher_round;
@@ -0,0 +1,224 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::{
+    return_minter_factory_inst_message, return_round_whitelist_factory_inst_message,
+};
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::setup::{setup, SetupResponse};
+use crate::helpers::utils::{add_whitelist_minter, get_contract_address_from_res};
+use cosmwasm_std::{coin, Addr, BlockInfo, Coin, Timestamp};
+
+use cw_multi_test::Executor;
+use omniflix_minter::error::ContractError as MinterContractError;
+use omniflix_minter::msg::ExecuteMsg as MinterExecuteMsg;
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use omniflix_round_whitelist::error::ContractError as RoundWhitelistContractError;
+use omniflix_round_whitelist::msg::ExecuteMsg;
+use omniflix_testing::app::OmniflixApp;
+use whitelist_types::{CreateWhitelistMsg, Round, RoundConfig, RoundWhitelistQueryMsgs};
+
+#[test]
+fn overlapping_rounds() {
+    let res: SetupResponse = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let round_whitelist_factory_code_id = res.round_whitelist_factory_code_id;
+    let round_whitelist_code_id = res.round_whitelist_code_id;
+    let mut app = res.app;
+
+    let round_whitelist_factory_inst_msg =
+        return_round_whitelist_factory_inst_message(round_whitelist_code_id);
+    let round_whitelist_factory_addr = app
+        .instantiate_contract(
+            round_whitelist_factory_code_id,
+            admin.clone(),
+            &round_whitelist_factory_inst_msg,
+            &[],
+            "round_whitelist_factory",
+            None,
+        )
+        .unwrap();
+
+    // OG round runs at the same time as the WL round with a lower price
+    let og_round = Round {
+        start_time: Timestamp::from_nanos(2000),
+        end_time: Timestamp::from_nanos(3000),
+        mint_price: coin(500000, "uflix"),
+        round_per_address_limit: 1,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 1,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
+    };
+    let wl_round = Round {
+        start_time: Timestamp::from_nanos(2000),
+        end_time: Timestamp::from_nanos(5000),
+        mint_price: coin(1000000, "uflix"),
+        round_per_address_limit: 1,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
+    };
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: admin.to_string(),
+                    rounds: vec![
+                        RoundConfig {
+                            round: og_round,
+                            members: vec![collector.to_string()],
+                        },
+                        RoundConfig {
+                            round: wl_round.clone(),
+                            members: vec![collector.to_string(), creator.to_string()],
+                        },
+                    ],
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+    let round_whitelist_address = get_contract_address_from_res(res);
+
+    // Rounds with the same priority can not overlap
+    let error = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::AddRound {
+                round_config: RoundConfig {
+                    round: Round {
+                        start_time: Timestamp::from_nanos(4000),
+                        end_time: Timestamp::from_nanos(6000),
+                        ..wl_round
+                    },
+                    members: vec![],
+                },
+            },
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::RoundsOverlapped {});
+
+    // Create a minter linked to the whitelist
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+    let mut minter_inst_msg = return_minter_instantiate_msg();
+    let mut init = minter_inst_msg.init.unwrap();
+    init.whitelist_address = Some(round_whitelist_address.clone());
+    minter_inst_msg.init = Some(init);
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr,
+            &FactoryExecuteMsg::CreateMinter {
+                msg: minter_inst_msg,
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res);
+    add_whitelist_minter(&mut app, &admin, &round_whitelist_address, &minter_address);
+
+    // Both rounds start
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_nanos(2_000 + 1),
+    });
+
+    // Price is resolved for each collector
+    let collector_price = |app: &OmniflixApp, address: &Addr| -> Coin {
+        app.wrap()
+            .query_wasm_smart(
+                round_whitelist_address.clone(),
+                &RoundWhitelistQueryMsgs::CollectorPrice {
+                    address: address.to_string(),
+                    minter: Some(minter_address.clone()),
+                    proof: None,
+                },
+            )
+            .unwrap()
+    };
+    assert_eq!(collector_price(&app, &collector), coin(500000, "uflix"));
+    assert_eq!(collector_price(&app, &creator), coin(1000000, "uflix"));
+
+    // Collector mints from the OG round first
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(500000, "uflix")],
+        )
+        .unwrap();
+
+    // Then from the WL round once the OG allocation is used
+    assert_eq!(collector_price(&app, &collector), coin(1000000, "uflix"));
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+
+    // Collector has used its allocation in both rounds
+    let error = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(500000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap().source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::RoundReachedMintLimit {});
+
+    // Creator is only a member of the WL round
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+
+    // Non members can not mint
+    let error = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::AddressNotWhitelisted {});
+}
@@ -53,6 +53,7 @@ fn paused_factory() {
                 msg: whitelist_types::CreateWhitelistMsg {
                     admin: admin.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000000, "uflix")],
@@ -85,6 +86,7 @@ fn paused_factory() {
                 msg: CreateWhitelistMsg {
                     admin: admin.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000000, "uflix")],
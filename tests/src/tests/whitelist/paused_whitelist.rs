@@ -0,0 +1,230 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::{
+    return_minter_factory_inst_message, return_round_whitelist_factory_inst_message,
+};
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::mock_messages::whitelist_mock_messages::return_round_configs;
+use crate::helpers::setup::{setup, SetupResponse};
+use crate::helpers::utils::{add_whitelist_minter, get_contract_address_from_res};
+use cosmwasm_std::{coin, Addr, BlockInfo, Timestamp};
+
+use cw_multi_test::Executor;
+use cw_ownable::OwnershipError;
+use omniflix_minter::msg::ExecuteMsg as MinterExecuteMsg;
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use omniflix_round_whitelist::error::ContractError as RoundWhitelistContractError;
+use omniflix_round_whitelist::msg::ExecuteMsg;
+use pauser::PauseError;
+use whitelist_types::{CreateWhitelistMsg, RoundWhitelistQueryMsgs};
+
+#[test]
+fn paused_whitelist() {
+    let res: SetupResponse = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let round_whitelist_factory_code_id = res.round_whitelist_factory_code_id;
+    let round_whitelist_code_id = res.round_whitelist_code_id;
+    let mut app = res.app;
+
+    let round_whitelist_factory_inst_msg =
+        return_round_whitelist_factory_inst_message(round_whitelist_code_id);
+    let round_whitelist_factory_addr = app
+        .instantiate_contract(
+            round_whitelist_factory_code_id,
+            admin.clone(),
+            &round_whitelist_factory_inst_msg,
+            &[],
+            "round_whitelist_factory",
+            None,
+        )
+        .unwrap();
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: admin.to_string(),
+                    rounds: return_round_configs(),
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+    let round_whitelist_address = get_contract_address_from_res(res);
+
+    // Admin of the whitelist is the default pauser
+    let pausers: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::Pausers {},
+        )
+        .unwrap();
+    assert_eq!(pausers, vec![admin.clone()]);
+
+    // Create a minter linked to the whitelist
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+    let mut minter_inst_msg = return_minter_instantiate_msg();
+    let mut init = minter_inst_msg.init.unwrap();
+    init.whitelist_address = Some(round_whitelist_address.clone());
+    minter_inst_msg.init = Some(init);
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr,
+            &FactoryExecuteMsg::CreateMinter {
+                msg: minter_inst_msg,
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res);
+    add_whitelist_minter(&mut app, &admin, &round_whitelist_address, &minter_address);
+
+    // Non pauser can not pause the whitelist
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::Pause {},
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(
+        error,
+        &RoundWhitelistContractError::Pause(PauseError::Unauthorized {
+            sender: creator.clone(),
+        })
+    );
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::Pause {},
+            &[],
+        )
+        .unwrap();
+    let is_paused: bool = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::IsPaused {},
+        )
+        .unwrap();
+    assert!(is_paused);
+
+    // Round 1 starts
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_nanos(2_000 + 1),
+    });
+
+    // Minting from the whitelist is blocked while it is paused
+    let error = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(1000000, "diffirent_denom")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap().source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(
+        error,
+        &RoundWhitelistContractError::Pause(PauseError::Paused {})
+    );
+
+    // Admin hands pausing over to the creator
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::SetPausers {
+                pausers: vec![creator.to_string()],
+            },
+            &[],
+        )
+        .unwrap();
+    let pausers: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::Pausers {},
+        )
+        .unwrap();
+    assert_eq!(pausers, vec![creator.clone()]);
+
+    // Setting pausers also unpauses the whitelist
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(1000000, "diffirent_denom")],
+        )
+        .unwrap();
+
+    // New pauser can pause and unpause
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::Pause {},
+            &[],
+        )
+        .unwrap();
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::Unpause {},
+            &[],
+        )
+        .unwrap();
+    let is_paused: bool = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::IsPaused {},
+        )
+        .unwrap();
+    assert!(!is_paused);
+
+    // Pausers who are not the owner can not set pausers
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::SetPausers {
+                pausers: vec![collector.to_string()],
+            },
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(
+        error,
+        &RoundWhitelistContractError::Ownership(OwnershipError::NotOwner)
+    );
+}
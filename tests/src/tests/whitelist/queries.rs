@@ -41,6 +41,7 @@ fn whitelist_queries() {
                 msg: CreateWhitelistMsg {
                     admin: admin.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000000, "uflix")],
@@ -134,6 +135,7 @@ fn whitelist_queries() {
             contract_addr: round_whitelist_address.clone(),
             msg: to_json_binary(&RoundWhitelistQueryMsgs::IsMember {
                 address: creator.to_string(),
+                proof: None,
             })
             .unwrap(),
         }))
@@ -147,6 +149,7 @@ fn whitelist_queries() {
             contract_addr: round_whitelist_address.clone(),
             msg: to_json_binary(&RoundWhitelistQueryMsgs::IsMember {
                 address: Addr::unchecked("collector".to_string()).to_string(),
+                proof: None,
             })
             .unwrap(),
         }))
@@ -194,6 +197,7 @@ fn test_paginated_members_query() {
                 msg: CreateWhitelistMsg {
                     admin: admin.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000000, "uflix")],
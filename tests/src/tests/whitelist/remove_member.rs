@@ -0,0 +1,196 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::return_round_whitelist_factory_inst_message;
+use crate::helpers::mock_messages::whitelist_mock_messages::return_round_configs;
+use crate::helpers::setup::{setup, SetupResponse};
+use crate::helpers::utils::get_contract_address_from_res;
+use cosmwasm_std::{coin, Addr, BlockInfo, Timestamp};
+
+use cw_multi_test::Executor;
+use omniflix_round_whitelist::error::ContractError as RoundWhitelistContractError;
+use omniflix_round_whitelist::msg::ExecuteMsg;
+use whitelist_types::{CreateWhitelistMsg, RoundWhitelistQueryMsgs};
+
+#[test]
+fn remove_member() {
+    let res: SetupResponse = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let round_whitelist_factory_code_id = res.round_whitelist_factory_code_id;
+    let round_whitelist_code_id = res.round_whitelist_code_id;
+    let mut app = res.app;
+
+    let round_whitelist_factory_inst_msg =
+        return_round_whitelist_factory_inst_message(round_whitelist_code_id);
+
+    let round_whitelist_factory_addr = app
+        .instantiate_contract(
+            round_whitelist_factory_code_id,
+            admin.clone(),
+            &round_whitelist_factory_inst_msg,
+            &[],
+            "round_whitelist_factory",
+            None,
+        )
+        .unwrap();
+    let rounds = return_round_configs();
+    // Create a whitelist
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: creator.to_string(),
+                    rounds: rounds.clone(),
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+    let round_whitelist_address = get_contract_address_from_res(res);
+
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::AddMembers {
+                members: vec![("member1".to_string(), 1), ("member2".to_string(), 1)],
+                round_index: 1,
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Non creator can not remove members
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::RemoveMembers {
+                members: vec!["member1".to_string()],
+                round_index: 1,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let err = res.source().unwrap();
+    let error = err.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::Unauthorized {});
+
+    // Send wrong round index
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::RemoveMembers {
+                members: vec!["member1".to_string()],
+                round_index: 100,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let err = res.source().unwrap();
+    let error = err.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::RoundNotFound {});
+
+    // Empty list
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::RemoveMembers {
+                members: vec![],
+                round_index: 1,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let err = res.source().unwrap();
+    let error = err.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::EmptyAddressList {});
+
+    // Remove a member before the round starts
+    // Addresses that are not members are not listed in the event
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::RemoveMembers {
+                members: vec!["member1".to_string(), "member3".to_string()],
+                round_index: 1,
+            },
+            &[],
+        )
+        .unwrap();
+    let addresses = res
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attribute| attribute.key == "addresses")
+        .unwrap();
+    assert_eq!(addresses.value, "member1");
+
+    let members: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::Members {
+                round_index: 1,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(members, vec!["collector".to_string(), "member2".to_string()]);
+
+    // Members can be removed during the round
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_nanos(2_000 + 1),
+    });
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::RemoveMembers {
+                members: vec!["collector".to_string()],
+                round_index: 1,
+            },
+            &[],
+        )
+        .unwrap();
+    let is_member: bool = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::IsMember {
+                address: "collector".to_string(),
+                proof: None,
+            },
+        )
+        .unwrap();
+    assert!(!is_member);
+
+    // Members can not be removed after the round has ended
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_nanos(3_000 + 1),
+    });
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::RemoveMembers {
+                members: vec!["member2".to_string()],
+                round_index: 1,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let err = res.source().unwrap();
+    let error = err.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::RoundEnded {});
+}
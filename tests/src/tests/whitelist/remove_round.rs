@@ -41,6 +41,7 @@ fn remove_round() {
                 msg: CreateWhitelistMsg {
                     admin: admin.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000000, "uflix")],
@@ -134,6 +135,12 @@ fn remove_round() {
         start_time: Timestamp::from_nanos(6000),
         end_time: Timestamp::from_nanos(7000),
         round_per_address_limit: 1,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
         mint_price: coin(1000000, "uflix"),
     };
     let round_members = vec!["collector".to_string()];
@@ -45,6 +45,7 @@ fn whitelist_creation() {
                 msg: CreateWhitelistMsg {
                     admin: admin.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000, "diffirent_denom")],
@@ -70,6 +71,7 @@ fn whitelist_creation() {
                 msg: CreateWhitelistMsg {
                     admin: admin.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000001, "uflix")],
@@ -95,6 +97,7 @@ fn whitelist_creation() {
                 msg: CreateWhitelistMsg {
                     admin: admin.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000000, "uflix")],
@@ -116,6 +119,7 @@ fn whitelist_creation() {
                 msg: CreateWhitelistMsg {
                     admin: admin.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000000, "uflix")],
@@ -136,6 +140,7 @@ fn whitelist_creation() {
                 msg: CreateWhitelistMsg {
                     admin: admin.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000000, "uflix")],
@@ -158,6 +163,7 @@ fn whitelist_creation() {
             &CreateWhitelistMsg {
                 admin: admin.to_string(),
                 rounds: rounds.clone(),
+                minters: None,
             },
             &[],
             "round_whitelist",
@@ -178,6 +184,7 @@ fn whitelist_creation() {
                 msg: CreateWhitelistMsg {
                     admin: admin.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000000, "uflix")],
@@ -202,6 +209,7 @@ fn whitelist_creation() {
                 msg: CreateWhitelistMsg {
                     admin: admin.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000000, "uflix")],
@@ -228,6 +236,7 @@ fn whitelist_creation() {
                 msg: CreateWhitelistMsg {
                     admin: admin.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000000, "uflix")],
@@ -265,6 +274,7 @@ fn whitelist_creation() {
                 msg: CreateWhitelistMsg {
                     admin: admin.to_string(),
                     rounds: rounds.clone(),
+                    minters: None,
                 },
             },
             &[coin(1000000, "uflix")],
@@ -0,0 +1,156 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::return_round_whitelist_factory_inst_message;
+use crate::helpers::mock_messages::whitelist_mock_messages::return_round_configs;
+use crate::helpers::setup::{setup, SetupResponse};
+use crate::helpers::utils::get_contract_address_from_res;
+use cosmwasm_std::{coin, Addr};
+
+use cw_multi_test::Executor;
+use cw_ownable::{Action, OwnershipError};
+use omniflix_round_whitelist::error::ContractError as RoundWhitelistContractError;
+use omniflix_round_whitelist::msg::ExecuteMsg;
+use whitelist_types::{CreateWhitelistMsg, RoundWhitelistQueryMsgs};
+
+#[test]
+fn update_admin() {
+    let res: SetupResponse = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let round_whitelist_factory_code_id = res.round_whitelist_factory_code_id;
+    let round_whitelist_code_id = res.round_whitelist_code_id;
+    let mut app = res.app;
+
+    let round_whitelist_factory_inst_msg =
+        return_round_whitelist_factory_inst_message(round_whitelist_code_id);
+    let round_whitelist_factory_addr = app
+        .instantiate_contract(
+            round_whitelist_factory_code_id,
+            admin.clone(),
+            &round_whitelist_factory_inst_msg,
+            &[],
+            "round_whitelist_factory",
+            None,
+        )
+        .unwrap();
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: creator.to_string(),
+                    rounds: return_round_configs(),
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+    let round_whitelist_address = get_contract_address_from_res(res);
+
+    // Non admin can not transfer ownership
+    let transfer_msg = ExecuteMsg::UpdateOwnership(Action::TransferOwnership {
+        new_owner: admin.to_string(),
+        expiry: None,
+    });
+    let error = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &transfer_msg,
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(
+        error,
+        &RoundWhitelistContractError::Ownership(OwnershipError::NotOwner)
+    );
+
+    // Admin proposes a new admin
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &transfer_msg,
+            &[],
+        )
+        .unwrap();
+
+    // Admin is not changed until the transfer is accepted
+    let whitelist_admin: String = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::Admin {},
+        )
+        .unwrap();
+    assert_eq!(whitelist_admin, creator.to_string());
+
+    // Only the pending owner can accept
+    let accept_msg = ExecuteMsg::UpdateOwnership(Action::AcceptOwnership);
+    let error = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &accept_msg,
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(
+        error,
+        &RoundWhitelistContractError::Ownership(OwnershipError::NotPendingOwner)
+    );
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &accept_msg,
+            &[],
+        )
+        .unwrap();
+    let whitelist_admin: String = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::Admin {},
+        )
+        .unwrap();
+    assert_eq!(whitelist_admin, admin.to_string());
+    let ownership: cw_ownable::Ownership<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::Ownership {},
+        )
+        .unwrap();
+    assert_eq!(ownership.owner, Some(admin.clone()));
+    assert_eq!(ownership.pending_owner, None);
+
+    // Previous admin can not manage the whitelist anymore
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::RemoveRound { round_index: 1 },
+            &[],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::Unauthorized {});
+
+    let _res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(round_whitelist_address),
+            &ExecuteMsg::RemoveRound { round_index: 1 },
+            &[],
+        )
+        .unwrap();
+}
@@ -0,0 +1,165 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::return_round_whitelist_factory_inst_message;
+use crate::helpers::mock_messages::whitelist_mock_messages::return_round_configs;
+use crate::helpers::setup::{setup, SetupResponse};
+use crate::helpers::utils::get_contract_address_from_res;
+use cosmwasm_std::{coin, Addr, BlockInfo, Timestamp};
+
+use cw_multi_test::Executor;
+use omniflix_round_whitelist::error::ContractError as RoundWhitelistContractError;
+use omniflix_round_whitelist::msg::ExecuteMsg;
+use whitelist_types::{CreateWhitelistMsg, Round, RoundWhitelistQueryMsgs};
+
+#[test]
+fn update_round() {
+    let res: SetupResponse = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let round_whitelist_factory_code_id = res.round_whitelist_factory_code_id;
+    let round_whitelist_code_id = res.round_whitelist_code_id;
+    let mut app = res.app;
+
+    let round_whitelist_factory_inst_msg =
+        return_round_whitelist_factory_inst_message(round_whitelist_code_id);
+    let round_whitelist_factory_addr = app
+        .instantiate_contract(
+            round_whitelist_factory_code_id,
+            admin.clone(),
+            &round_whitelist_factory_inst_msg,
+            &[],
+            "round_whitelist_factory",
+            None,
+        )
+        .unwrap();
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: creator.to_string(),
+                    rounds: return_round_configs(),
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+    let round_whitelist_address = get_contract_address_from_res(res);
+
+    // Non admin can not update rounds
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::UpdateRound {
+                round_index: 1,
+                start_time: None,
+                end_time: Some(Timestamp::from_nanos(3500)),
+                round_per_address_limit: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let err = res.source().unwrap();
+    let error = err.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::Unauthorized {});
+
+    // Updated round can not overlap with the other rounds
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::UpdateRound {
+                round_index: 1,
+                start_time: None,
+                end_time: Some(Timestamp::from_nanos(4500)),
+                round_per_address_limit: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let err = res.source().unwrap();
+    let error = err.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::RoundsOverlapped {});
+
+    // End time can not be before start time
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::UpdateRound {
+                round_index: 1,
+                start_time: Some(Timestamp::from_nanos(3500)),
+                end_time: None,
+                round_per_address_limit: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    let err = res.source().unwrap();
+    let error = err.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::InvalidEndTime {});
+
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::UpdateRound {
+                round_index: 1,
+                start_time: Some(Timestamp::from_nanos(2500)),
+                end_time: Some(Timestamp::from_nanos(3500)),
+                round_per_address_limit: Some(2),
+            },
+            &[],
+        )
+        .unwrap();
+    let round: Round = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::Round { round_index: 1 },
+        )
+        .unwrap();
+    assert_eq!(round.start_time, Timestamp::from_nanos(2500));
+    assert_eq!(round.end_time, Timestamp::from_nanos(3500));
+    assert_eq!(round.round_per_address_limit, 2);
+    assert_eq!(round.mint_price, coin(1000000, "diffirent_denom"));
+
+    // Members of the round are kept
+    let members: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::Members {
+                round_index: 1,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(members, vec!["collector".to_string()]);
+
+    // Round can not be updated after it starts
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_nanos(2_500 + 1),
+    });
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(round_whitelist_address.clone()),
+            &ExecuteMsg::UpdateRound {
+                round_index: 1,
+                start_time: None,
+                end_time: None,
+                round_per_address_limit: Some(3),
+            },
+            &[],
+        )
+        .unwrap_err();
+    let err = res.source().unwrap();
+    let error = err.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::RoundAlreadyStarted {});
+}
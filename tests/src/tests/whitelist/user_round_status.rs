@@ -0,0 +1,387 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::{
+    return_minter_factory_inst_message, return_round_whitelist_factory_inst_message,
+};
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::mock_messages::whitelist_mock_messages::return_round_configs;
+use crate::helpers::setup::{setup, SetupResponse};
+use crate::helpers::utils::{add_whitelist_minter, get_contract_address_from_res};
+use cosmwasm_std::{coin, Addr, BlockInfo, Timestamp};
+
+use cw_multi_test::Executor;
+use omniflix_minter::msg::ExecuteMsg as MinterExecuteMsg;
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use whitelist_types::{
+    CreateWhitelistMsg, RolloverPolicy, Round, RoundConfig, RoundSupply, RoundWhitelistQueryMsgs,
+    UsageRecord, UserRoundStatus,
+};
+
+#[test]
+fn user_round_status() {
+    let res: SetupResponse = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let round_whitelist_factory_code_id = res.round_whitelist_factory_code_id;
+    let round_whitelist_code_id = res.round_whitelist_code_id;
+    let mut app = res.app;
+
+    let round_whitelist_factory_inst_msg =
+        return_round_whitelist_factory_inst_message(round_whitelist_code_id);
+    let round_whitelist_factory_addr = app
+        .instantiate_contract(
+            round_whitelist_factory_code_id,
+            admin.clone(),
+            &round_whitelist_factory_inst_msg,
+            &[],
+            "round_whitelist_factory",
+            None,
+        )
+        .unwrap();
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: admin.to_string(),
+                    rounds: return_round_configs(),
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+    let round_whitelist_address = get_contract_address_from_res(res);
+
+    // Create a minter linked to the whitelist
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+    let mut minter_inst_msg = return_minter_instantiate_msg();
+    let mut init = minter_inst_msg.init.unwrap();
+    init.whitelist_address = Some(round_whitelist_address.clone());
+    minter_inst_msg.init = Some(init);
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr,
+            &FactoryExecuteMsg::CreateMinter {
+                msg: minter_inst_msg,
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res);
+    add_whitelist_minter(&mut app, &admin, &round_whitelist_address, &minter_address);
+
+    // Round 1 starts and collector mints
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_nanos(2_000 + 1),
+    });
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(1000000, "diffirent_denom")],
+        )
+        .unwrap();
+
+    let status: Vec<UserRoundStatus> = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::UserRoundStatus {
+                address: collector.to_string(),
+                minter: minter_address.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        status,
+        vec![
+            UserRoundStatus {
+                round_index: 1,
+                is_member: Some(true),
+                minted: 1,
+                remaining: Some(0),
+            },
+            UserRoundStatus {
+                round_index: 2,
+                is_member: Some(false),
+                minted: 0,
+                remaining: Some(0),
+            },
+        ]
+    );
+
+    // Usage is tracked per minter
+    let status: Vec<UserRoundStatus> = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::UserRoundStatus {
+                address: collector.to_string(),
+                minter: "other_minter".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(status[0].minted, 0);
+    assert_eq!(status[0].remaining, Some(1));
+
+    // Round 2 starts and creator mints
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_nanos(4_000 + 1),
+    });
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+
+    let records: Vec<UsageRecord> = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::UsageRecords {
+                start_after: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        records,
+        vec![UsageRecord {
+            address: collector.clone(),
+            minter: Addr::unchecked(minter_address.clone()),
+            round_index: 1,
+            minted: 1,
+        }]
+    );
+    let records: Vec<UsageRecord> = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::UsageRecords {
+                start_after: Some((collector.to_string(), minter_address.clone(), 1)),
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        records,
+        vec![UsageRecord {
+            address: creator.clone(),
+            minter: Addr::unchecked(minter_address.clone()),
+            round_index: 2,
+            minted: 1,
+        }]
+    );
+}
+
+#[test]
+fn user_round_status_round_supply() {
+    let res: SetupResponse = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let round_whitelist_factory_code_id = res.round_whitelist_factory_code_id;
+    let round_whitelist_code_id = res.round_whitelist_code_id;
+    let mut app = res.app;
+
+    let round_whitelist_factory_inst_msg =
+        return_round_whitelist_factory_inst_message(round_whitelist_code_id);
+    let round_whitelist_factory_addr = app
+        .instantiate_contract(
+            round_whitelist_factory_code_id,
+            admin.clone(),
+            &round_whitelist_factory_inst_msg,
+            &[],
+            "round_whitelist_factory",
+            None,
+        )
+        .unwrap();
+
+    // First round sells out after one mint, unused allocation rolls into the second round
+    let first_round = Round {
+        start_time: Timestamp::from_nanos(2000),
+        end_time: Timestamp::from_nanos(3000),
+        mint_price: coin(1000000, "uflix"),
+        round_per_address_limit: 2,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: Some(RolloverPolicy::NextRound {}),
+        round_supply: Some(RoundSupply {
+            limit: 1,
+            per_minter: false,
+        }),
+    };
+    let second_round = Round {
+        start_time: Timestamp::from_nanos(4000),
+        end_time: Timestamp::from_nanos(5000),
+        mint_price: coin(1000000, "uflix"),
+        round_per_address_limit: 1,
+        merkle_root: None,
+        voucher_signer: None,
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
+    };
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: admin.to_string(),
+                    rounds: vec![
+                        RoundConfig {
+                            round: first_round,
+                            members: vec![collector.to_string(), creator.to_string()],
+                        },
+                        RoundConfig {
+                            round: second_round,
+                            members: vec![collector.to_string()],
+                        },
+                    ],
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+    let round_whitelist_address = get_contract_address_from_res(res);
+
+    // Create a minter linked to the whitelist
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+    let mut minter_inst_msg = return_minter_instantiate_msg();
+    let mut init = minter_inst_msg.init.unwrap();
+    init.whitelist_address = Some(round_whitelist_address.clone());
+    minter_inst_msg.init = Some(init);
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr,
+            &FactoryExecuteMsg::CreateMinter {
+                msg: minter_inst_msg,
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res);
+    add_whitelist_minter(&mut app, &admin, &round_whitelist_address, &minter_address);
+
+    // First round starts and collector mints its supply
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_nanos(2_000 + 1),
+    });
+    let _res = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint { proof: None },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+
+    // Remaining mints are capped by the exhausted round supply
+    let status: Vec<UserRoundStatus> = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::UserRoundStatus {
+                address: creator.to_string(),
+                minter: minter_address.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        status[0],
+        UserRoundStatus {
+            round_index: 1,
+            is_member: Some(true),
+            minted: 0,
+            remaining: Some(0),
+        }
+    );
+    let status: Vec<UserRoundStatus> = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::UserRoundStatus {
+                address: collector.to_string(),
+                minter: minter_address.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        status,
+        vec![
+            UserRoundStatus {
+                round_index: 1,
+                is_member: Some(true),
+                minted: 1,
+                remaining: Some(0),
+            },
+            UserRoundStatus {
+                round_index: 2,
+                is_member: Some(true),
+                minted: 0,
+                remaining: Some(1),
+            },
+        ]
+    );
+
+    // First round ends and its unused allocation rolls into the second round
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_nanos(4_000 + 1),
+    });
+    let status: Vec<UserRoundStatus> = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::UserRoundStatus {
+                address: collector.to_string(),
+                minter: minter_address.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(status[1].remaining, Some(2));
+}
@@ -0,0 +1,323 @@
+#![cfg(test)]
+use crate::helpers::mock_messages::factory_mock_messages::{
+    return_minter_factory_inst_message, return_round_whitelist_factory_inst_message,
+};
+use crate::helpers::mock_messages::minter_mock_messages::return_minter_instantiate_msg;
+use crate::helpers::setup::{setup, SetupResponse};
+use crate::helpers::utils::{add_whitelist_minter, get_contract_address_from_res};
+use cosmwasm_std::{coin, to_json_vec, Addr, Binary, BlockInfo, Timestamp};
+use k256::ecdsa::signature::hazmat::PrehashSigner;
+use k256::ecdsa::{Signature, SigningKey};
+use sha2::{Digest, Sha256};
+
+use cw_multi_test::Executor;
+use omniflix_minter::error::ContractError as MinterContractError;
+use omniflix_minter::msg::ExecuteMsg as MinterExecuteMsg;
+use omniflix_minter_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use omniflix_round_whitelist::error::ContractError as RoundWhitelistContractError;
+use whitelist_types::{
+    CreateWhitelistMsg, MembershipProof, Round, RoundConfig, RoundWhitelistQueryMsgs,
+    VoucherPayload,
+};
+
+fn sign_voucher(signing_key: &SigningKey, payload: &VoucherPayload) -> MembershipProof {
+    let message_hash = Sha256::digest(to_json_vec(payload).unwrap());
+    let signature: Signature = signing_key.sign_prehash(&message_hash).unwrap();
+    MembershipProof::Voucher {
+        max_quantity: payload.max_quantity,
+        expiry: payload.expiry,
+        nonce: payload.nonce.clone(),
+        signature: Binary::from(signature.to_bytes().as_slice()),
+    }
+}
+
+#[test]
+fn voucher_round() {
+    let res: SetupResponse = setup();
+    let admin = res.test_accounts.admin;
+    let creator = res.test_accounts.creator;
+    let collector = res.test_accounts.collector;
+    let minter_factory_code_id = res.minter_factory_code_id;
+    let minter_code_id = res.minter_code_id;
+    let round_whitelist_factory_code_id = res.round_whitelist_factory_code_id;
+    let round_whitelist_code_id = res.round_whitelist_code_id;
+    let mut app = res.app;
+
+    let round_whitelist_factory_inst_msg =
+        return_round_whitelist_factory_inst_message(round_whitelist_code_id);
+    let round_whitelist_factory_addr = app
+        .instantiate_contract(
+            round_whitelist_factory_code_id,
+            admin.clone(),
+            &round_whitelist_factory_inst_msg,
+            &[],
+            "round_whitelist_factory",
+            None,
+        )
+        .unwrap();
+
+    let signing_key = SigningKey::from_slice(&[7u8; 32]).unwrap();
+    let voucher_signer =
+        Binary::from(signing_key.verifying_key().to_encoded_point(true).as_bytes());
+
+    let round = Round {
+        start_time: Timestamp::from_nanos(2000),
+        end_time: Timestamp::from_nanos(3000),
+        mint_price: coin(1000000, "uflix"),
+        round_per_address_limit: 1,
+        merkle_root: None,
+        voucher_signer: Some(Binary::from(vec![1u8; 32])),
+        priority: 0,
+        free_claim: false,
+        rollover: None,
+        round_supply: None,
+    };
+
+    // Voucher signer must be a secp256k1 public key
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: admin.to_string(),
+                    rounds: vec![RoundConfig {
+                        round: round.clone(),
+                        members: vec![],
+                    }],
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap().source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::InvalidVoucherSigner {});
+
+    // Voucher rounds can not store members
+    let round = Round {
+        voucher_signer: Some(voucher_signer),
+        ..round
+    };
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: admin.to_string(),
+                    rounds: vec![RoundConfig {
+                        round: round.clone(),
+                        members: vec![collector.to_string()],
+                    }],
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap().source().unwrap();
+    let error = res.downcast_ref::<RoundWhitelistContractError>().unwrap();
+    assert_eq!(error, &RoundWhitelistContractError::MembersNotAllowed {});
+
+    let res = app
+        .execute_contract(
+            creator.clone(),
+            round_whitelist_factory_addr.clone(),
+            &omniflix_round_whitelist_factory::msg::ExecuteMsg::CreateWhitelist {
+                msg: CreateWhitelistMsg {
+                    admin: admin.to_string(),
+                    rounds: vec![RoundConfig {
+                        round: round.clone(),
+                        members: vec![],
+                    }],
+                    minters: None,
+                },
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+    let round_whitelist_address = get_contract_address_from_res(res);
+
+    // Create a minter linked to the whitelist
+    let factory_inst_msg = return_minter_factory_inst_message(minter_code_id);
+    let factory_addr = app
+        .instantiate_contract(
+            minter_factory_code_id,
+            admin.clone(),
+            &factory_inst_msg,
+            &[],
+            "factory",
+            None,
+        )
+        .unwrap();
+    let mut minter_inst_msg = return_minter_instantiate_msg();
+    let mut init = minter_inst_msg.init.unwrap();
+    init.whitelist_address = Some(round_whitelist_address.clone());
+    minter_inst_msg.init = Some(init);
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            factory_addr,
+            &FactoryExecuteMsg::CreateMinter {
+                msg: minter_inst_msg,
+            },
+            &[coin(2000000, "uflix")],
+        )
+        .unwrap();
+    let minter_address = get_contract_address_from_res(res);
+    add_whitelist_minter(&mut app, &admin, &round_whitelist_address, &minter_address);
+
+    // Collector receives a voucher for 2 tokens
+    let payload = VoucherPayload {
+        whitelist_address: round_whitelist_address.clone(),
+        round_index: 1,
+        address: collector.to_string(),
+        max_quantity: 2,
+        expiry: Timestamp::from_nanos(2500),
+        nonce: "voucher_1".to_string(),
+    };
+    let collector_voucher = sign_voucher(&signing_key, &payload);
+
+    // Round starts
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_nanos(2_000 + 1),
+    });
+
+    let is_member: bool = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::IsMember {
+                address: collector.to_string(),
+                proof: Some(collector_voucher.clone()),
+            },
+        )
+        .unwrap();
+    assert!(is_member);
+
+    // Voucher of the collector can not be used by someone else
+    let error = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint {
+                proof: Some(collector_voucher.clone()),
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::AddressNotWhitelisted {});
+
+    // Voucher with a tampered quantity is rejected
+    let error = app
+        .execute_contract(
+            collector.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint {
+                proof: Some(MembershipProof::Voucher {
+                    max_quantity: 5,
+                    expiry: payload.expiry,
+                    nonce: payload.nonce.clone(),
+                    signature: match &collector_voucher {
+                        MembershipProof::Voucher { signature, .. } => signature.clone(),
+                        _ => unreachable!(),
+                    },
+                }),
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap_err();
+    let res = error.source().unwrap();
+    let error = res.downcast_ref::<MinterContractError>().unwrap();
+    assert_eq!(error, &MinterContractError::AddressNotWhitelisted {});
+
+    // Collector can mint up to the voucher quantity
+    for _ in 0..2 {
+        let _res = app
+            .execute_contract(
+                collector.clone(),
+                Addr::unchecked(minter_address.clone()),
+                &MinterExecuteMsg::Mint {
+                    proof: Some(collector_voucher.clone()),
+                },
+                &[coin(1000000, "uflix")],
+            )
+            .unwrap();
+    }
+    let nonce_usage: u32 = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::VoucherNonceUsage {
+                nonce: "voucher_1".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(nonce_usage, 2);
+
+    // Used voucher can not be replayed
+    let is_member: bool = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address.clone(),
+            &RoundWhitelistQueryMsgs::IsMember {
+                address: collector.to_string(),
+                proof: Some(collector_voucher.clone()),
+            },
+        )
+        .unwrap();
+    assert!(!is_member);
+
+    // Creator mints with its own voucher
+    let creator_voucher = sign_voucher(
+        &signing_key,
+        &VoucherPayload {
+            address: creator.to_string(),
+            max_quantity: 1,
+            nonce: "voucher_2".to_string(),
+            ..payload.clone()
+        },
+    );
+    let _res = app
+        .execute_contract(
+            creator.clone(),
+            Addr::unchecked(minter_address.clone()),
+            &MinterExecuteMsg::Mint {
+                proof: Some(creator_voucher.clone()),
+            },
+            &[coin(1000000, "uflix")],
+        )
+        .unwrap();
+
+    // Vouchers expire
+    app.set_block(BlockInfo {
+        chain_id: "test_1".to_string(),
+        height: 1_000,
+        time: Timestamp::from_nanos(2_500 + 1),
+    });
+    let expired_voucher = sign_voucher(
+        &signing_key,
+        &VoucherPayload {
+            address: creator.to_string(),
+            nonce: "voucher_3".to_string(),
+            ..payload
+        },
+    );
+    let is_member: bool = app
+        .wrap()
+        .query_wasm_smart(
+            round_whitelist_address,
+            &RoundWhitelistQueryMsgs::IsMember {
+                address: creator.to_string(),
+                proof: Some(expired_voucher),
+            },
+        )
+        .unwrap();
+    assert!(!is_member);
+}